@@ -1,6 +1,9 @@
 //! Shared artifact contract persisted by publish and read by site/server.
 
-use crate::{CategoryIndex, PageKey, PublishedArticleSummary, SectionPath, SiteMetadata};
+use crate::{
+    ArticleRef, Category, CategoryDefinition, CategoryIndex, CategoryRegistry, PageKey,
+    PublishedArticleSummary, SectionPath, Series, SeriesPart, SiteMetadata, TagIndex, TocEntry,
+};
 use serde::{Deserialize, Serialize};
 use std::time::SystemTime;
 
@@ -83,6 +86,67 @@ pub struct ArticleSummaryDocument {
     pub priority: Option<i32>,
     pub created_at: String,
     pub updated_at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub series: Option<SeriesPartDocument>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub publish_at: Option<String>,
+    /// Related articles ranked at publish time, closest first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub related: Vec<ArticleRefDocument>,
+    /// Articles whose body links to this one, newest first.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub backlinks: Vec<ArticleRefDocument>,
+    /// Non-whitespace characters of the body text.
+    #[serde(default)]
+    pub char_count: usize,
+    /// Estimated reading time in whole minutes.
+    #[serde(default)]
+    pub reading_minutes: u32,
+    /// Plain-text opening of the body for articles without a `description`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub excerpt: Option<String>,
+}
+
+impl ArticleSummaryDocument {
+    /// When a scheduled article goes public; `None` for articles released with no schedule.
+    pub fn release_time(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        self.publish_at
+            .as_deref()
+            .and_then(|publish_at| chrono::DateTime::parse_from_rfc3339(publish_at).ok())
+            .map(|publish_at| publish_at.to_utc())
+    }
+}
+
+/// Series membership of one article summary.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SeriesPartDocument {
+    pub series: String,
+    pub order: u32,
+}
+
+impl From<&SeriesPart> for SeriesPartDocument {
+    fn from(part: &SeriesPart) -> Self {
+        Self {
+            series: part.series.as_str().to_string(),
+            order: part.order,
+        }
+    }
+}
+
+/// Reference to another article by its URL segments.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ArticleRefDocument {
+    pub category: String,
+    pub slug: String,
+}
+
+impl From<&ArticleRef> for ArticleRefDocument {
+    fn from(article: &ArticleRef) -> Self {
+        Self {
+            category: article.category.as_str().to_string(),
+            slug: article.slug.as_str().to_string(),
+        }
+    }
 }
 
 impl From<&PublishedArticleSummary> for ArticleSummaryDocument {
@@ -93,14 +157,39 @@ impl From<&PublishedArticleSummary> for ArticleSummaryDocument {
             category: summary.category.as_str().to_string(),
             section_path: summary.section_path.clone(),
             description: summary.description.clone(),
-            tags: summary.tags.clone(),
+            tags: summary
+                .tags
+                .iter()
+                .map(|tag| tag.as_str().to_string())
+                .collect(),
             priority: summary.priority,
             created_at: summary.created_at.to_string(),
             updated_at: summary.updated_at.to_string(),
+            series: summary.series.as_ref().map(SeriesPartDocument::from),
+            publish_at: summary.publish_at.as_ref().map(ToString::to_string),
+            related: summary
+                .related
+                .iter()
+                .map(ArticleRefDocument::from)
+                .collect(),
+            backlinks: summary
+                .backlinks
+                .iter()
+                .map(ArticleRefDocument::from)
+                .collect(),
+            char_count: summary.reading_stats.char_count,
+            reading_minutes: summary.reading_stats.reading_minutes,
+            excerpt: summary.excerpt.clone(),
         }
     }
 }
 
+/// Heading outline of one article, stored beside its HTML so listings never carry it.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ArticleOutlineDocument {
+    pub toc: Vec<TocEntry>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct ArticleIndexDocument {
     pub articles: Vec<ArticleSummaryDocument>,
@@ -152,9 +241,84 @@ impl TryFrom<(&CategoryIndex, &str)> for CategoryArtifactDocument {
     }
 }
 
+/// Every part of one article series, ordered from part one.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SeriesArtifactDocument {
+    pub series: String,
+    pub articles: Vec<ArticleSummaryDocument>,
+}
+
+impl From<&Series> for SeriesArtifactDocument {
+    fn from(series: &Series) -> Self {
+        Self {
+            series: series.key.as_str().to_string(),
+            articles: series
+                .articles
+                .iter()
+                .map(ArticleSummaryDocument::from)
+                .collect(),
+        }
+    }
+}
+
+/// Articles that share one tag, in article index order.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TagArtifactDocument {
+    pub tag: String,
+    pub articles: Vec<ArticleSummaryDocument>,
+}
+
+impl From<&TagIndex> for TagArtifactDocument {
+    fn from(index: &TagIndex) -> Self {
+        Self {
+            tag: index.tag.as_str().to_string(),
+            articles: index
+                .articles
+                .iter()
+                .map(ArticleSummaryDocument::from)
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TagSummaryDocument {
+    pub tag: String,
+    pub article_count: usize,
+}
+
+/// Every published tag, ordered by tag.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TagIndexDocument {
+    pub tags: Vec<TagSummaryDocument>,
+}
+
+impl From<&[TagIndex]> for TagIndexDocument {
+    fn from(indexes: &[TagIndex]) -> Self {
+        Self {
+            tags: indexes
+                .iter()
+                .map(|index| TagSummaryDocument {
+                    tag: index.tag.as_str().to_string(),
+                    article_count: index.articles.len(),
+                })
+                .collect(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct CategoryMetadataDocument {
     pub category: String,
+    /// Empty in metadata written before categories were configured; readers fall back to the key.
+    #[serde(default)]
+    pub display_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub sort_order: i32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
     pub article_count: usize,
 }
 
@@ -183,6 +347,46 @@ pub struct HomeFragmentArtifactDocument {
     pub updated_at: String,
 }
 
+/// Permanent redirect from a retired article URL to its current path.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RedirectDocument {
+    pub from: String,
+    pub to: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RedirectIndexDocument {
+    pub redirects: Vec<RedirectDocument>,
+}
+
+impl RedirectIndexDocument {
+    pub fn target_for(&self, path: &str) -> Option<&str> {
+        self.redirects
+            .iter()
+            .find(|redirect| redirect.from == path)
+            .map(|redirect| redirect.to.as_str())
+    }
+}
+
+/// Internal WikiLinks between published notes, addressed by their public paths.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LinkGraphDocument {
+    pub nodes: Vec<LinkGraphNodeDocument>,
+    pub edges: Vec<LinkGraphEdgeDocument>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LinkGraphNodeDocument {
+    pub path: String,
+    pub title: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LinkGraphEdgeDocument {
+    pub source: String,
+    pub target: String,
+}
+
 impl From<&SiteMetadata> for SiteMetadataDocument {
     fn from(metadata: &SiteMetadata) -> Self {
         Self {
@@ -191,7 +395,11 @@ impl From<&SiteMetadata> for SiteMetadataDocument {
                 .categories
                 .iter()
                 .map(|category| CategoryMetadataDocument {
-                    category: category.category.as_str().to_string(),
+                    category: category.definition.key.as_str().to_string(),
+                    display_name: category.definition.display_name.clone(),
+                    description: category.definition.description.clone(),
+                    sort_order: category.definition.sort_order,
+                    icon: category.definition.icon.clone(),
                     article_count: category.article_count,
                 })
                 .collect(),
@@ -199,10 +407,37 @@ impl From<&SiteMetadata> for SiteMetadataDocument {
     }
 }
 
+/// Rebuilds the published category registry that readers use for navigation and routing.
+impl TryFrom<&SiteMetadataDocument> for CategoryRegistry {
+    type Error = crate::DomainError;
+
+    fn try_from(metadata: &SiteMetadataDocument) -> crate::Result<Self> {
+        let definitions = metadata
+            .categories
+            .iter()
+            .map(|category| {
+                let key = category.category.parse::<Category>()?;
+                let display_name = match category.display_name.trim() {
+                    "" => key.as_str().to_string(),
+                    display_name => display_name.to_string(),
+                };
+                Ok(CategoryDefinition {
+                    key,
+                    display_name,
+                    description: category.description.clone(),
+                    sort_order: category.sort_order,
+                    icon: category.icon.clone(),
+                })
+            })
+            .collect::<crate::Result<Vec<_>>>()?;
+        Self::new(definitions)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{Category, CategoryLandingMeta, PageKey, Slug, Timestamp, Title};
+    use crate::{Category, CategoryLandingMeta, PageKey, ReadingStats, Slug, Timestamp, Title};
 
     fn release_pointer(prefix: &str) -> ArtifactReleasePointerDocument {
         ArtifactReleasePointerDocument {
@@ -271,13 +506,31 @@ mod tests {
         let summary = PublishedArticleSummary {
             slug: Slug::new("abc123def456".to_string()).unwrap(),
             title: Title::new("Test Output".to_string()).unwrap(),
-            category: Category::Tech,
+            category: Category::new("tech".to_string()).unwrap(),
             section_path: SectionPath::new(vec!["block".to_string()]),
             description: Some("Test description".to_string()),
-            tags: vec!["test".to_string()],
+            tags: vec!["Test".parse().unwrap()],
             priority: Some(1),
             created_at: Timestamp::new("2025-01-01T00:00:00+09:00".to_string()).unwrap(),
             updated_at: Timestamp::new("2025-01-02T00:00:00+09:00".to_string()).unwrap(),
+            series: Some(SeriesPart {
+                series: "rust-async".parse().unwrap(),
+                order: 2,
+            }),
+            publish_at: Some(Timestamp::new("2025-01-03T09:00:00+09:00".to_string()).unwrap()),
+            related: vec![ArticleRef {
+                category: Category::new("daily".to_string()).unwrap(),
+                slug: Slug::new("related00001".to_string()).unwrap(),
+            }],
+            backlinks: vec![ArticleRef {
+                category: Category::new("daily".to_string()).unwrap(),
+                slug: Slug::new("linking00001".to_string()).unwrap(),
+            }],
+            reading_stats: ReadingStats {
+                char_count: 1200,
+                reading_minutes: 3,
+            },
+            excerpt: Some("Opening sentence.".to_string()),
         };
 
         let json = serde_json::to_string(&ArticleSummaryDocument::from(&summary)).unwrap();
@@ -285,7 +538,16 @@ mod tests {
         assert!(json.contains("\"title\":\"Test Output\""));
         assert!(json.contains("\"slug\":\"abc123def456\""));
         assert!(json.contains("\"category\":\"tech\""));
+        assert!(json.contains("\"tags\":[\"test\"]"));
         assert!(json.contains("\"section_path\":[\"block\"]"));
+        assert!(json.contains("\"series\":{\"series\":\"rust-async\",\"order\":2}"));
+        assert!(json.contains("\"publish_at\":\"2025-01-03T09:00:00+09:00\""));
+        assert!(json.contains("\"related\":[{\"category\":\"daily\",\"slug\":\"related00001\"}]"));
+        assert!(
+            json.contains("\"backlinks\":[{\"category\":\"daily\",\"slug\":\"linking00001\"}]")
+        );
+        assert!(json.contains("\"char_count\":1200,\"reading_minutes\":3"));
+        assert!(json.contains("\"excerpt\":\"Opening sentence.\""));
     }
 
     #[test]
@@ -293,18 +555,52 @@ mod tests {
         let summary = PublishedArticleSummary {
             slug: Slug::new("emptytags001".to_string()).unwrap(),
             title: Title::new("Empty Tags".to_string()).unwrap(),
-            category: Category::Daily,
+            category: Category::new("daily".to_string()).unwrap(),
             section_path: SectionPath::default(),
             description: None,
             tags: vec![],
             priority: None,
             created_at: Timestamp::new("2025-01-01T00:00:00+09:00".to_string()).unwrap(),
             updated_at: Timestamp::new("2025-01-02T00:00:00+09:00".to_string()).unwrap(),
+            series: None,
+            publish_at: None,
+            related: vec![],
+            backlinks: vec![],
+            reading_stats: ReadingStats::default(),
+            excerpt: None,
         };
 
         let json = serde_json::to_string(&ArticleSummaryDocument::from(&summary)).unwrap();
 
         assert!(json.contains("\"tags\":[]"));
+        assert!(!json.contains("\"toc\""));
+        assert!(!json.contains("\"series\""));
+        assert!(!json.contains("\"publish_at\""));
+        assert!(!json.contains("\"related\""));
+        assert!(!json.contains("\"backlinks\""));
+        assert!(!json.contains("\"excerpt\""));
+    }
+
+    #[test]
+    fn test_article_summary_document_reads_release_time_from_publish_at() {
+        let mut summary: ArticleSummaryDocument = serde_json::from_str(
+            r#"{"slug":"scheduled001","title":"Scheduled","category":"tech","tags":[],"created_at":"","updated_at":""}"#,
+        )
+        .unwrap();
+        assert_eq!(summary.release_time(), None);
+
+        summary.publish_at = Some("2025-01-03T09:00:01+09:00".to_string());
+        assert_eq!(
+            summary.release_time(),
+            Some(
+                chrono::DateTime::parse_from_rfc3339("2025-01-03T00:00:01Z")
+                    .unwrap()
+                    .to_utc()
+            )
+        );
+
+        summary.publish_at = Some("soon".to_string());
+        assert_eq!(summary.release_time(), None);
     }
 
     #[test]
@@ -323,6 +619,7 @@ mod tests {
         let document: ArticleSummaryDocument = serde_json::from_str(json).unwrap();
 
         assert_eq!(document.section_path, SectionPath::default());
+        assert!(document.series.is_none());
     }
 
     #[test]
@@ -358,16 +655,32 @@ mod tests {
         assert!(json.contains("\"html\":\"<p>Welcome</p>\""));
     }
 
+    #[test]
+    fn test_redirect_index_document_finds_target() {
+        let document = RedirectIndexDocument {
+            redirects: vec![RedirectDocument {
+                from: "/tech/old-slug".to_string(),
+                to: "/tech/new-slug".to_string(),
+            }],
+        };
+
+        assert_eq!(
+            document.target_for("/tech/old-slug"),
+            Some("/tech/new-slug")
+        );
+        assert_eq!(document.target_for("/tech/new-slug"), None);
+    }
+
     #[test]
     fn test_category_artifact_document_combines_landing_and_index() {
         let landing = CategoryLandingMeta {
-            category: Category::Tech,
+            category: Category::new("tech".to_string()).unwrap(),
             title: Title::new("Technology".to_string()).unwrap(),
             description: Some("Technology landing".to_string()),
             updated_at: Timestamp::new("2025-01-02T00:00:00+09:00".to_string()).unwrap(),
         };
         let index = CategoryIndex {
-            category: Category::Tech,
+            category: Category::new("tech".to_string()).unwrap(),
             landing: Some(landing),
             articles: vec![],
         };
@@ -383,7 +696,7 @@ mod tests {
     #[test]
     fn test_category_artifact_document_requires_landing() {
         let index = CategoryIndex {
-            category: Category::Tech,
+            category: Category::new("tech".to_string()).unwrap(),
             landing: None,
             articles: vec![],
         };
@@ -394,9 +707,9 @@ mod tests {
     #[test]
     fn test_category_artifact_document_rejects_blank_html() {
         let index = CategoryIndex {
-            category: Category::Tech,
+            category: Category::new("tech".to_string()).unwrap(),
             landing: Some(CategoryLandingMeta {
-                category: Category::Tech,
+                category: Category::new("tech".to_string()).unwrap(),
                 title: Title::new("Technology".to_string()).unwrap(),
                 description: None,
                 updated_at: Timestamp::new("2025-01-02T00:00:00+09:00".to_string()).unwrap(),
@@ -406,4 +719,27 @@ mod tests {
 
         assert!(CategoryArtifactDocument::try_from((&index, "  ")).is_err());
     }
+
+    #[test]
+    fn test_category_registry_from_site_metadata_falls_back_to_key() {
+        let metadata: SiteMetadataDocument = serde_json::from_str(
+            r#"{"total_articles":1,"categories":[{"category":"tech","article_count":1}]}"#,
+        )
+        .unwrap();
+
+        let registry = CategoryRegistry::try_from(&metadata).unwrap();
+
+        let tech = registry.resolve("tech").unwrap();
+        assert_eq!(registry.display_name(&tech), Ok("tech"));
+        assert!(
+            CategoryRegistry::try_from(&SiteMetadataDocument {
+                categories: vec![
+                    metadata.categories[0].clone(),
+                    metadata.categories[0].clone()
+                ],
+                ..metadata
+            })
+            .is_err()
+        );
+    }
 }
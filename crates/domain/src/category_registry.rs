@@ -0,0 +1,168 @@
+//! Site-configured categories shared by publish, the artifact reader and the web UI.
+
+use crate::{Category, DomainError, Result};
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::HashSet;
+
+/// Category declared in the site configuration.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CategoryDefinition {
+    pub key: Category,
+    pub display_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub sort_order: i32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+}
+
+/// Validated category definitions in navigation order.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+#[serde(transparent)]
+pub struct CategoryRegistry(Vec<CategoryDefinition>);
+
+impl CategoryRegistry {
+    /// Rejects duplicate keys and blank display names, then orders by `sort_order` and key.
+    pub fn new(mut definitions: Vec<CategoryDefinition>) -> Result<Self> {
+        let mut keys = HashSet::with_capacity(definitions.len());
+        for definition in &mut definitions {
+            if !keys.insert(definition.key.clone()) {
+                return Err(DomainError::InvalidCategory {
+                    category: format!("{} is declared more than once", definition.key),
+                });
+            }
+            let display_name = definition.display_name.trim();
+            if display_name.is_empty() {
+                return Err(DomainError::validation("display_name"));
+            }
+            definition.display_name = display_name.to_string();
+        }
+        definitions.sort_by(|a, b| {
+            a.sort_order
+                .cmp(&b.sort_order)
+                .then_with(|| a.key.cmp(&b.key))
+        });
+
+        Ok(Self(definitions))
+    }
+
+    pub fn get(&self, category: &Category) -> Option<&CategoryDefinition> {
+        self.0.iter().find(|definition| &definition.key == category)
+    }
+
+    /// Parses a category key with the `FromStr` rules and requires it to be registered.
+    pub fn resolve(&self, key: &str) -> Result<Category> {
+        let category = key.parse::<Category>()?;
+        if self.get(&category).is_none() {
+            return Err(DomainError::InvalidCategory {
+                category: key.to_string(),
+            });
+        }
+        Ok(category)
+    }
+
+    pub fn display_name(&self, category: &Category) -> Result<&str> {
+        self.get(category)
+            .map(|definition| definition.display_name.as_str())
+            .ok_or_else(|| DomainError::InvalidCategory {
+                category: category.as_str().to_string(),
+            })
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &CategoryDefinition> {
+        self.0.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl<'de> Deserialize<'de> for CategoryRegistry {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let definitions = Vec::<CategoryDefinition>::deserialize(deserializer)?;
+        Self::new(definitions).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn definition(key: &str, display_name: &str, sort_order: i32) -> CategoryDefinition {
+        CategoryDefinition {
+            key: Category::new(key.to_string()).unwrap(),
+            display_name: display_name.to_string(),
+            description: None,
+            sort_order,
+            icon: None,
+        }
+    }
+
+    #[test]
+    fn test_category_registry_orders_by_sort_order_then_key() {
+        let registry = CategoryRegistry::new(vec![
+            definition("physics", "物理学", 20),
+            definition("tech", "技術", 10),
+            definition("daily", "日常", 20),
+        ])
+        .unwrap();
+
+        let keys: Vec<_> = registry
+            .iter()
+            .map(|definition| definition.key.as_str())
+            .collect();
+        assert_eq!(keys, ["tech", "daily", "physics"]);
+    }
+
+    #[test]
+    fn test_category_registry_rejects_duplicate_keys_and_blank_names() {
+        assert!(
+            CategoryRegistry::new(vec![
+                definition("tech", "技術", 0),
+                definition("tech", "Tech", 1)
+            ])
+            .is_err()
+        );
+        assert_eq!(
+            CategoryRegistry::new(vec![definition("tech", "  ", 0)]),
+            Err(DomainError::validation("display_name"))
+        );
+    }
+
+    #[test]
+    fn test_category_registry_resolves_only_registered_keys() {
+        let registry = CategoryRegistry::new(vec![definition("math", "数学", 0)]).unwrap();
+
+        assert_eq!(registry.resolve("MATH").unwrap().as_str(), "math");
+        assert_eq!(
+            registry.display_name(&registry.resolve("math").unwrap()),
+            Ok("数学")
+        );
+        assert!(registry.resolve("tech").is_err());
+        assert!(registry.resolve("not a key").is_err());
+    }
+
+    #[test]
+    fn test_category_registry_deserialization_validates_definitions() {
+        let registry: CategoryRegistry = serde_json::from_str(
+            r#"[{"key":"math","display_name":"数学","sort_order":5,"icon":"fa-solid fa-square-root-variable"}]"#,
+        )
+        .unwrap();
+
+        let math = registry.get(&"math".parse().unwrap()).unwrap();
+        assert_eq!(math.sort_order, 5);
+        assert_eq!(
+            math.icon.as_deref(),
+            Some("fa-solid fa-square-root-variable")
+        );
+        assert!(
+            serde_json::from_str::<CategoryRegistry>(r#"[{"key":"api","display_name":"API"}]"#)
+                .is_err()
+        );
+    }
+}
@@ -6,8 +6,8 @@ mod attributes;
 mod identifiers;
 
 use crate::error::DomainError;
-pub use attributes::{Category, SectionPath, Timestamp, Title};
-pub use identifiers::{PageKey, Slug};
+pub use attributes::{Category, SectionPath, Tag, Timestamp, Title, TocEntry};
+pub use identifiers::{AssetName, PageKey, SeriesKey, Slug};
 use serde::{Deserialize, Deserializer, de::Error as DeError};
 use std::str::FromStr;
 
@@ -1,8 +1,10 @@
 //! Domain attribute and classification types.
 
+use super::identifiers::RESERVED_ROUTE_SEGMENTS;
 use crate::error::{DomainError, Result};
 use serde::{Deserialize, Deserializer, Serialize};
 use std::{fmt, str::FromStr};
+use unicode_normalization::UnicodeNormalization;
 
 /// Ordered category-relative directory segments used for article grouping.
 #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
@@ -23,6 +25,14 @@ impl SectionPath {
     }
 }
 
+/// Heading in an article outline, linked by its rendered anchor ID.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TocEntry {
+    pub level: u8,
+    pub text: String,
+    pub id: String,
+}
+
 /// Article title with business-rule validation.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub struct Title(String);
@@ -91,6 +101,12 @@ impl Timestamp {
     pub fn as_str(&self) -> &str {
         &self.0
     }
+
+    pub fn to_utc(&self) -> chrono::DateTime<chrono::Utc> {
+        chrono::DateTime::parse_from_rfc3339(&self.0)
+            .expect("timestamps are validated on construction")
+            .to_utc()
+    }
 }
 
 impl FromStr for Timestamp {
@@ -116,32 +132,30 @@ impl<'de> Deserialize<'de> for Timestamp {
     }
 }
 
-/// Category constrained by an enum.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
-pub enum Category {
-    Tech,
-    Daily,
-    Statistics,
-    Physics,
-}
+/// Key of the required static page, which a category would otherwise shadow.
+const ABOUT_PAGE_KEY: &str = "about";
+
+/// Category key declared in the site configuration and used as the first URL segment.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+pub struct Category(String);
 
 impl Category {
-    pub fn as_str(&self) -> &'static str {
-        match self {
-            Category::Tech => "tech",
-            Category::Daily => "daily",
-            Category::Statistics => "statistics",
-            Category::Physics => "physics",
+    pub fn new(value: String) -> Result<Self> {
+        let is_valid = !value.is_empty()
+            && value
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+            && value != ABOUT_PAGE_KEY
+            && !RESERVED_ROUTE_SEGMENTS.contains(&value.as_str());
+        if !is_valid {
+            return Err(DomainError::InvalidCategory { category: value });
         }
+
+        Ok(Self(value))
     }
 
-    pub fn display_name(&self) -> &'static str {
-        match self {
-            Category::Tech => "技術",
-            Category::Daily => "日常",
-            Category::Statistics => "統計学",
-            Category::Physics => "物理学",
-        }
+    pub fn as_str(&self) -> &str {
+        &self.0
     }
 }
 
@@ -155,15 +169,7 @@ impl FromStr for Category {
     type Err = DomainError;
 
     fn from_str(s: &str) -> Result<Self> {
-        match s.to_lowercase().as_str() {
-            "tech" => Ok(Category::Tech),
-            "daily" => Ok(Category::Daily),
-            "statistics" => Ok(Category::Statistics),
-            "physics" => Ok(Category::Physics),
-            _ => Err(DomainError::InvalidCategory {
-                category: s.to_string(),
-            }),
-        }
+        Self::new(s.to_lowercase())
     }
 }
 
@@ -176,9 +182,85 @@ impl<'de> Deserialize<'de> for Category {
     }
 }
 
+/// Article tag normalized so that spelling variants share one tag page.
+///
+/// The value is NFKC-normalized (full-width and other compatibility forms fold to their plain
+/// spelling), letters are lowercased, a leading `#` is dropped, and runs of whitespace or `/`
+/// (Obsidian nested tags) become a single `-`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+pub struct Tag(String);
+
+impl Tag {
+    pub fn new(value: String) -> Result<Self> {
+        let folded = value.nfkc().collect::<String>().to_lowercase();
+        let normalized = folded
+            .trim()
+            .trim_start_matches('#')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .filter(|segment| !segment.is_empty())
+            .collect::<Vec<_>>()
+            .join("-");
+        let is_valid = !normalized.is_empty()
+            && !normalized.starts_with('.')
+            && !normalized.chars().any(|c| c.is_control() || c == '\\');
+        if !is_valid {
+            return Err(DomainError::InvalidTag { tag: value });
+        }
+
+        Ok(Self(normalized))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The tag as one URL path segment, with characters that would end or escape the segment
+    /// (such as `#`, `?` and `%`) percent-encoded. Other characters, including non-ASCII, stay
+    /// as they are.
+    pub fn path_segment(&self) -> String {
+        let mut segment = String::with_capacity(self.0.len());
+        for c in self.0.chars() {
+            if c.is_control()
+                || matches!(c, ' ' | '"' | '#' | '%' | '<' | '>' | '?' | '`' | '{' | '}')
+            {
+                let mut bytes = [0; 4];
+                for byte in c.encode_utf8(&mut bytes).bytes() {
+                    segment.push_str(&format!("%{byte:02X}"));
+                }
+            } else {
+                segment.push(c);
+            }
+        }
+        segment
+    }
+}
+
+impl fmt::Display for Tag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FromStr for Tag {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::new(s.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Tag {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        super::deserialize_validated_string(deserializer)
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{Category, SectionPath, Timestamp, Title};
+    use super::{Category, SectionPath, Tag, Timestamp, Title};
 
     #[test]
     fn test_section_path_exposes_ordered_segments() {
@@ -229,6 +311,55 @@ mod tests {
     #[test]
     fn test_category_deserializes_case_insensitively() {
         let category: Category = serde_json::from_str(r#""TECH""#).unwrap();
-        assert_eq!(category, Category::Tech);
+        assert_eq!(category.as_str(), "tech");
+    }
+
+    #[test]
+    fn test_category_rejects_invalid_and_reserved_keys() {
+        for value in ["", "math notes", "数学", "about", "api", "media"] {
+            assert!(value.parse::<Category>().is_err(), "{value}");
+        }
+        assert_eq!("math".parse::<Category>().unwrap().as_str(), "math");
+    }
+
+    #[test]
+    fn test_tag_normalizes_case_width_and_whitespace() {
+        for (value, expected) in [
+            ("Rust", "rust"),
+            ("ＲＵＳＴ", "rust"),
+            (" #Async  Rust ", "async-rust"),
+            ("machine\u{3000}learning", "machine-learning"),
+            ("rust/async", "rust-async"),
+            ("統計", "統計"),
+            ("ｃ＃", "c#"),
+            ("ﾃﾞｰﾀ", "データ"),
+            ("①", "1"),
+            ("ﬁle", "file"),
+        ] {
+            assert_eq!(Tag::new(value.to_string()).unwrap().as_str(), expected);
+        }
+    }
+
+    #[test]
+    fn test_tag_rejects_empty_and_url_unsafe_values() {
+        for value in ["", " # ", "..", "a\\b", "a\u{7}b"] {
+            assert!(Tag::new(value.to_string()).is_err(), "{value}");
+        }
+    }
+
+    #[test]
+    fn test_tag_path_segment_percent_encodes_url_delimiters() {
+        for (value, expected) in [
+            ("C#", "c%23"),
+            ("100%", "100%25"),
+            ("what?", "what%3F"),
+            ("統計", "統計"),
+            ("rust", "rust"),
+        ] {
+            assert_eq!(
+                Tag::new(value.to_string()).unwrap().path_segment(),
+                expected
+            );
+        }
     }
 }
@@ -61,6 +61,9 @@ impl FromStr for Slug {
 
 impl_display_and_deserialize!(Slug);
 
+/// Top-level path segments owned by server or fixed site routes rather than site content.
+pub(crate) const RESERVED_ROUTE_SEGMENTS: &[&str] = &["api", "assets", "media", "pkg", "tags"];
+
 /// Single path-segment page key used for generated static pages.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
 pub struct PageKey(String);
@@ -79,6 +82,12 @@ impl PageKey {
             });
         }
 
+        if RESERVED_ROUTE_SEGMENTS.contains(&value.as_str()) {
+            return Err(DomainError::InvalidPath {
+                path: format!("{value} is reserved for a server route"),
+            });
+        }
+
         if !value
             .chars()
             .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_')
@@ -107,9 +116,138 @@ impl FromStr for PageKey {
 
 impl_display_and_deserialize!(PageKey);
 
+/// Single path-segment key that groups the parts of a multi-part article series.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+pub struct SeriesKey(String);
+
+impl SeriesKey {
+    pub fn new(value: String) -> Result<Self> {
+        if value.is_empty()
+            || !value
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        {
+            return Err(DomainError::InvalidSeries {
+                series: value,
+                reason: "series keys may only contain lowercase letters, digits, and hyphens"
+                    .to_string(),
+            });
+        }
+
+        Ok(Self(value))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for SeriesKey {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::new(s.to_string())
+    }
+}
+
+impl_display_and_deserialize!(SeriesKey);
+
+/// File extensions accepted as published attachments, paired with their media types.
+const ASSET_MEDIA_TYPES: &[(&str, &str)] = &[
+    ("avif", "image/avif"),
+    ("bmp", "image/bmp"),
+    ("gif", "image/gif"),
+    ("jpeg", "image/jpeg"),
+    ("jpg", "image/jpeg"),
+    ("png", "image/png"),
+    ("svg", "image/svg+xml"),
+    ("webp", "image/webp"),
+    ("pdf", "application/pdf"),
+    ("flac", "audio/flac"),
+    ("m4a", "audio/mp4"),
+    ("mp3", "audio/mpeg"),
+    ("ogg", "audio/ogg"),
+    ("wav", "audio/wav"),
+    ("mp4", "video/mp4"),
+    ("webm", "video/webm"),
+];
+
+/// Single path-segment file name of a published attachment, such as `0123abcd.png`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+pub struct AssetName(String);
+
+impl AssetName {
+    pub fn new(value: String) -> Result<Self> {
+        let Some((stem, extension)) = value.split_once('.') else {
+            return Err(DomainError::InvalidPath {
+                path: format!("アセット名には拡張子が必要です: {value}"),
+            });
+        };
+
+        if stem.is_empty()
+            || !stem
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        {
+            return Err(DomainError::InvalidPath {
+                path: format!("アセット名は英小文字、数字、ハイフンのみ使用可能です: {value}"),
+            });
+        }
+
+        if !Self::supports_extension(extension) || extension.chars().any(|c| c.is_ascii_uppercase())
+        {
+            return Err(DomainError::InvalidPath {
+                path: format!("unsupported asset extension: {value}"),
+            });
+        }
+
+        Ok(Self(value))
+    }
+
+    /// Returns whether attachments with this extension can be published.
+    pub fn supports_extension(extension: &str) -> bool {
+        ASSET_MEDIA_TYPES
+            .iter()
+            .any(|(supported, _)| supported.eq_ignore_ascii_case(extension))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn extension(&self) -> &str {
+        self.0
+            .split_once('.')
+            .map(|(_, extension)| extension)
+            .unwrap_or_default()
+    }
+
+    pub fn media_type(&self) -> &'static str {
+        let extension = self.extension();
+        ASSET_MEDIA_TYPES
+            .iter()
+            .find_map(|(supported, media_type)| (*supported == extension).then_some(*media_type))
+            .unwrap_or("application/octet-stream")
+    }
+
+    pub fn is_image(&self) -> bool {
+        self.media_type().starts_with("image/")
+    }
+}
+
+impl FromStr for AssetName {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::new(s.to_string())
+    }
+}
+
+impl_display_and_deserialize!(AssetName);
+
 #[cfg(test)]
 mod tests {
-    use super::{PageKey, Slug};
+    use super::{AssetName, PageKey, RESERVED_ROUTE_SEGMENTS, SeriesKey, Slug};
 
     #[test]
     fn test_page_key_rejects_reserved_home_key() {
@@ -118,6 +256,15 @@ mod tests {
         assert!(error.to_string().contains("reserved"));
     }
 
+    #[test]
+    fn test_page_key_rejects_reserved_route_segments() {
+        for value in RESERVED_ROUTE_SEGMENTS {
+            let error = PageKey::new(value.to_string()).unwrap_err();
+
+            assert!(error.to_string().contains("reserved"), "{value}");
+        }
+    }
+
     #[test]
     fn test_page_key_rejects_invalid_characters() {
         for value in ["about/team", "About"] {
@@ -125,6 +272,14 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_series_key_rejects_empty_and_non_segment_values() {
+        assert!(SeriesKey::new("rust-async".to_string()).is_ok());
+        for value in ["", "Rust", "rust async", "rust/async"] {
+            assert!(SeriesKey::new(value.to_string()).is_err(), "{value}");
+        }
+    }
+
     #[test]
     fn test_slug_deserializes_with_validation() {
         let slug: Slug = serde_json::from_str(r#""intro00000001""#).unwrap();
@@ -136,4 +291,33 @@ mod tests {
         let error = serde_json::from_str::<Slug>(r#""bad slug""#).unwrap_err();
         assert!(error.to_string().contains("スラッグ"));
     }
+
+    #[test]
+    fn test_asset_name_accepts_hashed_attachment_names() {
+        let asset = AssetName::new("0123456789abcdef.png".to_string()).unwrap();
+
+        assert_eq!(asset.extension(), "png");
+        assert_eq!(asset.media_type(), "image/png");
+        assert!(asset.is_image());
+        assert!(
+            !AssetName::new("0123456789abcdef.pdf".to_string())
+                .unwrap()
+                .is_image()
+        );
+    }
+
+    #[test]
+    fn test_asset_name_rejects_paths_and_unsupported_extensions() {
+        for value in [
+            "../secret.png",
+            "nested/figure.png",
+            "figure",
+            "Figure.png",
+            "figure.PNG",
+            "figure.tar.gz",
+            "figure.exe",
+        ] {
+            assert!(AssetName::new(value.to_string()).is_err(), "{value}");
+        }
+    }
 }
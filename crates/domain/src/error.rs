@@ -14,9 +14,15 @@ pub enum DomainError {
     #[error("無効なカテゴリです: {category}")]
     InvalidCategory { category: String },
 
+    #[error("無効なタグです: {tag}")]
+    InvalidTag { tag: String },
+
     #[error("無効なパスです: {path}")]
     InvalidPath { path: String },
 
+    #[error("invalid series {series}: {reason}")]
+    InvalidSeries { series: String, reason: String },
+
     #[error("invalid RFC 3339 timestamp: {value}")]
     InvalidTimestamp { value: String },
 
@@ -5,16 +5,22 @@
 
 // Domain entities and value objects.
 pub mod artifact_document;
+pub mod category_registry;
 pub mod entities;
 pub mod publishable;
+pub mod reading_stats;
 pub mod site_page;
+pub mod tag_synonyms;
 
 // Domain error types.
 pub mod error;
 
 // Re-exports.
 pub use artifact_document::*;
+pub use category_registry::*;
 pub use entities::*;
 pub use error::{DomainError, Result};
 pub use publishable::*;
+pub use reading_stats::*;
 pub use site_page::*;
+pub use tag_synonyms::*;
@@ -1,6 +1,9 @@
 //! Domain models and pure functions for publishable site artifacts.
 
-use crate::{Category, DomainError, Result, SectionPath, Slug, Timestamp, Title};
+use crate::{
+    Category, CategoryDefinition, CategoryRegistry, DomainError, ReadingStats, Result, SectionPath,
+    SeriesKey, Slug, Tag, Timestamp, Title, TocEntry,
+};
 use std::cmp::Ordering;
 
 /// Metadata for a publishable article.
@@ -11,10 +14,49 @@ pub struct ArticleMeta {
     pub category: Category,
     pub section_path: SectionPath,
     pub description: Option<String>,
-    pub tags: Vec<String>,
+    pub tags: Vec<Tag>,
     pub priority: Option<i32>,
     pub created_at: Timestamp,
     pub updated_at: Timestamp,
+    /// Heading outline of the rendered body, in document order.
+    pub toc: Vec<TocEntry>,
+    pub series: Option<SeriesPart>,
+    /// Release time for a scheduled article that the server keeps hidden until then.
+    pub publish_at: Option<Timestamp>,
+    /// Other published articles the body links to, in first-link order.
+    pub links: Vec<ArticleRef>,
+    /// Closest articles by [`rank_related_articles`], filled in once every article is known.
+    pub related: Vec<ArticleRef>,
+    /// Articles linking here by [`collect_backlinks`], filled in once every article is known.
+    pub backlinks: Vec<ArticleRef>,
+    /// Length of the rendered body text, excluding code blocks and math.
+    pub reading_stats: ReadingStats,
+    /// Opening sentences of the body, shown where `description` is missing.
+    pub excerpt: Option<String>,
+}
+
+impl ArticleMeta {
+    pub fn article_ref(&self) -> ArticleRef {
+        ArticleRef {
+            category: self.category.clone(),
+            slug: self.slug.clone(),
+        }
+    }
+}
+
+/// Published article addressed by its URL segments.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ArticleRef {
+    pub category: Category,
+    pub slug: Slug,
+}
+
+/// Position of an article within a multi-part series.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeriesPart {
+    pub series: SeriesKey,
+    /// One-based part number, contiguous within the series.
+    pub order: u32,
 }
 
 /// Rendered HTML body for a publishable article.
@@ -56,10 +98,16 @@ pub struct PublishedArticleSummary {
     pub category: Category,
     pub section_path: SectionPath,
     pub description: Option<String>,
-    pub tags: Vec<String>,
+    pub tags: Vec<Tag>,
     pub priority: Option<i32>,
     pub created_at: Timestamp,
     pub updated_at: Timestamp,
+    pub series: Option<SeriesPart>,
+    pub publish_at: Option<Timestamp>,
+    pub related: Vec<ArticleRef>,
+    pub backlinks: Vec<ArticleRef>,
+    pub reading_stats: ReadingStats,
+    pub excerpt: Option<String>,
 }
 
 /// Metadata for a rendered category landing page.
@@ -111,10 +159,25 @@ pub struct CategoryIndex {
     pub articles: Vec<PublishedArticleSummary>,
 }
 
+/// Articles that share one tag, in article index order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagIndex {
+    pub tag: Tag,
+    pub articles: Vec<PublishedArticleSummary>,
+}
+
+/// Ordered parts of one multi-part article series.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Series {
+    pub key: SeriesKey,
+    /// Parts sorted by their series order, starting at part one.
+    pub articles: Vec<PublishedArticleSummary>,
+}
+
 /// Per-category metadata for the whole site.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CategoryMetadata {
-    pub category: Category,
+    pub definition: CategoryDefinition,
     pub article_count: usize,
 }
 
@@ -135,13 +198,19 @@ pub fn build_article_summary_from_meta(meta: &ArticleMeta) -> PublishedArticleSu
     PublishedArticleSummary {
         slug: meta.slug.clone(),
         title: meta.title.clone(),
-        category: meta.category,
+        category: meta.category.clone(),
         section_path: meta.section_path.clone(),
         description: meta.description.clone(),
         tags: meta.tags.clone(),
         priority: meta.priority,
         created_at: meta.created_at.clone(),
         updated_at: meta.updated_at.clone(),
+        series: meta.series.clone(),
+        publish_at: meta.publish_at.clone(),
+        related: meta.related.clone(),
+        backlinks: meta.backlinks.clone(),
+        reading_stats: meta.reading_stats,
+        excerpt: meta.excerpt.clone(),
     }
 }
 
@@ -165,17 +234,17 @@ pub fn build_category_indexes(
     let mut grouped: HashMap<Category, Vec<PublishedArticleSummary>> = HashMap::new();
     for article_meta in article_metas {
         grouped
-            .entry(article_meta.category)
+            .entry(article_meta.category.clone())
             .or_default()
             .push(build_article_summary_from_meta(article_meta));
     }
 
     let mut landings_by_category: HashMap<_, _> = category_landings
         .into_iter()
-        .map(|landing| (landing.category, landing))
+        .map(|landing| (landing.category.clone(), landing))
         .collect();
     for category in landings_by_category.keys() {
-        grouped.entry(*category).or_default();
+        grouped.entry(category.clone()).or_default();
     }
 
     let mut indexes: Vec<_> = grouped
@@ -183,8 +252,8 @@ pub fn build_category_indexes(
         .map(|(category, mut articles)| {
             articles.sort_by(compare_summaries);
             CategoryIndex {
-                category,
                 landing: landings_by_category.remove(&category),
+                category,
                 articles,
             }
         })
@@ -193,23 +262,204 @@ pub fn build_category_indexes(
     indexes
 }
 
-/// Build site metadata from completed category indexes.
-pub fn build_site_metadata(category_indexes: &[CategoryIndex]) -> SiteMetadata {
-    let categories = category_indexes
+/// Build one index per tag, ordered by tag.
+pub fn build_tag_indexes(article_metas: &[ArticleMeta]) -> Vec<TagIndex> {
+    use std::collections::BTreeMap;
+
+    let mut grouped: BTreeMap<Tag, Vec<PublishedArticleSummary>> = BTreeMap::new();
+    for article_meta in article_metas {
+        for tag in &article_meta.tags {
+            grouped
+                .entry(tag.clone())
+                .or_default()
+                .push(build_article_summary_from_meta(article_meta));
+        }
+    }
+
+    grouped
+        .into_iter()
+        .map(|(tag, mut articles)| {
+            articles.sort_by(compare_summaries);
+            TagIndex { tag, articles }
+        })
+        .collect()
+}
+
+/// Build every article series, rejecting duplicate or missing part numbers.
+pub fn build_series(article_metas: &[ArticleMeta]) -> Result<Vec<Series>> {
+    use std::collections::BTreeMap;
+
+    let mut grouped: BTreeMap<SeriesKey, Vec<(u32, PublishedArticleSummary)>> = BTreeMap::new();
+    for article_meta in article_metas {
+        if let Some(part) = &article_meta.series {
+            grouped
+                .entry(part.series.clone())
+                .or_default()
+                .push((part.order, build_article_summary_from_meta(article_meta)));
+        }
+    }
+
+    grouped
+        .into_iter()
+        .map(|(key, mut parts)| {
+            parts.sort_by_key(|(order, _)| *order);
+            for (expected, (order, summary)) in (1..).zip(&parts) {
+                let reason = match order.cmp(&expected) {
+                    Ordering::Equal => continue,
+                    Ordering::Less if *order == 0 => "part numbers start at 1".to_string(),
+                    Ordering::Less => format!(
+                        "part {order} is used by more than one article, including {}",
+                        summary.slug
+                    ),
+                    Ordering::Greater => format!("part {expected} is missing"),
+                };
+                return Err(DomainError::InvalidSeries {
+                    series: key.as_str().to_string(),
+                    reason,
+                });
+            }
+
+            Ok(Series {
+                key,
+                articles: parts.into_iter().map(|(_, summary)| summary).collect(),
+            })
+        })
+        .collect()
+}
+
+/// Build site metadata from completed category indexes in registry order.
+pub fn build_site_metadata(
+    category_indexes: &[CategoryIndex],
+    registry: &CategoryRegistry,
+) -> Result<SiteMetadata> {
+    if let Some(index) = category_indexes
+        .iter()
+        .find(|index| registry.get(&index.category).is_none())
+    {
+        return Err(DomainError::InvalidCategory {
+            category: index.category.as_str().to_string(),
+        });
+    }
+    let categories = registry
         .iter()
-        .map(|index| CategoryMetadata {
-            category: index.category,
-            article_count: index.articles.len(),
+        .filter_map(|definition| {
+            let index = category_indexes
+                .iter()
+                .find(|index| index.category == definition.key)?;
+            Some(CategoryMetadata {
+                definition: definition.clone(),
+                article_count: index.articles.len(),
+            })
         })
         .collect();
 
-    SiteMetadata {
+    Ok(SiteMetadata {
         total_articles: category_indexes
             .iter()
             .map(|index| index.articles.len())
             .sum(),
         categories,
+    })
+}
+
+/// Number of related articles kept for each article.
+pub const RELATED_ARTICLE_LIMIT: usize = 3;
+
+/// Ranks up to `limit` related articles for each article, in input order.
+///
+/// Shared tags weigh most, then WikiLink co-citation and a shared section path within the same
+/// category; candidates with nothing in common are left out and recency breaks ties.
+pub fn rank_related_articles(article_metas: &[ArticleMeta], limit: usize) -> Vec<Vec<ArticleRef>> {
+    use std::collections::{HashMap, HashSet};
+
+    // An article cites itself, so a direct link counts as one co-citation.
+    let mut citers: HashMap<ArticleRef, HashSet<ArticleRef>> = article_metas
+        .iter()
+        .map(|meta| (meta.article_ref(), HashSet::from([meta.article_ref()])))
+        .collect();
+    for meta in article_metas {
+        for link in &meta.links {
+            if let Some(link_citers) = citers.get_mut(link) {
+                link_citers.insert(meta.article_ref());
+            }
+        }
     }
+
+    article_metas
+        .iter()
+        .map(|article| {
+            let article_citers = &citers[&article.article_ref()];
+            let mut scored: Vec<_> = article_metas
+                .iter()
+                .filter(|candidate| candidate.article_ref() != article.article_ref())
+                .map(|candidate| {
+                    let co_citations = article_citers
+                        .intersection(&citers[&candidate.article_ref()])
+                        .count();
+                    (related_score(article, candidate, co_citations), candidate)
+                })
+                .filter(|(score, _)| *score > 0)
+                .collect();
+            scored.sort_by(|(a_score, a), (b_score, b)| {
+                b_score
+                    .cmp(a_score)
+                    .then_with(|| b.updated_at.cmp(&a.updated_at))
+                    .then_with(|| b.created_at.cmp(&a.created_at))
+                    .then_with(|| a.slug.as_str().cmp(b.slug.as_str()))
+            });
+            scored
+                .into_iter()
+                .take(limit)
+                .map(|(_, candidate)| candidate.article_ref())
+                .collect()
+        })
+        .collect()
+}
+
+/// Collects the articles linking to each article, newest first, in input order.
+pub fn collect_backlinks(article_metas: &[ArticleMeta]) -> Vec<Vec<ArticleRef>> {
+    let mut sources: Vec<_> = article_metas.iter().collect();
+    sources.sort_by(|a, b| {
+        b.updated_at
+            .cmp(&a.updated_at)
+            .then_with(|| b.created_at.cmp(&a.created_at))
+            .then_with(|| a.slug.as_str().cmp(b.slug.as_str()))
+    });
+
+    article_metas
+        .iter()
+        .map(|article| {
+            let article_ref = article.article_ref();
+            sources
+                .iter()
+                .filter(|source| source.links.contains(&article_ref))
+                .map(|source| source.article_ref())
+                .collect()
+        })
+        .collect()
+}
+
+fn related_score(article: &ArticleMeta, candidate: &ArticleMeta, co_citations: usize) -> usize {
+    let shared_tags = article
+        .tags
+        .iter()
+        .filter(|tag| candidate.tags.contains(tag))
+        .count();
+    let shared_sections = if article.category == candidate.category {
+        let shared_prefix = article
+            .section_path
+            .segments()
+            .iter()
+            .zip(candidate.section_path.segments())
+            .take_while(|(a, b)| a == b)
+            .count();
+        // A bare category match is too weak to relate articles on its own.
+        shared_prefix * 2
+    } else {
+        0
+    };
+
+    shared_tags * 3 + co_citations * 2 + shared_sections
 }
 
 fn compare_summaries(a: &PublishedArticleSummary, b: &PublishedArticleSummary) -> Ordering {
@@ -247,10 +497,18 @@ mod tests {
             category,
             section_path: SectionPath::default(),
             description: Some(format!("{title} summary")),
-            tags: vec!["tag".to_string()],
+            tags: vec![Tag::new("tag".to_string()).unwrap()],
             priority,
             created_at: Timestamp::new(created_at.to_string()).unwrap(),
             updated_at: Timestamp::new(created_at.to_string()).unwrap(),
+            toc: Vec::new(),
+            series: None,
+            publish_at: None,
+            links: Vec::new(),
+            related: Vec::new(),
+            backlinks: Vec::new(),
+            reading_stats: ReadingStats::default(),
+            excerpt: None,
         };
         let body = ArticleBody::new(format!("<p>{title}</p>")).unwrap();
         PublishableArticle::new(meta, body)
@@ -278,14 +536,14 @@ mod tests {
             build_article(
                 "Low",
                 "low000000001",
-                Category::Tech,
+                Category::new("tech".to_string()).unwrap(),
                 Some(1),
                 "2025-01-01T00:00:00+09:00",
             ),
             build_article(
                 "High",
                 "high00000002",
-                Category::Tech,
+                Category::new("tech".to_string()).unwrap(),
                 Some(10),
                 "2025-01-02T00:00:00+09:00",
             ),
@@ -303,14 +561,14 @@ mod tests {
             build_article(
                 "Tech",
                 "tech00000001",
-                Category::Tech,
+                Category::new("tech".to_string()).unwrap(),
                 Some(1),
                 "2025-01-01T00:00:00+09:00",
             ),
             build_article(
                 "Daily",
                 "daily0000001",
-                Category::Daily,
+                Category::new("daily".to_string()).unwrap(),
                 Some(1),
                 "2025-01-02T00:00:00+09:00",
             ),
@@ -320,21 +578,21 @@ mod tests {
         let indexes = build_category_indexes(
             &metas,
             vec![
-                build_category_landing(Category::Tech, "Technology"),
-                build_category_landing(Category::Physics, "Physics"),
+                build_category_landing(Category::new("tech".to_string()).unwrap(), "Technology"),
+                build_category_landing(Category::new("physics".to_string()).unwrap(), "Physics"),
             ],
         );
 
         assert_eq!(indexes.len(), 3);
-        assert_eq!(indexes[0].category, Category::Daily);
+        assert_eq!(indexes[0].category.as_str(), "daily");
         assert_eq!(indexes[0].articles.len(), 1);
-        assert_eq!(indexes[1].category, Category::Physics);
+        assert_eq!(indexes[1].category.as_str(), "physics");
         assert_eq!(indexes[1].articles.len(), 0);
         assert_eq!(
             indexes[1].landing.as_ref().unwrap().title.as_str(),
             "Physics"
         );
-        assert_eq!(indexes[2].category, Category::Tech);
+        assert_eq!(indexes[2].category.as_str(), "tech");
         assert_eq!(indexes[2].articles.len(), 1);
         assert_eq!(
             indexes[2].landing.as_ref().unwrap().title.as_str(),
@@ -342,17 +600,293 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_build_tag_indexes_groups_articles_by_tag() {
+        let mut rust = build_article(
+            "Rust",
+            "rust00000001",
+            Category::new("tech".to_string()).unwrap(),
+            Some(1),
+            "2025-01-01T00:00:00+09:00",
+        )
+        .meta;
+        rust.tags.push(Tag::new("rust".to_string()).unwrap());
+        let daily = build_article(
+            "Daily",
+            "daily0000001",
+            Category::new("daily".to_string()).unwrap(),
+            Some(10),
+            "2025-01-02T00:00:00+09:00",
+        )
+        .meta;
+
+        let indexes = build_tag_indexes(&[rust, daily]);
+
+        assert_eq!(indexes.len(), 2);
+        assert_eq!(indexes[0].tag.as_str(), "rust");
+        assert_eq!(indexes[0].articles.len(), 1);
+        assert_eq!(indexes[1].tag.as_str(), "tag");
+        let slugs: Vec<_> = indexes[1]
+            .articles
+            .iter()
+            .map(|article| article.slug.as_str())
+            .collect();
+        assert_eq!(slugs, ["daily0000001", "rust00000001"]);
+    }
+
+    fn registry(keys: &[(&str, i32)]) -> CategoryRegistry {
+        CategoryRegistry::new(
+            keys.iter()
+                .map(|(key, sort_order)| CategoryDefinition {
+                    key: Category::new(key.to_string()).unwrap(),
+                    display_name: key.to_uppercase(),
+                    description: None,
+                    sort_order: *sort_order,
+                    icon: None,
+                })
+                .collect(),
+        )
+        .unwrap()
+    }
+
     #[test]
     fn test_build_site_metadata_includes_landing_only_category() {
         let indexes = build_category_indexes(
             &[],
-            vec![build_category_landing(Category::Physics, "Physics")],
+            vec![build_category_landing(
+                Category::new("physics".to_string()).unwrap(),
+                "Physics",
+            )],
         );
-        let metadata = build_site_metadata(&indexes);
+        let metadata = build_site_metadata(&indexes, &registry(&[("physics", 0)])).unwrap();
 
         assert_eq!(metadata.total_articles, 0);
         assert_eq!(metadata.categories.len(), 1);
-        assert_eq!(metadata.categories[0].category, Category::Physics);
+        assert_eq!(metadata.categories[0].definition.key.as_str(), "physics");
+        assert_eq!(metadata.categories[0].definition.display_name, "PHYSICS");
         assert_eq!(metadata.categories[0].article_count, 0);
     }
+
+    #[test]
+    fn test_build_site_metadata_follows_registry_order_and_rejects_unknown_categories() {
+        let indexes = build_category_indexes(
+            &[],
+            vec![
+                build_category_landing(Category::new("daily".to_string()).unwrap(), "Daily"),
+                build_category_landing(Category::new("tech".to_string()).unwrap(), "Tech"),
+            ],
+        );
+
+        let metadata =
+            build_site_metadata(&indexes, &registry(&[("daily", 20), ("tech", 10)])).unwrap();
+        let keys: Vec<_> = metadata
+            .categories
+            .iter()
+            .map(|category| category.definition.key.as_str())
+            .collect();
+        assert_eq!(keys, ["tech", "daily"]);
+
+        assert!(matches!(
+            build_site_metadata(&indexes, &registry(&[("tech", 0)])),
+            Err(DomainError::InvalidCategory { category }) if category == "daily"
+        ));
+    }
+
+    fn series_article(slug: &str, series: &str, order: u32) -> ArticleMeta {
+        let mut meta = build_article(
+            slug,
+            slug,
+            Category::new("tech".to_string()).unwrap(),
+            None,
+            "2025-01-01T00:00:00+09:00",
+        )
+        .meta;
+        meta.series = Some(SeriesPart {
+            series: SeriesKey::new(series.to_string()).unwrap(),
+            order,
+        });
+        meta
+    }
+
+    #[test]
+    fn test_build_series_orders_parts_and_skips_standalone_articles() {
+        let standalone = build_article(
+            "Standalone",
+            "standalone01",
+            Category::new("tech".to_string()).unwrap(),
+            None,
+            "2025-01-01T00:00:00+09:00",
+        )
+        .meta;
+        let series = build_series(&[
+            series_article("async0000002", "rust-async", 2),
+            standalone,
+            series_article("async0000001", "rust-async", 1),
+            series_article("cargo0000001", "cargo", 1),
+        ])
+        .unwrap();
+
+        assert_eq!(series.len(), 2);
+        assert_eq!(series[0].key.as_str(), "cargo");
+        assert_eq!(series[1].key.as_str(), "rust-async");
+        let slugs: Vec<_> = series[1]
+            .articles
+            .iter()
+            .map(|article| article.slug.as_str())
+            .collect();
+        assert_eq!(slugs, ["async0000001", "async0000002"]);
+    }
+
+    #[test]
+    fn test_build_series_rejects_duplicate_and_missing_parts() {
+        for (orders, reason) in [
+            (&[1, 1][..], "more than one article"),
+            (&[1, 3][..], "part 2 is missing"),
+            (&[2, 3][..], "part 1 is missing"),
+            (&[0, 1][..], "start at 1"),
+        ] {
+            let metas: Vec<_> = orders
+                .iter()
+                .enumerate()
+                .map(|(index, order)| series_article(&format!("part{index:08}"), "rust", *order))
+                .collect();
+
+            assert!(
+                matches!(
+                    build_series(&metas),
+                    Err(DomainError::InvalidSeries { series, reason: actual })
+                        if series == "rust" && actual.contains(reason)
+                ),
+                "{orders:?}"
+            );
+        }
+    }
+
+    fn related_article(
+        slug: &str,
+        category: &str,
+        section_path: &[&str],
+        tags: &[&str],
+        updated_at: &str,
+    ) -> ArticleMeta {
+        let mut meta = build_article(
+            slug,
+            slug,
+            Category::new(category.to_string()).unwrap(),
+            None,
+            updated_at,
+        )
+        .meta;
+        meta.section_path =
+            SectionPath::new(section_path.iter().map(ToString::to_string).collect());
+        meta.tags = tags
+            .iter()
+            .map(|tag| Tag::new(tag.to_string()).unwrap())
+            .collect();
+        meta
+    }
+
+    fn related_slugs(related: &[ArticleRef]) -> Vec<&str> {
+        related
+            .iter()
+            .map(|article| article.slug.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_rank_related_articles_scores_tags_sections_and_co_citations() {
+        let mut citing = related_article("citing", "daily", &[], &[], "2025-01-01T00:00:00+09:00");
+        let mut metas = vec![
+            related_article(
+                "current",
+                "tech",
+                &["rust"],
+                &["rust", "wasm"],
+                "2025-01-01T00:00:00+09:00",
+            ),
+            related_article(
+                "two-tags",
+                "daily",
+                &[],
+                &["rust", "wasm"],
+                "2025-01-01T00:00:00+09:00",
+            ),
+            related_article(
+                "one-tag-old",
+                "daily",
+                &[],
+                &["rust"],
+                "2025-01-01T00:00:00+09:00",
+            ),
+            related_article(
+                "one-tag-new",
+                "daily",
+                &[],
+                &["rust"],
+                "2025-01-05T00:00:00+09:00",
+            ),
+            related_article(
+                "same-section",
+                "tech",
+                &["rust"],
+                &[],
+                "2025-01-01T00:00:00+09:00",
+            ),
+            related_article(
+                "cited-together",
+                "physics",
+                &[],
+                &[],
+                "2025-01-01T00:00:00+09:00",
+            ),
+            related_article(
+                "same-category",
+                "tech",
+                &["go"],
+                &[],
+                "2025-01-01T00:00:00+09:00",
+            ),
+        ];
+        citing.links = vec![metas[0].article_ref(), metas[5].article_ref()];
+        metas.push(citing);
+
+        let related = rank_related_articles(&metas, 10);
+
+        assert_eq!(
+            related_slugs(&related[0]),
+            [
+                "two-tags",
+                "one-tag-new",
+                "one-tag-old",
+                "cited-together",
+                "citing",
+                "same-section"
+            ]
+        );
+        assert_eq!(
+            related_slugs(&rank_related_articles(&metas, 2)[0]),
+            ["two-tags", "one-tag-new"]
+        );
+        assert!(related[6].is_empty());
+    }
+
+    #[test]
+    fn test_collect_backlinks_lists_linking_articles_newest_first() {
+        let mut metas = vec![
+            related_article("target", "tech", &[], &[], "2025-01-01T00:00:00+09:00"),
+            related_article("old-source", "tech", &[], &[], "2025-01-02T00:00:00+09:00"),
+            related_article("new-source", "daily", &[], &[], "2025-01-03T00:00:00+09:00"),
+            related_article("unlinked", "tech", &[], &[], "2025-01-04T00:00:00+09:00"),
+        ];
+        let target = metas[0].article_ref();
+        metas[1].links = vec![target.clone()];
+        metas[2].links = vec![target, metas[1].article_ref()];
+
+        let backlinks = collect_backlinks(&metas);
+
+        assert_eq!(related_slugs(&backlinks[0]), ["new-source", "old-source"]);
+        assert_eq!(related_slugs(&backlinks[1]), ["new-source"]);
+        assert!(backlinks[2].is_empty());
+        assert!(backlinks[3].is_empty());
+    }
 }
@@ -0,0 +1,123 @@
+//! Article length measured so that Japanese and Latin-script text compare fairly.
+
+use serde::{Deserialize, Serialize};
+
+/// Japanese characters read per minute.
+const CJK_CHARS_PER_MINUTE: usize = 500;
+/// Latin-script words read per minute.
+const WORDS_PER_MINUTE: usize = 200;
+
+/// Character count and estimated reading time of an article body.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReadingStats {
+    /// Characters other than whitespace, so each kana or kanji counts once.
+    pub char_count: usize,
+    /// Whole minutes rounded up, at least one for any non-empty text.
+    pub reading_minutes: u32,
+}
+
+impl ReadingStats {
+    /// Measures plain text, reading CJK text per character and other scripts per word.
+    pub fn measure(text: &str) -> Self {
+        let mut char_count = 0;
+        let mut cjk_chars = 0;
+        let mut words = 0;
+        let mut in_word = false;
+        for ch in text.chars() {
+            if ch.is_whitespace() {
+                in_word = false;
+                continue;
+            }
+            char_count += 1;
+            if is_cjk(ch) {
+                cjk_chars += 1;
+                in_word = false;
+            } else if ch.is_alphanumeric() {
+                if !in_word {
+                    words += 1;
+                }
+                in_word = true;
+            }
+        }
+
+        // Integer arithmetic over a common denominator keeps the estimate deterministic.
+        let per_minute = CJK_CHARS_PER_MINUTE * WORDS_PER_MINUTE;
+        let reading_cost = cjk_chars * WORDS_PER_MINUTE + words * CJK_CHARS_PER_MINUTE;
+        let reading_minutes = match reading_cost.div_ceil(per_minute) {
+            0 if char_count > 0 => 1,
+            minutes => u32::try_from(minutes).unwrap_or(u32::MAX),
+        };
+
+        Self {
+            char_count,
+            reading_minutes,
+        }
+    }
+}
+
+/// Characters read one at a time: kana, kanji, hangul, and fullwidth forms and punctuation.
+fn is_cjk(ch: char) -> bool {
+    matches!(
+        ch,
+        '\u{3000}'..='\u{30ff}'
+            | '\u{3400}'..='\u{4dbf}'
+            | '\u{4e00}'..='\u{9fff}'
+            | '\u{ac00}'..='\u{d7af}'
+            | '\u{f900}'..='\u{faff}'
+            | '\u{ff00}'..='\u{ffef}'
+            | '\u{20000}'..='\u{2ffff}'
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_measure_counts_japanese_characters_without_whitespace() {
+        let stats = ReadingStats::measure("吾輩は 猫である。\n名前はまだ無い。");
+
+        assert_eq!(stats.char_count, 16);
+        assert_eq!(stats.reading_minutes, 1);
+    }
+
+    #[test]
+    fn test_measure_reads_cjk_per_character_and_latin_per_word() {
+        let japanese = "あ".repeat(1001);
+        assert_eq!(
+            ReadingStats::measure(&japanese),
+            ReadingStats {
+                char_count: 1001,
+                reading_minutes: 3,
+            }
+        );
+
+        let english = "word ".repeat(400);
+        assert_eq!(
+            ReadingStats::measure(&english),
+            ReadingStats {
+                char_count: 1600,
+                reading_minutes: 2,
+            }
+        );
+
+        // 250 characters (half a minute) plus 100 words (half a minute) make exactly one minute.
+        let mixed = format!("{}{}", "漢".repeat(250), " Rust".repeat(100));
+        assert_eq!(ReadingStats::measure(&mixed).reading_minutes, 1);
+    }
+
+    #[test]
+    fn test_measure_treats_punctuation_as_part_of_words() {
+        let stats = ReadingStats::measure("don't stop, e.g. v1.2");
+        assert_eq!(stats.char_count, 18);
+
+        // Each of the 100 phrases is four words rather than seven, so they take exactly two minutes.
+        let phrases = "don't stop, e.g. v1.2 ".repeat(100);
+        assert_eq!(ReadingStats::measure(&phrases).reading_minutes, 2);
+    }
+
+    #[test]
+    fn test_measure_empty_text_takes_no_time() {
+        assert_eq!(ReadingStats::measure(" \n\t"), ReadingStats::default());
+    }
+}
@@ -1,12 +1,12 @@
 //! Shared page contracts built from persisted artifact documents.
 
 use crate::{
-    ArticleIndexDocument, ArticleSummaryDocument, Category, CategoryArtifactDocument, DomainError,
-    HomeFragmentArtifactDocument, PageArtifactDocument, PageKey, Result, SectionPath,
-    SiteMetadataDocument, Slug, Title,
+    ArticleIndexDocument, ArticleOutlineDocument, ArticleRefDocument, ArticleSummaryDocument,
+    AssetName, Category, CategoryArtifactDocument, CategoryRegistry, DomainError,
+    HomeFragmentArtifactDocument, PageArtifactDocument, PageKey, ReadingStats, Result, SectionPath,
+    SeriesArtifactDocument, SiteMetadataDocument, Slug, Tag, TagArtifactDocument, Title, TocEntry,
 };
 use serde::{Deserialize, Serialize};
-use std::str::FromStr;
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SiteArticleCard {
@@ -16,29 +16,51 @@ pub struct SiteArticleCard {
     pub category_display_name: String,
     pub section_path: SectionPath,
     pub description: Option<String>,
-    pub tags: Vec<String>,
+    pub tags: Vec<Tag>,
     pub priority: Option<i32>,
     pub created_at: String,
     pub updated_at: String,
+    pub reading_stats: ReadingStats,
+    pub excerpt: Option<String>,
 }
 
-impl TryFrom<&ArticleSummaryDocument> for SiteArticleCard {
+impl SiteArticleCard {
+    /// Author-written description, or the body excerpt when there is none.
+    pub fn summary_text(&self) -> Option<&str> {
+        self.description
+            .as_deref()
+            .filter(|description| !description.trim().is_empty())
+            .or(self.excerpt.as_deref())
+    }
+}
+
+impl TryFrom<(&ArticleSummaryDocument, &CategoryRegistry)> for SiteArticleCard {
     type Error = DomainError;
 
-    fn try_from(summary: &ArticleSummaryDocument) -> Result<Self> {
-        let category = Category::from_str(&summary.category)?;
+    fn try_from((summary, registry): (&ArticleSummaryDocument, &CategoryRegistry)) -> Result<Self> {
+        let category = registry.resolve(&summary.category)?;
+        let category_display_name = registry.display_name(&category)?.to_string();
 
         Ok(Self {
             slug: Slug::new(summary.slug.clone())?,
             title: Title::new(summary.title.clone())?,
             category,
-            category_display_name: category.display_name().to_string(),
+            category_display_name,
             section_path: summary.section_path.clone(),
             description: summary.description.clone(),
-            tags: summary.tags.clone(),
+            tags: summary
+                .tags
+                .iter()
+                .map(|tag| Tag::new(tag.clone()))
+                .collect::<Result<_>>()?,
             priority: summary.priority,
             created_at: summary.created_at.clone(),
             updated_at: summary.updated_at.clone(),
+            reading_stats: ReadingStats {
+                char_count: summary.char_count,
+                reading_minutes: summary.reading_minutes,
+            },
+            excerpt: summary.excerpt.clone(),
         })
     }
 }
@@ -47,6 +69,8 @@ impl TryFrom<&ArticleSummaryDocument> for SiteArticleCard {
 pub struct SiteCategorySummary {
     pub category: Category,
     pub category_display_name: String,
+    pub description: Option<String>,
+    pub icon: Option<String>,
     pub article_count: usize,
 }
 
@@ -69,6 +93,39 @@ pub struct HomeFragmentDocument {
 pub struct ArticlePageDocument {
     pub article: SiteArticleCard,
     pub html: String,
+    pub toc: Vec<TocEntry>,
+    pub series: Option<SeriesNavigation>,
+    pub related: Vec<SiteArticleCard>,
+    pub backlinks: Vec<SiteArticleCard>,
+}
+
+/// Part list of the series an article belongs to, positioned at that article.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SeriesNavigation {
+    pub series: String,
+    pub current_order: u32,
+    pub parts: Vec<SeriesPartLink>,
+}
+
+impl SeriesNavigation {
+    pub fn previous(&self) -> Option<&SeriesPartLink> {
+        self.parts
+            .iter()
+            .find(|part| part.order + 1 == self.current_order)
+    }
+
+    pub fn next(&self) -> Option<&SeriesPartLink> {
+        self.parts
+            .iter()
+            .find(|part| part.order == self.current_order + 1)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SeriesPartLink {
+    pub order: u32,
+    pub title: String,
+    pub path: String,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -82,6 +139,12 @@ pub struct CategoryPageDocument {
     pub articles: Vec<SiteArticleCard>,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TagPageDocument {
+    pub tag: Tag,
+    pub articles: Vec<SiteArticleCard>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct StaticPageDocument {
     pub page: PageKey,
@@ -121,6 +184,15 @@ pub fn build_article_path(category: &Category, slug: &Slug) -> String {
     format!("{}/{}", build_category_path(category), slug.as_str())
 }
 
+pub fn build_tag_path(tag: &Tag) -> String {
+    format!("/tags/{}", tag.path_segment())
+}
+
+/// Public path of a published attachment. `/assets` is reserved for the web bundle.
+pub fn build_asset_path(asset: &AssetName) -> String {
+    format!("/media/{}", asset.as_str())
+}
+
 pub fn build_article_page_title(document: &ArticlePageDocument, site_name: &str) -> String {
     format!("{} | {}", document.article.title.as_str(), site_name)
 }
@@ -128,9 +200,7 @@ pub fn build_article_page_title(document: &ArticlePageDocument, site_name: &str)
 pub fn build_article_page_description(document: &ArticlePageDocument) -> String {
     document
         .article
-        .description
-        .as_deref()
-        .filter(|description| !description.trim().is_empty())
+        .summary_text()
         .map(str::to_owned)
         .unwrap_or_else(|| {
             format!(
@@ -167,6 +237,22 @@ pub fn build_category_page_canonical_path(document: &CategoryPageDocument) -> St
     build_category_path(&document.category)
 }
 
+pub fn build_tag_page_title(document: &TagPageDocument, site_name: &str) -> String {
+    format!("#{} | {}", document.tag.as_str(), site_name)
+}
+
+pub fn build_tag_page_description(document: &TagPageDocument) -> String {
+    format!(
+        "#{} のタグが付いた{}件の記事です。",
+        document.tag.as_str(),
+        document.articles.len()
+    )
+}
+
+pub fn build_tag_page_canonical_path(document: &TagPageDocument) -> String {
+    build_tag_path(&document.tag)
+}
+
 pub fn build_static_page_title(document: &StaticPageDocument, site_name: &str) -> String {
     format!("{} | {}", document.title, site_name)
 }
@@ -189,27 +275,12 @@ pub fn build_home_page_document(
     site_metadata: &SiteMetadataDocument,
     home_fragment: Option<&HomeFragmentArtifactDocument>,
 ) -> Result<HomePageDocument> {
-    let articles = article_index
-        .articles
-        .iter()
-        .map(SiteArticleCard::try_from)
-        .collect::<Result<Vec<_>>>()?;
-    let categories = site_metadata
-        .categories
-        .iter()
-        .map(|category| {
-            let parsed = Category::from_str(&category.category)?;
-            Ok(SiteCategorySummary {
-                category: parsed,
-                category_display_name: parsed.display_name().to_string(),
-                article_count: category.article_count,
-            })
-        })
-        .collect::<Result<Vec<_>>>()?;
+    let registry = CategoryRegistry::try_from(site_metadata)?;
+    let articles = build_article_cards(&article_index.articles, &registry)?;
 
     Ok(HomePageDocument {
         total_articles: site_metadata.total_articles,
-        categories,
+        categories: build_site_category_summaries(site_metadata)?,
         articles,
         fragment: home_fragment
             .map(build_home_fragment_document)
@@ -217,6 +288,27 @@ pub fn build_home_page_document(
     })
 }
 
+/// Lists published categories in the configured navigation order.
+pub fn build_site_category_summaries(
+    site_metadata: &SiteMetadataDocument,
+) -> Result<Vec<SiteCategorySummary>> {
+    let registry = CategoryRegistry::try_from(site_metadata)?;
+    Ok(registry
+        .iter()
+        .map(|definition| SiteCategorySummary {
+            category: definition.key.clone(),
+            category_display_name: definition.display_name.clone(),
+            description: definition.description.clone(),
+            icon: definition.icon.clone(),
+            article_count: site_metadata
+                .categories
+                .iter()
+                .find(|category| category.category == definition.key.as_str())
+                .map_or(0, |category| category.article_count),
+        })
+        .collect())
+}
+
 pub fn build_home_fragment_document(
     artifact: &HomeFragmentArtifactDocument,
 ) -> Result<HomeFragmentDocument> {
@@ -238,24 +330,90 @@ pub fn build_home_fragment_document(
     })
 }
 
+/// Builds an article page; `series` must be the artifact named by the summary, if any.
+///
+/// Related articles and backlinks missing from `article_index` are skipped, so hidden articles
+/// never surface.
 pub fn build_article_page_document(
     summary: &ArticleSummaryDocument,
     html: &str,
+    outline: &ArticleOutlineDocument,
+    series: Option<&SeriesArtifactDocument>,
+    article_index: &ArticleIndexDocument,
+    registry: &CategoryRegistry,
 ) -> Result<ArticlePageDocument> {
     if html.trim().is_empty() {
         return Err(DomainError::validation("html"));
     }
 
+    let series = match (&summary.series, series) {
+        (None, _) => None,
+        (Some(part), Some(artifact)) if artifact.series == part.series => {
+            Some(build_series_navigation(artifact, part.order, registry)?)
+        }
+        (Some(_), _) => return Err(DomainError::validation("series")),
+    };
+
     Ok(ArticlePageDocument {
-        article: SiteArticleCard::try_from(summary)?,
+        article: SiteArticleCard::try_from((summary, registry))?,
         html: html.to_string(),
+        toc: outline.toc.clone(),
+        series,
+        related: build_referenced_article_cards(&summary.related, article_index, registry)?,
+        backlinks: build_referenced_article_cards(&summary.backlinks, article_index, registry)?,
+    })
+}
+
+fn build_referenced_article_cards(
+    references: &[ArticleRefDocument],
+    article_index: &ArticleIndexDocument,
+    registry: &CategoryRegistry,
+) -> Result<Vec<SiteArticleCard>> {
+    references
+        .iter()
+        .filter_map(|reference| {
+            article_index.articles.iter().find(|article| {
+                article.category == reference.category && article.slug == reference.slug
+            })
+        })
+        .map(|article| SiteArticleCard::try_from((article, registry)))
+        .collect()
+}
+
+fn build_series_navigation(
+    artifact: &SeriesArtifactDocument,
+    current_order: u32,
+    registry: &CategoryRegistry,
+) -> Result<SeriesNavigation> {
+    let parts = (1..)
+        .zip(&artifact.articles)
+        .map(|(order, article)| {
+            let category = registry.resolve(&article.category)?;
+            let slug = Slug::new(article.slug.clone())?;
+            Ok(SeriesPartLink {
+                order,
+                title: article.title.clone(),
+                path: build_article_path(&category, &slug),
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    if !parts.iter().any(|part| part.order == current_order) {
+        return Err(DomainError::validation("series_order"));
+    }
+
+    Ok(SeriesNavigation {
+        series: artifact.series.clone(),
+        current_order,
+        parts,
     })
 }
 
 pub fn build_category_page_document(
     artifact: &CategoryArtifactDocument,
+    registry: &CategoryRegistry,
 ) -> Result<CategoryPageDocument> {
-    let category = Category::from_str(&artifact.category)?;
+    let category = registry.resolve(&artifact.category)?;
+    let category_display_name = registry.display_name(&category)?.to_string();
     let title = artifact.title.trim();
     if title.is_empty() {
         return Err(DomainError::validation("title"));
@@ -265,17 +423,13 @@ pub fn build_category_page_document(
         return Err(DomainError::validation("html"));
     }
 
-    let articles = artifact
-        .articles
-        .iter()
-        .map(SiteArticleCard::try_from)
-        .collect::<Result<Vec<_>>>()?;
+    let articles = build_article_cards(&artifact.articles, registry)?;
     let sections = build_category_section_groups(&articles);
 
     Ok(CategoryPageDocument {
         category,
         title: title.to_string(),
-        category_display_name: category.display_name().to_string(),
+        category_display_name,
         description: artifact.description.clone(),
         html: artifact.html.clone(),
         sections,
@@ -283,6 +437,16 @@ pub fn build_category_page_document(
     })
 }
 
+pub fn build_tag_page_document(
+    artifact: &TagArtifactDocument,
+    registry: &CategoryRegistry,
+) -> Result<TagPageDocument> {
+    Ok(TagPageDocument {
+        tag: Tag::new(artifact.tag.clone())?,
+        articles: build_article_cards(&artifact.articles, registry)?,
+    })
+}
+
 pub fn build_static_page_document(artifact: &PageArtifactDocument) -> Result<StaticPageDocument> {
     let title = artifact.title.trim();
     let html = artifact.html.trim();
@@ -314,6 +478,16 @@ pub fn find_article_summary<'a>(
         .find(|article| article.slug == slug.as_str() && article.category == category.as_str())
 }
 
+fn build_article_cards(
+    summaries: &[ArticleSummaryDocument],
+    registry: &CategoryRegistry,
+) -> Result<Vec<SiteArticleCard>> {
+    summaries
+        .iter()
+        .map(|summary| SiteArticleCard::try_from((summary, registry)))
+        .collect()
+}
+
 fn build_category_section_groups(articles: &[SiteArticleCard]) -> Vec<CategorySectionGroup> {
     use std::collections::BTreeMap;
 
@@ -346,7 +520,29 @@ fn build_section_heading(section_path: &SectionPath) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::CategoryMetadataDocument;
+    use crate::{CategoryMetadataDocument, SeriesPartDocument};
+
+    fn sample_category(key: &str, display_name: &str) -> CategoryMetadataDocument {
+        CategoryMetadataDocument {
+            category: key.to_string(),
+            display_name: display_name.to_string(),
+            description: None,
+            sort_order: 0,
+            icon: None,
+            article_count: 1,
+        }
+    }
+
+    fn sample_registry() -> CategoryRegistry {
+        CategoryRegistry::try_from(&SiteMetadataDocument {
+            total_articles: 2,
+            categories: vec![
+                sample_category("tech", "技術"),
+                sample_category("daily", "日常"),
+            ],
+        })
+        .unwrap()
+    }
 
     fn sample_summary() -> ArticleSummaryDocument {
         ArticleSummaryDocument {
@@ -355,21 +551,68 @@ mod tests {
             category: "tech".to_string(),
             section_path: SectionPath::new(vec!["block".to_string()]),
             description: Some("summary".to_string()),
-            tags: vec!["rust".to_string()],
+            tags: vec!["Rust".to_string()],
             priority: Some(10),
             created_at: "2025-01-01T00:00:00+09:00".to_string(),
             updated_at: "2025-01-02T00:00:00+09:00".to_string(),
+            series: None,
+            publish_at: None,
+            related: vec![],
+            backlinks: Vec::new(),
+            char_count: 0,
+            reading_minutes: 0,
+            excerpt: None,
+        }
+    }
+
+    fn sample_outline() -> ArticleOutlineDocument {
+        ArticleOutlineDocument {
+            toc: vec![TocEntry {
+                level: 2,
+                text: "Setup".to_string(),
+                id: "setup".to_string(),
+            }],
+        }
+    }
+
+    fn sample_index() -> ArticleIndexDocument {
+        ArticleIndexDocument {
+            articles: vec![sample_summary()],
         }
     }
 
     #[test]
     fn test_build_site_article_card() {
-        let card = SiteArticleCard::try_from(&sample_summary()).unwrap();
+        let card = SiteArticleCard::try_from((&sample_summary(), &sample_registry())).unwrap();
 
         assert_eq!(card.slug.as_str(), "intro00000001");
         assert_eq!(card.title.as_str(), "Intro");
-        assert_eq!(card.category, Category::Tech);
+        assert_eq!(card.category.as_str(), "tech");
         assert_eq!(card.category_display_name, "技術");
+        assert_eq!(card.tags, ["rust".parse::<Tag>().unwrap()]);
+    }
+
+    #[test]
+    fn test_build_tag_page_document_and_metadata() {
+        let document = build_tag_page_document(
+            &TagArtifactDocument {
+                tag: "rust".to_string(),
+                articles: vec![sample_summary()],
+            },
+            &sample_registry(),
+        )
+        .unwrap();
+
+        assert_eq!(document.articles[0].slug.as_str(), "intro00000001");
+        assert_eq!(
+            build_tag_page_title(&document, "ぶくせんの探窟メモ"),
+            "#rust | ぶくせんの探窟メモ"
+        );
+        assert_eq!(
+            build_tag_page_description(&document),
+            "#rust のタグが付いた1件の記事です。"
+        );
+        assert_eq!(build_tag_page_canonical_path(&document), "/tags/rust");
     }
 
     #[test]
@@ -380,10 +623,7 @@ mod tests {
             },
             &SiteMetadataDocument {
                 total_articles: 1,
-                categories: vec![CategoryMetadataDocument {
-                    category: "tech".to_string(),
-                    article_count: 1,
-                }],
+                categories: vec![sample_category("tech", "技術")],
             },
             None,
         )
@@ -396,6 +636,30 @@ mod tests {
         assert_eq!(document.fragment, None);
     }
 
+    #[test]
+    fn test_build_site_category_summaries_follow_sort_order() {
+        let summaries = build_site_category_summaries(&SiteMetadataDocument {
+            total_articles: 2,
+            categories: vec![
+                CategoryMetadataDocument {
+                    sort_order: 20,
+                    ..sample_category("tech", "技術")
+                },
+                CategoryMetadataDocument {
+                    sort_order: 10,
+                    icon: Some("fa-solid fa-mug-hot".to_string()),
+                    ..sample_category("daily", "日常")
+                },
+            ],
+        })
+        .unwrap();
+
+        assert_eq!(summaries.len(), 2);
+        assert_eq!(summaries[0].category.as_str(), "daily");
+        assert_eq!(summaries[0].icon.as_deref(), Some("fa-solid fa-mug-hot"));
+        assert_eq!(summaries[1].category_display_name, "技術");
+    }
+
     #[test]
     fn test_build_home_page_document_with_fragment() {
         let fragment = HomeFragmentArtifactDocument {
@@ -410,10 +674,7 @@ mod tests {
             },
             &SiteMetadataDocument {
                 total_articles: 1,
-                categories: vec![CategoryMetadataDocument {
-                    category: "tech".to_string(),
-                    article_count: 1,
-                }],
+                categories: vec![sample_category("tech", "技術")],
             },
             Some(&fragment),
         )
@@ -425,37 +686,198 @@ mod tests {
 
     #[test]
     fn test_build_article_page_document() {
-        let document =
-            build_article_page_document(&sample_summary(), "<article><h1>Intro</h1></article>")
-                .unwrap();
+        let document = build_article_page_document(
+            &sample_summary(),
+            "<article><h1>Intro</h1></article>",
+            &sample_outline(),
+            None,
+            &sample_index(),
+            &sample_registry(),
+        )
+        .unwrap();
 
         assert_eq!(document.article.slug.as_str(), "intro00000001");
         assert!(document.html.contains("<h1>Intro</h1>"));
+        assert_eq!(document.toc, sample_outline().toc);
+        assert!(document.related.is_empty());
+    }
+
+    #[test]
+    fn test_build_article_page_document_resolves_related_articles_and_backlinks_in_index() {
+        let related = ArticleSummaryDocument {
+            slug: "related00001".to_string(),
+            title: "Related".to_string(),
+            ..sample_summary()
+        };
+        let summary = ArticleSummaryDocument {
+            related: vec![
+                ArticleRefDocument {
+                    category: "tech".to_string(),
+                    slug: "hidden000001".to_string(),
+                },
+                ArticleRefDocument {
+                    category: "tech".to_string(),
+                    slug: "related00001".to_string(),
+                },
+            ],
+            backlinks: vec![ArticleRefDocument {
+                category: "tech".to_string(),
+                slug: "related00001".to_string(),
+            }],
+            ..sample_summary()
+        };
+        let article_index = ArticleIndexDocument {
+            articles: vec![summary.clone(), related],
+        };
+
+        let document = build_article_page_document(
+            &summary,
+            "<article><h1>Intro</h1></article>",
+            &ArticleOutlineDocument::default(),
+            None,
+            &article_index,
+            &sample_registry(),
+        )
+        .unwrap();
+
+        let titles: Vec<_> = document
+            .related
+            .iter()
+            .map(|article| article.title.as_str())
+            .collect();
+        assert_eq!(titles, ["Related"]);
+        assert_eq!(document.backlinks, document.related);
+    }
+
+    #[test]
+    fn test_build_category_page_document_rejects_unregistered_category() {
+        let result = build_category_page_document(
+            &CategoryArtifactDocument {
+                category: "physics".to_string(),
+                title: "Physics".to_string(),
+                description: None,
+                html: "<article><h1>Physics</h1></article>".to_string(),
+                updated_at: "2025-01-01T00:00:00+09:00".to_string(),
+                articles: vec![],
+            },
+            &sample_registry(),
+        );
+
+        assert_eq!(
+            result,
+            Err(DomainError::InvalidCategory {
+                category: "physics".to_string()
+            })
+        );
     }
 
     #[test]
     fn test_build_article_page_document_rejects_blank_html() {
-        let result = build_article_page_document(&sample_summary(), "   ");
+        let result = build_article_page_document(
+            &sample_summary(),
+            "   ",
+            &ArticleOutlineDocument::default(),
+            None,
+            &sample_index(),
+            &sample_registry(),
+        );
 
         assert_eq!(result, Err(DomainError::validation("html")));
     }
 
+    fn sample_series() -> SeriesArtifactDocument {
+        let part = |slug: &str, title: &str, order| ArticleSummaryDocument {
+            slug: slug.to_string(),
+            title: title.to_string(),
+            series: Some(SeriesPartDocument {
+                series: "rust-async".to_string(),
+                order,
+            }),
+            ..sample_summary()
+        };
+
+        SeriesArtifactDocument {
+            series: "rust-async".to_string(),
+            articles: vec![
+                part("basics000001", "Basics", 1),
+                part("intro00000001", "Intro", 2),
+                part("runtime00001", "Runtime", 3),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_build_article_page_document_links_series_neighbors() {
+        let series = sample_series();
+        let document = build_article_page_document(
+            &series.articles[1],
+            "<article><h1>Intro</h1></article>",
+            &ArticleOutlineDocument::default(),
+            Some(&series),
+            &sample_index(),
+            &sample_registry(),
+        )
+        .unwrap();
+
+        let navigation = document.series.unwrap();
+        assert_eq!(navigation.series, "rust-async");
+        assert_eq!(navigation.current_order, 2);
+        assert_eq!(navigation.parts.len(), 3);
+        assert_eq!(navigation.previous().unwrap().path, "/tech/basics000001");
+        assert_eq!(navigation.next().unwrap().title, "Runtime");
+    }
+
+    #[test]
+    fn test_build_article_page_document_rejects_missing_or_mismatched_series() {
+        let series = sample_series();
+        let summary = &series.articles[0];
+
+        assert_eq!(
+            build_article_page_document(
+                summary,
+                "<p>Basics</p>",
+                &ArticleOutlineDocument::default(),
+                None,
+                &sample_index(),
+                &sample_registry()
+            ),
+            Err(DomainError::validation("series"))
+        );
+        assert_eq!(
+            build_article_page_document(
+                summary,
+                "<p>Basics</p>",
+                &ArticleOutlineDocument::default(),
+                Some(&SeriesArtifactDocument {
+                    series: "cargo".to_string(),
+                    ..series.clone()
+                }),
+                &sample_index(),
+                &sample_registry(),
+            ),
+            Err(DomainError::validation("series"))
+        );
+    }
+
     #[test]
     fn test_build_category_page_document() {
-        let document = build_category_page_document(&CategoryArtifactDocument {
-            category: "daily".to_string(),
-            title: "Daily Notes".to_string(),
-            description: Some("Daily landing".to_string()),
-            html: "<article><h1>Daily Notes</h1></article>".to_string(),
-            updated_at: "2025-01-01T00:00:00+09:00".to_string(),
-            articles: vec![ArticleSummaryDocument {
+        let document = build_category_page_document(
+            &CategoryArtifactDocument {
                 category: "daily".to_string(),
-                ..sample_summary()
-            }],
-        })
+                title: "Daily Notes".to_string(),
+                description: Some("Daily landing".to_string()),
+                html: "<article><h1>Daily Notes</h1></article>".to_string(),
+                updated_at: "2025-01-01T00:00:00+09:00".to_string(),
+                articles: vec![ArticleSummaryDocument {
+                    category: "daily".to_string(),
+                    ..sample_summary()
+                }],
+            },
+            &sample_registry(),
+        )
         .unwrap();
 
-        assert_eq!(document.category, Category::Daily);
+        assert_eq!(document.category.as_str(), "daily");
         assert_eq!(document.title, "Daily Notes");
         assert_eq!(document.category_display_name, "日常");
         assert_eq!(document.description, Some("Daily landing".to_string()));
@@ -499,7 +921,7 @@ mod tests {
         let index = ArticleIndexDocument {
             articles: vec![sample_summary()],
         };
-        let category = Category::Tech;
+        let category = Category::new("tech".to_string()).unwrap();
         let slug = Slug::new("intro00000001".to_string()).unwrap();
 
         let article = find_article_summary(&index, &category, &slug).unwrap();
@@ -513,17 +935,23 @@ mod tests {
             total_articles: 3,
             categories: vec![
                 SiteCategorySummary {
-                    category: Category::Tech,
+                    category: Category::new("tech".to_string()).unwrap(),
                     category_display_name: "技術".to_string(),
+                    description: None,
+                    icon: None,
                     article_count: 2,
                 },
                 SiteCategorySummary {
-                    category: Category::Daily,
+                    category: Category::new("daily".to_string()).unwrap(),
                     category_display_name: "日常".to_string(),
+                    description: None,
+                    icon: None,
                     article_count: 1,
                 },
             ],
-            articles: vec![SiteArticleCard::try_from(&sample_summary()).unwrap()],
+            articles: vec![
+                SiteArticleCard::try_from((&sample_summary(), &sample_registry())).unwrap(),
+            ],
             fragment: None,
         };
 
@@ -540,9 +968,15 @@ mod tests {
 
     #[test]
     fn test_build_article_page_metadata() {
-        let document =
-            build_article_page_document(&sample_summary(), "<article><h1>Intro</h1></article>")
-                .unwrap();
+        let document = build_article_page_document(
+            &sample_summary(),
+            "<article><h1>Intro</h1></article>",
+            &ArticleOutlineDocument::default(),
+            None,
+            &sample_index(),
+            &sample_registry(),
+        )
+        .unwrap();
 
         assert_eq!(
             build_article_page_title(&document, "ぶくせんの探窟メモ"),
@@ -563,6 +997,10 @@ mod tests {
                 ..sample_summary()
             },
             "<article><h1>Intro</h1></article>",
+            &ArticleOutlineDocument::default(),
+            None,
+            &sample_index(),
+            &sample_registry(),
         )
         .unwrap();
 
@@ -572,6 +1010,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_build_article_page_description_uses_excerpt_when_missing() {
+        let document = build_article_page_document(
+            &ArticleSummaryDocument {
+                description: Some("   ".to_string()),
+                excerpt: Some("本文の冒頭です。".to_string()),
+                ..sample_summary()
+            },
+            "<article><h1>Intro</h1></article>",
+            &ArticleOutlineDocument::default(),
+            None,
+            &sample_index(),
+            &sample_registry(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            build_article_page_description(&document),
+            "本文の冒頭です。"
+        );
+    }
+
     #[test]
     fn test_build_article_page_description_falls_back_when_blank() {
         let document = build_article_page_document(
@@ -580,6 +1040,10 @@ mod tests {
                 ..sample_summary()
             },
             "<article><h1>Intro</h1></article>",
+            &ArticleOutlineDocument::default(),
+            None,
+            &sample_index(),
+            &sample_registry(),
         )
         .unwrap();
 
@@ -591,14 +1055,17 @@ mod tests {
 
     #[test]
     fn test_build_category_page_metadata() {
-        let document = build_category_page_document(&CategoryArtifactDocument {
-            category: "tech".to_string(),
-            title: "Rust".to_string(),
-            description: Some("Rust articles".to_string()),
-            html: "<article><h1>Rust</h1></article>".to_string(),
-            updated_at: "2025-01-01T00:00:00+09:00".to_string(),
-            articles: vec![sample_summary()],
-        })
+        let document = build_category_page_document(
+            &CategoryArtifactDocument {
+                category: "tech".to_string(),
+                title: "Rust".to_string(),
+                description: Some("Rust articles".to_string()),
+                html: "<article><h1>Rust</h1></article>".to_string(),
+                updated_at: "2025-01-01T00:00:00+09:00".to_string(),
+                articles: vec![sample_summary()],
+            },
+            &sample_registry(),
+        )
         .unwrap();
 
         assert_eq!(
@@ -611,14 +1078,17 @@ mod tests {
 
     #[test]
     fn test_build_category_page_description_falls_back_when_missing() {
-        let document = build_category_page_document(&CategoryArtifactDocument {
-            category: "tech".to_string(),
-            title: "Tech".to_string(),
-            description: None,
-            html: "<article><h1>Tech</h1></article>".to_string(),
-            updated_at: "2025-01-01T00:00:00+09:00".to_string(),
-            articles: vec![sample_summary()],
-        })
+        let document = build_category_page_document(
+            &CategoryArtifactDocument {
+                category: "tech".to_string(),
+                title: "Tech".to_string(),
+                description: None,
+                html: "<article><h1>Tech</h1></article>".to_string(),
+                updated_at: "2025-01-01T00:00:00+09:00".to_string(),
+                articles: vec![sample_summary()],
+            },
+            &sample_registry(),
+        )
         .unwrap();
 
         assert_eq!(
@@ -629,62 +1099,92 @@ mod tests {
 
     #[test]
     fn test_build_category_page_document_rejects_blank_html() {
-        let result = build_category_page_document(&CategoryArtifactDocument {
-            category: "tech".to_string(),
-            title: "Tech".to_string(),
-            description: None,
-            html: "  ".to_string(),
-            updated_at: "2025-01-01T00:00:00+09:00".to_string(),
-            articles: vec![sample_summary()],
-        });
+        let result = build_category_page_document(
+            &CategoryArtifactDocument {
+                category: "tech".to_string(),
+                title: "Tech".to_string(),
+                description: None,
+                html: "  ".to_string(),
+                updated_at: "2025-01-01T00:00:00+09:00".to_string(),
+                articles: vec![sample_summary()],
+            },
+            &sample_registry(),
+        );
 
         assert_eq!(result, Err(DomainError::validation("html")));
     }
 
     #[test]
     fn test_build_category_page_document_groups_articles_by_section_path() {
-        let document = build_category_page_document(&CategoryArtifactDocument {
-            category: "tech".to_string(),
-            title: "Tech".to_string(),
-            description: None,
-            html: "<article><h1>Tech</h1></article>".to_string(),
-            updated_at: "2025-01-01T00:00:00+09:00".to_string(),
-            articles: vec![
-                ArticleSummaryDocument {
-                    slug: "alpha0000001".to_string(),
-                    title: "Alpha".to_string(),
-                    category: "tech".to_string(),
-                    section_path: SectionPath::new(vec!["rust".to_string()]),
-                    description: None,
-                    tags: vec![],
-                    priority: None,
-                    created_at: "2025-01-01T00:00:00+09:00".to_string(),
-                    updated_at: "2025-01-01T00:00:00+09:00".to_string(),
-                },
-                ArticleSummaryDocument {
-                    slug: "beta00000001".to_string(),
-                    title: "Beta".to_string(),
-                    category: "tech".to_string(),
-                    section_path: SectionPath::new(vec!["rust".to_string(), "async".to_string()]),
-                    description: None,
-                    tags: vec![],
-                    priority: None,
-                    created_at: "2025-01-01T00:00:00+09:00".to_string(),
-                    updated_at: "2025-01-01T00:00:00+09:00".to_string(),
-                },
-                ArticleSummaryDocument {
-                    slug: "gamma0000001".to_string(),
-                    title: "Gamma".to_string(),
-                    category: "tech".to_string(),
-                    section_path: SectionPath::default(),
-                    description: None,
-                    tags: vec![],
-                    priority: None,
-                    created_at: "2025-01-01T00:00:00+09:00".to_string(),
-                    updated_at: "2025-01-01T00:00:00+09:00".to_string(),
-                },
-            ],
-        })
+        let document = build_category_page_document(
+            &CategoryArtifactDocument {
+                category: "tech".to_string(),
+                title: "Tech".to_string(),
+                description: None,
+                html: "<article><h1>Tech</h1></article>".to_string(),
+                updated_at: "2025-01-01T00:00:00+09:00".to_string(),
+                articles: vec![
+                    ArticleSummaryDocument {
+                        slug: "alpha0000001".to_string(),
+                        title: "Alpha".to_string(),
+                        category: "tech".to_string(),
+                        section_path: SectionPath::new(vec!["rust".to_string()]),
+                        description: None,
+                        tags: vec![],
+                        priority: None,
+                        created_at: "2025-01-01T00:00:00+09:00".to_string(),
+                        updated_at: "2025-01-01T00:00:00+09:00".to_string(),
+                        series: None,
+                        publish_at: None,
+                        related: vec![],
+                        backlinks: vec![],
+                        char_count: 0,
+                        reading_minutes: 0,
+                        excerpt: None,
+                    },
+                    ArticleSummaryDocument {
+                        slug: "beta00000001".to_string(),
+                        title: "Beta".to_string(),
+                        category: "tech".to_string(),
+                        section_path: SectionPath::new(vec![
+                            "rust".to_string(),
+                            "async".to_string(),
+                        ]),
+                        description: None,
+                        tags: vec![],
+                        priority: None,
+                        created_at: "2025-01-01T00:00:00+09:00".to_string(),
+                        updated_at: "2025-01-01T00:00:00+09:00".to_string(),
+                        series: None,
+                        publish_at: None,
+                        related: vec![],
+                        backlinks: vec![],
+                        char_count: 0,
+                        reading_minutes: 0,
+                        excerpt: None,
+                    },
+                    ArticleSummaryDocument {
+                        slug: "gamma0000001".to_string(),
+                        title: "Gamma".to_string(),
+                        category: "tech".to_string(),
+                        section_path: SectionPath::default(),
+                        description: None,
+                        tags: vec![],
+                        priority: None,
+                        created_at: "2025-01-01T00:00:00+09:00".to_string(),
+                        updated_at: "2025-01-01T00:00:00+09:00".to_string(),
+                        series: None,
+                        publish_at: None,
+                        related: vec![],
+                        backlinks: vec![],
+                        char_count: 0,
+                        reading_minutes: 0,
+                        excerpt: None,
+                    },
+                ],
+            },
+            &sample_registry(),
+        )
         .unwrap();
 
         assert_eq!(document.sections.len(), 3);
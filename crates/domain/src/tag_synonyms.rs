@@ -0,0 +1,96 @@
+//! Site-configured tag spellings that publish folds into one canonical tag.
+
+use crate::{DomainError, Result, Tag};
+use serde::{Deserialize, Deserializer};
+use std::collections::{BTreeMap, HashMap};
+
+/// Alternative tag spellings keyed by the tag they resolve to.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TagSynonyms(HashMap<Tag, Tag>);
+
+impl TagSynonyms {
+    /// Rejects synonyms that are declared twice, name a canonical tag, or equal their own tag.
+    pub fn new(entries: impl IntoIterator<Item = (Tag, Vec<Tag>)>) -> Result<Self> {
+        let entries: Vec<_> = entries.into_iter().collect();
+        let mut synonyms = HashMap::new();
+        for (canonical, aliases) in &entries {
+            for alias in aliases {
+                let reason = if alias == canonical {
+                    "is already the canonical spelling"
+                } else if entries.iter().any(|(other, _)| other == alias) {
+                    "is itself a canonical tag"
+                } else if synonyms.insert(alias.clone(), canonical.clone()).is_some() {
+                    "is declared more than once"
+                } else {
+                    continue;
+                };
+                return Err(DomainError::InvalidTag {
+                    tag: format!("synonym {alias} {reason}"),
+                });
+            }
+        }
+
+        Ok(Self(synonyms))
+    }
+
+    pub fn canonicalize(&self, tag: Tag) -> Tag {
+        self.0.get(&tag).cloned().unwrap_or(tag)
+    }
+
+    /// Normalizes raw tags and resolves synonyms, keeping the first occurrence of each tag.
+    pub fn canonical_tags(&self, raw_tags: impl IntoIterator<Item = String>) -> Result<Vec<Tag>> {
+        let mut tags: Vec<Tag> = Vec::new();
+        for raw_tag in raw_tags {
+            let tag = self.canonicalize(Tag::new(raw_tag)?);
+            if !tags.contains(&tag) {
+                tags.push(tag);
+            }
+        }
+        Ok(tags)
+    }
+}
+
+impl<'de> Deserialize<'de> for TagSynonyms {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let entries = BTreeMap::<Tag, Vec<Tag>>::deserialize(deserializer)?;
+        Self::new(entries).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tag(value: &str) -> Tag {
+        Tag::new(value.to_string()).unwrap()
+    }
+
+    #[test]
+    fn test_canonical_tags_resolves_synonyms_and_deduplicates() {
+        let synonyms =
+            TagSynonyms::new([(tag("kubernetes"), vec![tag("k8s"), tag("kube")])]).unwrap();
+
+        let tags = synonyms
+            .canonical_tags(["K8s", "rust", "Kubernetes", "ＲＵＳＴ"].map(String::from))
+            .unwrap();
+
+        assert_eq!(tags, [tag("kubernetes"), tag("rust")]);
+    }
+
+    #[test]
+    fn test_tag_synonyms_reject_ambiguous_declarations() {
+        for entries in [
+            vec![(tag("rust"), vec![tag("Rust")])],
+            vec![(tag("rust"), vec![tag("go")]), (tag("go"), vec![])],
+            vec![
+                (tag("rust"), vec![tag("rs")]),
+                (tag("ruby"), vec![tag("rs")]),
+            ],
+        ] {
+            assert!(TagSynonyms::new(entries.clone()).is_err(), "{entries:?}");
+        }
+    }
+}
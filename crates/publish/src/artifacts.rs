@@ -4,4 +4,6 @@ mod writer;
 
 pub(crate) use builder::build_site_artifacts;
 pub(crate) use validator::validate_site_artifacts;
-pub(crate) use writer::{SiteDirectories, write_article_page, write_site_artifacts};
+pub(crate) use writer::{
+    SiteDirectories, remove_stale_artifacts, write_articles, write_assets, write_site_artifacts,
+};
@@ -1,8 +1,10 @@
 use crate::error::Result;
 use domain::{
-    ArticleMeta, CategoryArtifactDocument, HomeFragmentArtifactDocument, PageArtifactDocument,
-    PublishableCategoryLanding, SiteMetadata, build_article_index, build_category_indexes,
-    build_site_metadata,
+    ArticleMeta, CategoryArtifactDocument, CategoryRegistry, HomeFragmentArtifactDocument,
+    LinkGraphDocument, PageArtifactDocument, PublishableCategoryLanding, RELATED_ARTICLE_LIMIT,
+    RedirectDocument, RedirectIndexDocument, SeriesArtifactDocument, SiteMetadata,
+    TagArtifactDocument, TagIndexDocument, build_article_index, build_category_indexes,
+    build_series, build_site_metadata, build_tag_indexes, collect_backlinks, rank_related_articles,
 };
 
 /// Complete artifact bundle produced from validated content.
@@ -10,24 +12,45 @@ use domain::{
 pub(crate) struct SiteArtifacts {
     pub(crate) article_index: Vec<domain::PublishedArticleSummary>,
     pub(super) category_documents: Vec<CategoryArtifactDocument>,
+    pub(super) series_documents: Vec<SeriesArtifactDocument>,
+    pub(super) tag_index: TagIndexDocument,
+    pub(super) tag_documents: Vec<TagArtifactDocument>,
     pub(super) page_documents: Vec<PageArtifactDocument>,
     pub(super) home_fragment: Option<HomeFragmentArtifactDocument>,
     pub(super) site_metadata: SiteMetadata,
+    pub(super) redirects: RedirectIndexDocument,
+    pub(super) link_graph: LinkGraphDocument,
 }
 
 pub(crate) fn build_site_artifacts(
-    article_metas: Vec<ArticleMeta>,
+    mut article_metas: Vec<ArticleMeta>,
     category_landings: Vec<PublishableCategoryLanding>,
     page_documents: Vec<PageArtifactDocument>,
     home_fragment: Option<HomeFragmentArtifactDocument>,
+    redirects: Vec<RedirectDocument>,
+    link_graph: LinkGraphDocument,
+    registry: &CategoryRegistry,
 ) -> Result<SiteArtifacts> {
     let category_metas = category_landings
         .iter()
         .map(|landing| landing.meta.clone())
         .collect();
+    let related = rank_related_articles(&article_metas, RELATED_ARTICLE_LIMIT);
+    let backlinks = collect_backlinks(&article_metas);
+    for ((article_meta, related), backlinks) in article_metas.iter_mut().zip(related).zip(backlinks)
+    {
+        article_meta.related = related;
+        article_meta.backlinks = backlinks;
+    }
     let article_index = build_article_index(&article_metas);
     let category_indexes = build_category_indexes(&article_metas, category_metas);
-    let site_metadata = build_site_metadata(&category_indexes);
+    let series_documents = build_series(&article_metas)?
+        .iter()
+        .map(SeriesArtifactDocument::from)
+        .collect();
+    let tag_indexes = build_tag_indexes(&article_metas);
+    let tag_documents = tag_indexes.iter().map(TagArtifactDocument::from).collect();
+    let site_metadata = build_site_metadata(&category_indexes, registry)?;
     let category_documents = category_indexes
         .iter()
         .map(|index| {
@@ -42,17 +65,24 @@ pub(crate) fn build_site_artifacts(
     Ok(SiteArtifacts {
         article_index,
         category_documents,
+        series_documents,
+        tag_index: TagIndexDocument::from(tag_indexes.as_slice()),
+        tag_documents,
         page_documents,
         home_fragment,
         site_metadata,
+        redirects: RedirectIndexDocument { redirects },
+        link_graph,
     })
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::test_category_registry;
     use domain::{
-        Category, CategoryLandingBody, CategoryLandingMeta, SectionPath, Slug, Timestamp, Title,
+        Category, CategoryLandingBody, CategoryLandingMeta, ReadingStats, SectionPath, SeriesKey,
+        SeriesPart, Slug, Tag, Timestamp, Title,
     };
 
     fn article_meta(
@@ -68,10 +98,18 @@ mod tests {
             category,
             section_path: SectionPath::default(),
             description: Some(format!("{title} summary")),
-            tags: vec!["rust".to_string()],
+            tags: vec![Tag::new("rust".to_string()).unwrap()],
             priority,
             created_at: Timestamp::new(created_at.to_string()).unwrap(),
             updated_at: Timestamp::new(created_at.to_string()).unwrap(),
+            toc: Vec::new(),
+            series: None,
+            publish_at: None,
+            links: Vec::new(),
+            related: Vec::new(),
+            backlinks: Vec::new(),
+            reading_stats: ReadingStats::default(),
+            excerpt: None,
         }
     }
 
@@ -98,24 +136,27 @@ mod tests {
                 article_meta(
                     "First",
                     "first0000001",
-                    Category::Tech,
+                    Category::new("tech".to_string()).unwrap(),
                     Some(1),
                     "2025-01-01T00:00:00+09:00",
                 ),
                 article_meta(
                     "Second",
                     "second000002",
-                    Category::Daily,
+                    Category::new("daily".to_string()).unwrap(),
                     Some(10),
                     "2025-01-02T00:00:00+09:00",
                 ),
             ],
             vec![
-                publishable_category_landing(Category::Tech, "Tech"),
-                publishable_category_landing(Category::Daily, "Daily"),
+                publishable_category_landing(Category::new("tech".to_string()).unwrap(), "Tech"),
+                publishable_category_landing(Category::new("daily".to_string()).unwrap(), "Daily"),
             ],
             vec![],
             None,
+            vec![],
+            LinkGraphDocument::default(),
+            &test_category_registry(),
         )
         .unwrap();
 
@@ -123,15 +164,25 @@ mod tests {
         assert_eq!(artifacts.category_documents.len(), 2);
         assert_eq!(artifacts.site_metadata.total_articles, 2);
         assert_eq!(artifacts.article_index[0].slug.as_str(), "second000002");
+        assert_eq!(artifacts.tag_index.tags.len(), 1);
+        assert_eq!(artifacts.tag_index.tags[0].tag, "rust");
+        assert_eq!(artifacts.tag_index.tags[0].article_count, 2);
+        assert_eq!(artifacts.tag_documents[0].articles.len(), 2);
     }
 
     #[test]
     fn test_build_site_artifacts_includes_landing_only_category() {
         let artifacts = build_site_artifacts(
             vec![],
-            vec![publishable_category_landing(Category::Physics, "Physics")],
+            vec![publishable_category_landing(
+                Category::new("physics".to_string()).unwrap(),
+                "Physics",
+            )],
             vec![],
             None,
+            vec![],
+            LinkGraphDocument::default(),
+            &test_category_registry(),
         )
         .unwrap();
 
@@ -147,15 +198,63 @@ mod tests {
             vec![article_meta(
                 "First",
                 "first0000001",
-                Category::Tech,
+                Category::new("tech".to_string()).unwrap(),
                 Some(1),
                 "2025-01-01T00:00:00+09:00",
             )],
             vec![],
             vec![],
             None,
+            vec![],
+            LinkGraphDocument::default(),
+            &test_category_registry(),
         );
 
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_build_site_artifacts_writes_series_and_rejects_gaps() {
+        let series_article = |slug: &str, order| ArticleMeta {
+            series: Some(SeriesPart {
+                series: SeriesKey::new("rust-async".to_string()).unwrap(),
+                order,
+            }),
+            ..article_meta(
+                slug,
+                slug,
+                Category::new("tech".to_string()).unwrap(),
+                None,
+                "2025-01-01T00:00:00+09:00",
+            )
+        };
+        let build = |article_metas| {
+            build_site_artifacts(
+                article_metas,
+                vec![publishable_category_landing(
+                    Category::new("tech".to_string()).unwrap(),
+                    "Tech",
+                )],
+                vec![],
+                None,
+                vec![],
+                LinkGraphDocument::default(),
+                &test_category_registry(),
+            )
+        };
+
+        let artifacts = build(vec![
+            series_article("part00000002", 2),
+            series_article("part00000001", 1),
+        ])
+        .unwrap();
+        assert_eq!(artifacts.series_documents.len(), 1);
+        assert_eq!(artifacts.series_documents[0].series, "rust-async");
+        assert_eq!(
+            artifacts.series_documents[0].articles[0].slug,
+            "part00000001"
+        );
+
+        assert!(build(vec![series_article("part00000002", 2)]).is_err());
+    }
 }
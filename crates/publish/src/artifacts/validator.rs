@@ -1,18 +1,28 @@
 use crate::error::{PublishError, Result};
 use domain::{
-    ArticleIndexDocument, Category, CategoryArtifactDocument, PageArtifactDocument,
-    SiteMetadataDocument, Slug,
+    ArticleIndexDocument, ArticleOutlineDocument, AssetName, Category, CategoryArtifactDocument,
+    CategoryRegistry, LinkGraphDocument, PageArtifactDocument, PageKey, RedirectIndexDocument,
+    SeriesArtifactDocument, SeriesKey, SiteMetadataDocument, Slug, Tag, TagArtifactDocument,
+    TagIndexDocument, Timestamp,
 };
+use regex::Regex;
 use std::{
-    collections::HashSet,
+    collections::{BTreeSet, HashSet},
     fs,
     path::{Path, PathBuf},
+    sync::LazyLock,
 };
 
+static ASSET_REFERENCE_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?:src|href)="/media/([^"/?#]+)""#)
+        .expect("Invalid asset reference regex pattern")
+});
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) struct ArtifactValidationSummary {
     pub(crate) article_count: usize,
     pub(crate) category_count: usize,
+    pub(crate) asset_count: usize,
 }
 
 /// Validates that a generated site is complete enough for destructive deployment.
@@ -37,8 +47,17 @@ pub(crate) fn validate_site_artifacts(
             article_index.articles.len(),
         )));
     }
+    CategoryRegistry::try_from(&site_metadata).map_err(|error| {
+        PublishError::ArtifactValidation(format!(
+            "metadata/site.json contains invalid category definitions: {error}"
+        ))
+    })?;
 
     let mut article_categories = HashSet::new();
+    let mut article_paths = HashSet::new();
+    let mut series_keys = BTreeSet::new();
+    let mut tags = BTreeSet::new();
+    let mut referenced_assets = BTreeSet::new();
     for article in &article_index.articles {
         let category = article.category.parse::<Category>().map_err(|error| {
             PublishError::ArtifactValidation(format!(
@@ -55,8 +74,176 @@ pub(crate) fn validate_site_artifacts(
         let relative_path = PathBuf::from("articles")
             .join(category.as_str())
             .join(format!("{}.html", slug.as_str()));
-        read_required_nonempty(site_root, &relative_path)?;
+        let html = read_required_nonempty(site_root, &relative_path)?;
+        collect_asset_references(&html, &mut referenced_assets);
+        read_required_json::<ArticleOutlineDocument>(
+            site_root,
+            &relative_path.with_extension("outline.json"),
+        )?;
+        article_paths.insert(format!("/{}/{}", category.as_str(), slug.as_str()));
         article_categories.insert(category);
+        if let Some(part) = &article.series {
+            let series = SeriesKey::new(part.series.clone()).map_err(|error| {
+                PublishError::ArtifactValidation(format!(
+                    "articles/index.json contains invalid series {}: {error}",
+                    part.series
+                ))
+            })?;
+            series_keys.insert(series);
+        }
+        if let Some(publish_at) = &article.publish_at {
+            Timestamp::new(publish_at.clone()).map_err(|error| {
+                PublishError::ArtifactValidation(format!(
+                    "articles/index.json contains invalid publish_at {publish_at}: {error}"
+                ))
+            })?;
+        }
+        for raw_tag in &article.tags {
+            match Tag::new(raw_tag.clone()) {
+                Ok(tag) if tag.as_str() == raw_tag => {
+                    tags.insert(tag);
+                }
+                _ => {
+                    return Err(PublishError::ArtifactValidation(format!(
+                        "articles/index.json contains unnormalized tag {raw_tag}"
+                    )));
+                }
+            }
+        }
+    }
+    for article in &article_index.articles {
+        let references = article
+            .related
+            .iter()
+            .map(|reference| ("related article", reference))
+            .chain(
+                article
+                    .backlinks
+                    .iter()
+                    .map(|reference| ("backlink", reference)),
+            );
+        for (label, reference) in references {
+            let reference_path = format!("/{}/{}", reference.category, reference.slug);
+            if !article_paths.contains(&reference_path)
+                || (reference.category == article.category && reference.slug == article.slug)
+            {
+                return Err(PublishError::ArtifactValidation(format!(
+                    "articles/index.json lists unknown {label} {reference_path} for /{}/{}",
+                    article.category, article.slug
+                )));
+            }
+        }
+    }
+
+    for series in &series_keys {
+        let series_path = PathBuf::from("series").join(format!("{}.json", series.as_str()));
+        let series_document: SeriesArtifactDocument = read_required_json(site_root, &series_path)?;
+        let mut expected_articles: Vec<_> = article_index
+            .articles
+            .iter()
+            .filter(|article| {
+                article
+                    .series
+                    .as_ref()
+                    .is_some_and(|part| part.series == series.as_str())
+            })
+            .cloned()
+            .collect();
+        expected_articles.sort_by_key(|article| article.series.as_ref().map(|part| part.order));
+        if !(1..)
+            .zip(&expected_articles)
+            .all(|(order, article)| article.series.as_ref().map(|part| part.order) == Some(order))
+        {
+            return Err(PublishError::ArtifactValidation(format!(
+                "series {} has duplicate or missing part numbers",
+                series.as_str()
+            )));
+        }
+        if series_document.series != series.as_str()
+            || series_document.articles != expected_articles
+        {
+            return Err(PublishError::ArtifactValidation(format!(
+                "{} does not match articles/index.json",
+                series_path.display(),
+            )));
+        }
+    }
+
+    let tag_index: TagIndexDocument = read_required_json(site_root, Path::new("tags/index.json"))?;
+    let indexed_tags: Vec<_> = tag_index
+        .tags
+        .iter()
+        .map(|summary| summary.tag.as_str())
+        .collect();
+    if !indexed_tags
+        .iter()
+        .copied()
+        .eq(tags.iter().map(Tag::as_str))
+    {
+        return Err(PublishError::ArtifactValidation(
+            "tags/index.json does not list the tags in articles/index.json".to_string(),
+        ));
+    }
+    for summary in &tag_index.tags {
+        let tag_segment = summary.tag.parse::<Tag>()?.path_segment();
+        let tag_path = PathBuf::from("tags").join(format!("{tag_segment}.json"));
+        let tag_document: TagArtifactDocument = read_required_json(site_root, &tag_path)?;
+        let expected_articles: Vec<_> = article_index
+            .articles
+            .iter()
+            .filter(|article| article.tags.contains(&summary.tag))
+            .cloned()
+            .collect();
+        if tag_document.tag != summary.tag
+            || tag_document.articles != expected_articles
+            || summary.article_count != expected_articles.len()
+        {
+            return Err(PublishError::ArtifactValidation(format!(
+                "{} does not match articles/index.json",
+                tag_path.display(),
+            )));
+        }
+    }
+
+    let redirects: RedirectIndexDocument =
+        read_required_json(site_root, Path::new("redirects.json"))?;
+    let mut redirect_sources = HashSet::new();
+    for redirect in &redirects.redirects {
+        if article_paths.contains(&redirect.from) || !redirect_sources.insert(&redirect.from) {
+            return Err(PublishError::ArtifactValidation(format!(
+                "redirects.json redirects live or duplicate path {}",
+                redirect.from
+            )));
+        }
+        if !article_paths.contains(&redirect.to) {
+            return Err(PublishError::ArtifactValidation(format!(
+                "redirects.json redirects {} to missing article {}",
+                redirect.from, redirect.to
+            )));
+        }
+    }
+
+    let link_graph: LinkGraphDocument = read_required_json(site_root, Path::new("graph.json"))?;
+    let node_paths: HashSet<_> = link_graph
+        .nodes
+        .iter()
+        .map(|node| node.path.as_str())
+        .collect();
+    if let Some(article_path) = article_paths
+        .iter()
+        .find(|article_path| !node_paths.contains(article_path.as_str()))
+    {
+        return Err(PublishError::ArtifactValidation(format!(
+            "graph.json is missing article {article_path}"
+        )));
+    }
+    if let Some(edge) = link_graph.edges.iter().find(|edge| {
+        !node_paths.contains(edge.source.as_str()) || !node_paths.contains(edge.target.as_str())
+    }) {
+        return Err(PublishError::ArtifactValidation(format!(
+            "graph.json links {} to {} outside its nodes",
+            edge.source, edge.target
+        )));
     }
 
     let metadata_category_names: HashSet<_> = site_metadata
@@ -110,6 +297,7 @@ pub(crate) fn validate_site_artifacts(
                 category_path.display(),
             )));
         }
+        collect_asset_references(&category_document.html, &mut referenced_assets);
 
         let expected_articles: Vec<_> = article_index
             .articles
@@ -133,28 +321,109 @@ pub(crate) fn validate_site_artifacts(
         }
     }
 
-    let about_path = Path::new("pages/about.json");
-    let about: PageArtifactDocument = read_required_json(site_root, about_path)?;
-    if about.page.as_str() != "about" {
-        return Err(PublishError::ArtifactValidation(format!(
-            "{} declares page {} instead of about",
-            about_path.display(),
-            about.page,
-        )));
+    // The header links to the about page, so it must be published.
+    read_required_nonempty(site_root, Path::new("pages/about.json"))?;
+    for relative_path in read_page_paths(site_root)? {
+        let page_key = relative_path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        PageKey::new(page_key.clone()).map_err(|error| {
+            PublishError::ArtifactValidation(format!(
+                "{} is not a routable page: {error}",
+                relative_path.display()
+            ))
+        })?;
+        if metadata_category_names.contains(page_key.as_str()) {
+            return Err(PublishError::ArtifactValidation(format!(
+                "{} shadows category {page_key}",
+                relative_path.display()
+            )));
+        }
+        let page: PageArtifactDocument = read_required_json(site_root, &relative_path)?;
+        if page.page.as_str() != page_key {
+            return Err(PublishError::ArtifactValidation(format!(
+                "{} declares page {} instead of {page_key}",
+                relative_path.display(),
+                page.page,
+            )));
+        }
+        if page.html.trim().is_empty() {
+            return Err(PublishError::ArtifactValidation(format!(
+                "required artifact {} contains empty html",
+                relative_path.display(),
+            )));
+        }
+        collect_asset_references(&page.html, &mut referenced_assets);
     }
-    if about.html.trim().is_empty() {
+
+    let published_assets = read_published_assets(site_root)?;
+    let missing_assets: Vec<_> = referenced_assets
+        .difference(&published_assets)
+        .map(String::as_str)
+        .collect();
+    if !missing_assets.is_empty() {
         return Err(PublishError::ArtifactValidation(format!(
-            "required artifact {} contains empty html",
-            about_path.display(),
+            "assets referenced by generated html are missing: {}",
+            missing_assets.join(", "),
         )));
     }
 
     Ok(ArtifactValidationSummary {
         article_count: article_index.articles.len(),
         category_count: site_metadata.categories.len(),
+        asset_count: published_assets.len(),
     })
 }
 
+fn collect_asset_references(html: &str, referenced_assets: &mut BTreeSet<String>) {
+    referenced_assets.extend(
+        ASSET_REFERENCE_RE
+            .captures_iter(html)
+            .map(|captures| captures[1].to_string()),
+    );
+}
+
+fn read_page_paths(site_root: &Path) -> Result<Vec<PathBuf>> {
+    let mut paths = Vec::new();
+    for entry in fs::read_dir(site_root.join("pages"))? {
+        let path = entry?.path();
+        if path
+            .extension()
+            .is_some_and(|extension| extension == "json")
+        {
+            paths.push(PathBuf::from("pages").join(path.file_name().unwrap_or_default()));
+        }
+    }
+    paths.sort_unstable();
+    Ok(paths)
+}
+
+fn read_published_assets(site_root: &Path) -> Result<BTreeSet<String>> {
+    let assets_dir = site_root.join("assets");
+    if !assets_dir.exists() {
+        return Ok(BTreeSet::new());
+    }
+
+    let mut assets = BTreeSet::new();
+    for entry in fs::read_dir(&assets_dir)? {
+        let entry = entry?;
+        let file_name = entry.file_name().to_string_lossy().into_owned();
+        let asset_name = AssetName::new(file_name.clone()).map_err(|error| {
+            PublishError::ArtifactValidation(format!(
+                "assets/{file_name} is not a valid asset name: {error}"
+            ))
+        })?;
+        if !entry.file_type()?.is_file() || entry.metadata()?.len() == 0 {
+            return Err(PublishError::ArtifactValidation(format!(
+                "required artifact assets/{file_name} is empty"
+            )));
+        }
+        assets.insert(asset_name.as_str().to_string());
+    }
+    Ok(assets)
+}
+
 fn read_required_json<T: serde::de::DeserializeOwned>(
     site_root: &Path,
     relative_path: &Path,
@@ -188,17 +457,27 @@ fn read_required_nonempty(site_root: &Path, relative_path: &Path) -> Result<Stri
 #[cfg(test)]
 mod tests {
     use super::super::builder::build_site_artifacts;
-    use super::super::writer::{SiteDirectories, write_article_page, write_site_artifacts};
+    use super::super::writer::{
+        SiteDirectories, write_article_outline, write_article_page, write_site_artifacts,
+    };
     use super::*;
+    use crate::config::test_category_registry;
     use domain::{
-        ArticleMeta, CategoryLandingBody, CategoryLandingMeta, PageKey, PublishableCategoryLanding,
-        SectionPath, Timestamp, Title,
+        ArticleMeta, ArticleRefDocument, CategoryLandingBody, CategoryLandingMeta,
+        LinkGraphEdgeDocument, LinkGraphNodeDocument, PageKey, PublishableCategoryLanding,
+        ReadingStats, RedirectDocument, SectionPath, SeriesPart, TagSummaryDocument, Timestamp,
+        Title,
     };
+    use rstest::rstest;
     use tempfile::TempDir;
 
     const ARTICLE_PATH: &str = "site/articles/tech/artifact00001.html";
+    const ARTICLE_OUTLINE_PATH: &str = "site/articles/tech/artifact00001.outline.json";
     const CATEGORY_PATH: &str = "site/categories/tech.json";
     const ABOUT_PATH: &str = "site/pages/about.json";
+    const SERIES_PATH: &str = "site/series/artifact-series.json";
+    const TAG_INDEX_PATH: &str = "site/tags/index.json";
+    const TAG_PATH: &str = "site/tags/rust.json";
 
     fn write_complete_site() -> TempDir {
         let temp_dir = TempDir::new().unwrap();
@@ -207,16 +486,27 @@ mod tests {
         let article = ArticleMeta {
             slug: Slug::new("artifact00001".to_string()).unwrap(),
             title: Title::new("Artifact Test".to_string()).unwrap(),
-            category: Category::Tech,
+            category: Category::new("tech".to_string()).unwrap(),
             section_path: SectionPath::default(),
             description: Some("Artifact summary".to_string()),
-            tags: vec!["rust".to_string()],
+            tags: vec![Tag::new("rust".to_string()).unwrap()],
             priority: Some(1),
             created_at: timestamp.clone(),
             updated_at: timestamp.clone(),
+            toc: Vec::new(),
+            series: Some(SeriesPart {
+                series: SeriesKey::new("artifact-series".to_string()).unwrap(),
+                order: 1,
+            }),
+            publish_at: None,
+            links: Vec::new(),
+            related: Vec::new(),
+            backlinks: Vec::new(),
+            reading_stats: ReadingStats::default(),
+            excerpt: None,
         };
         let landing = CategoryLandingMeta {
-            category: Category::Tech,
+            category: Category::new("tech".to_string()).unwrap(),
             title: Title::new("Tech".to_string()).unwrap(),
             description: Some("Tech landing".to_string()),
             updated_at: timestamp,
@@ -235,16 +525,30 @@ mod tests {
                 updated_at: "2025-01-01T00:00:00+09:00".to_string(),
             }],
             None,
+            vec![RedirectDocument {
+                from: "/tech/old-artifact".to_string(),
+                to: "/tech/artifact00001".to_string(),
+            }],
+            LinkGraphDocument {
+                nodes: vec![LinkGraphNodeDocument {
+                    path: "/tech/artifact00001".to_string(),
+                    title: "Artifact Test".to_string(),
+                }],
+                edges: Vec::new(),
+            },
+            &test_category_registry(),
         )
         .unwrap();
 
         write_article_page(
             &directories,
-            article.category,
+            &article.category,
             &article.slug,
             "<h1>Artifact Test</h1>",
         )
         .unwrap();
+        write_article_outline(&directories, &article.category, &article.slug, &article.toc)
+            .unwrap();
         write_site_artifacts(&directories, &artifacts).unwrap();
 
         temp_dir
@@ -258,6 +562,39 @@ mod tests {
 
         assert_eq!(summary.article_count, 1);
         assert_eq!(summary.category_count, 1);
+        assert_eq!(summary.asset_count, 0);
+    }
+
+    #[test]
+    fn test_validate_site_artifacts_rejects_missing_referenced_asset() {
+        let temp_dir = write_complete_site();
+        fs::write(
+            temp_dir.path().join(ARTICLE_PATH),
+            r#"<p><img src="/media/00112233aabbccdd.png" alt="Figure" /></p>"#,
+        )
+        .unwrap();
+
+        let error = validate_site_artifacts(temp_dir.path().join("site")).unwrap_err();
+
+        assert!(error.to_string().contains("00112233aabbccdd.png"));
+
+        fs::write(
+            temp_dir.path().join("site/assets/00112233aabbccdd.png"),
+            "png bytes",
+        )
+        .unwrap();
+        let summary = validate_site_artifacts(temp_dir.path().join("site")).unwrap();
+        assert_eq!(summary.asset_count, 1);
+    }
+
+    #[test]
+    fn test_validate_site_artifacts_rejects_unhashed_asset_names() {
+        let temp_dir = write_complete_site();
+        fs::write(temp_dir.path().join("site/assets/diagram.PNG"), "png bytes").unwrap();
+
+        let error = validate_site_artifacts(temp_dir.path().join("site")).unwrap_err();
+
+        assert!(error.to_string().contains("assets/diagram.PNG"));
     }
 
     #[test]
@@ -266,7 +603,16 @@ mod tests {
         let directories = SiteDirectories::prepare(temp_dir.path()).unwrap();
         write_site_artifacts(
             &directories,
-            &build_site_artifacts(vec![], vec![], vec![], None).unwrap(),
+            &build_site_artifacts(
+                vec![],
+                vec![],
+                vec![],
+                None,
+                vec![],
+                LinkGraphDocument::default(),
+                &test_category_registry(),
+            )
+            .unwrap(),
         )
         .unwrap();
 
@@ -276,19 +622,78 @@ mod tests {
     }
 
     #[test]
-    fn test_validate_site_artifacts_rejects_missing_article_html() {
+    fn test_validate_site_artifacts_rejects_unknown_related_article() {
         let temp_dir = write_complete_site();
-        fs::remove_file(temp_dir.path().join(ARTICLE_PATH)).unwrap();
+        let index_path = temp_dir.path().join("site/articles/index.json");
+        let mut article_index: ArticleIndexDocument =
+            serde_json::from_str(&fs::read_to_string(&index_path).unwrap()).unwrap();
+        article_index.articles[0].related = vec![ArticleRefDocument {
+            category: "tech".to_string(),
+            slug: "missing000001".to_string(),
+        }];
+        fs::write(
+            &index_path,
+            serde_json::to_string_pretty(&article_index).unwrap(),
+        )
+        .unwrap();
 
         let error = validate_site_artifacts(temp_dir.path().join("site")).unwrap_err();
 
         assert!(
             error
                 .to_string()
-                .contains("articles/tech/artifact00001.html")
+                .contains("unknown related article /tech/missing000001")
         );
     }
 
+    #[test]
+    fn test_validate_site_artifacts_rejects_incomplete_link_graph() {
+        let temp_dir = write_complete_site();
+        let graph_path = temp_dir.path().join("site/graph.json");
+        fs::write(
+            &graph_path,
+            serde_json::to_string_pretty(&LinkGraphDocument {
+                nodes: vec![LinkGraphNodeDocument {
+                    path: "/tech/artifact00001".to_string(),
+                    title: "Artifact Test".to_string(),
+                }],
+                edges: vec![LinkGraphEdgeDocument {
+                    source: "/tech/artifact00001".to_string(),
+                    target: "/about".to_string(),
+                }],
+            })
+            .unwrap(),
+        )
+        .unwrap();
+
+        let error = validate_site_artifacts(temp_dir.path().join("site")).unwrap_err();
+        assert!(error.to_string().contains("outside its nodes"));
+
+        fs::write(
+            &graph_path,
+            serde_json::to_string_pretty(&LinkGraphDocument::default()).unwrap(),
+        )
+        .unwrap();
+        let error = validate_site_artifacts(temp_dir.path().join("site")).unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("graph.json is missing article /tech/artifact00001")
+        );
+    }
+
+    #[rstest]
+    #[case::html(ARTICLE_PATH)]
+    #[case::outline(ARTICLE_OUTLINE_PATH)]
+    fn test_validate_site_artifacts_rejects_missing_article_files(#[case] path: &str) {
+        let temp_dir = write_complete_site();
+        fs::remove_file(temp_dir.path().join(path)).unwrap();
+
+        let error = validate_site_artifacts(temp_dir.path().join("site")).unwrap_err();
+
+        assert!(error.to_string().contains(path.trim_start_matches("site/")));
+    }
+
     #[test]
     fn test_validate_site_artifacts_rejects_missing_category_artifact() {
         let temp_dir = write_complete_site();
@@ -309,6 +714,102 @@ mod tests {
         assert!(error.to_string().contains("pages/about.json"));
     }
 
+    #[test]
+    fn test_validate_site_artifacts_rejects_missing_or_stale_series() {
+        let temp_dir = write_complete_site();
+        fs::write(
+            temp_dir.path().join(SERIES_PATH),
+            r#"{"series":"artifact-series","articles":[]}"#,
+        )
+        .unwrap();
+
+        let error = validate_site_artifacts(temp_dir.path().join("site")).unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("series/artifact-series.json does not match")
+        );
+
+        fs::remove_file(temp_dir.path().join(SERIES_PATH)).unwrap();
+        let error = validate_site_artifacts(temp_dir.path().join("site")).unwrap_err();
+        assert!(error.to_string().contains("series/artifact-series.json"));
+    }
+
+    #[test]
+    fn test_validate_site_artifacts_rejects_missing_or_stale_tags() {
+        let temp_dir = write_complete_site();
+        fs::write(
+            temp_dir.path().join(TAG_PATH),
+            r#"{"tag":"rust","articles":[]}"#,
+        )
+        .unwrap();
+
+        let error = validate_site_artifacts(temp_dir.path().join("site")).unwrap_err();
+        assert!(error.to_string().contains("tags/rust.json does not match"));
+
+        fs::write(
+            temp_dir.path().join(TAG_INDEX_PATH),
+            serde_json::to_string(&TagIndexDocument {
+                tags: vec![TagSummaryDocument {
+                    tag: "go".to_string(),
+                    article_count: 1,
+                }],
+            })
+            .unwrap(),
+        )
+        .unwrap();
+        let error = validate_site_artifacts(temp_dir.path().join("site")).unwrap_err();
+        assert!(error.to_string().contains("tags/index.json does not list"));
+
+        fs::remove_file(temp_dir.path().join(TAG_INDEX_PATH)).unwrap();
+        let error = validate_site_artifacts(temp_dir.path().join("site")).unwrap_err();
+        assert!(error.to_string().contains("tags/index.json"));
+    }
+
+    #[rstest]
+    #[case::category("tech", "shadows category tech")]
+    #[case::server_route("api", "is not a routable page")]
+    fn test_validate_site_artifacts_rejects_pages_that_shadow_routes(
+        #[case] page: &str,
+        #[case] message: &str,
+    ) {
+        let temp_dir = write_complete_site();
+        fs::write(
+            temp_dir.path().join(format!("site/pages/{page}.json")),
+            r#"{"page":"contact","title":"Contact","html":"<p>Contact</p>","updated_at":""}"#,
+        )
+        .unwrap();
+
+        let error = validate_site_artifacts(temp_dir.path().join("site")).unwrap_err();
+
+        assert!(error.to_string().contains(message), "{error}");
+    }
+
+    #[test]
+    fn test_validate_site_artifacts_checks_every_page() {
+        let temp_dir = write_complete_site();
+        let contact_path = temp_dir.path().join("site/pages/contact.json");
+        let mut contact = PageArtifactDocument {
+            page: PageKey::new("contact".to_string()).unwrap(),
+            title: "Contact".to_string(),
+            description: None,
+            html: "<p>Contact</p>".to_string(),
+            updated_at: "2025-01-01T00:00:00+09:00".to_string(),
+        };
+        fs::write(&contact_path, serde_json::to_string(&contact).unwrap()).unwrap();
+        validate_site_artifacts(temp_dir.path().join("site")).unwrap();
+
+        contact.html = " ".to_string();
+        fs::write(&contact_path, serde_json::to_string(&contact).unwrap()).unwrap();
+        let error = validate_site_artifacts(temp_dir.path().join("site")).unwrap_err();
+
+        assert!(
+            error
+                .to_string()
+                .contains("pages/contact.json contains empty html")
+        );
+    }
+
     #[test]
     fn test_validate_site_artifacts_rejects_article_category_missing_from_metadata() {
         let temp_dir = write_complete_site();
@@ -327,4 +828,65 @@ mod tests {
         assert!(error.to_string().contains("missing article categories"));
         assert!(error.to_string().contains("tech"));
     }
+
+    #[test]
+    fn test_validate_site_artifacts_rejects_reserved_metadata_category() {
+        let temp_dir = write_complete_site();
+        let metadata_path = temp_dir.path().join("site/metadata/site.json");
+        let mut metadata: SiteMetadataDocument =
+            serde_json::from_str(&fs::read_to_string(&metadata_path).unwrap()).unwrap();
+        let mut reserved = metadata.categories[0].clone();
+        reserved.category = "assets".to_string();
+        metadata.categories.push(reserved);
+        fs::write(
+            &metadata_path,
+            serde_json::to_string_pretty(&metadata).unwrap(),
+        )
+        .unwrap();
+
+        let error = validate_site_artifacts(temp_dir.path().join("site")).unwrap_err();
+
+        assert!(error.to_string().contains("invalid category definitions"));
+        assert!(error.to_string().contains("assets"));
+    }
+
+    #[test]
+    fn test_validate_site_artifacts_rejects_redirect_to_missing_article() {
+        let temp_dir = write_complete_site();
+        fs::write(
+            temp_dir.path().join("site/redirects.json"),
+            serde_json::to_string_pretty(&RedirectIndexDocument {
+                redirects: vec![RedirectDocument {
+                    from: "/tech/old-artifact".to_string(),
+                    to: "/tech/missing000001".to_string(),
+                }],
+            })
+            .unwrap(),
+        )
+        .unwrap();
+
+        let error = validate_site_artifacts(temp_dir.path().join("site")).unwrap_err();
+
+        assert!(error.to_string().contains("/tech/missing000001"));
+    }
+
+    #[test]
+    fn test_validate_site_artifacts_rejects_redirect_from_live_article() {
+        let temp_dir = write_complete_site();
+        fs::write(
+            temp_dir.path().join("site/redirects.json"),
+            serde_json::to_string_pretty(&RedirectIndexDocument {
+                redirects: vec![RedirectDocument {
+                    from: "/tech/artifact00001".to_string(),
+                    to: "/tech/artifact00001".to_string(),
+                }],
+            })
+            .unwrap(),
+        )
+        .unwrap();
+
+        let error = validate_site_artifacts(temp_dir.path().join("site")).unwrap_err();
+
+        assert!(error.to_string().contains("live or duplicate path"));
+    }
 }
@@ -1,11 +1,16 @@
 use super::builder::SiteArtifacts;
+use crate::assets::Attachment;
 use crate::error::Result;
 
-use domain::{ArticleIndexDocument, Category, SiteMetadataDocument, Slug};
+use domain::{
+    ArticleIndexDocument, ArticleOutlineDocument, Category, PublishableArticle,
+    SiteMetadataDocument, Slug, Tag, TocEntry,
+};
 use serde::Serialize;
 use std::{
-    fs::{self, File},
-    io::BufWriter,
+    collections::BTreeSet,
+    ffi::OsString,
+    fs, io,
     path::{Path, PathBuf},
 };
 
@@ -14,9 +19,14 @@ use std::{
 pub(crate) struct SiteDirectories {
     home_fragment_path: PathBuf,
     articles_dir: PathBuf,
+    assets_dir: PathBuf,
     categories_dir: PathBuf,
     metadata_dir: PathBuf,
     pages_dir: PathBuf,
+    series_dir: PathBuf,
+    tags_dir: PathBuf,
+    redirects_path: PathBuf,
+    link_graph_path: PathBuf,
 }
 
 impl SiteDirectories {
@@ -25,83 +35,237 @@ impl SiteDirectories {
         let site_directories = Self {
             home_fragment_path: site_root.join("home.json"),
             articles_dir: site_root.join("articles"),
+            assets_dir: site_root.join("assets"),
             categories_dir: site_root.join("categories"),
             metadata_dir: site_root.join("metadata"),
             pages_dir: site_root.join("pages"),
+            series_dir: site_root.join("series"),
+            tags_dir: site_root.join("tags"),
+            redirects_path: site_root.join("redirects.json"),
+            link_graph_path: site_root.join("graph.json"),
         };
 
         fs::create_dir_all(&site_directories.articles_dir)?;
+        fs::create_dir_all(&site_directories.assets_dir)?;
         fs::create_dir_all(&site_directories.categories_dir)?;
         fs::create_dir_all(&site_directories.metadata_dir)?;
         fs::create_dir_all(&site_directories.pages_dir)?;
+        fs::create_dir_all(&site_directories.series_dir)?;
+        fs::create_dir_all(&site_directories.tags_dir)?;
 
         Ok(site_directories)
     }
+
+    pub(crate) fn article_page_path(&self, category: &Category, slug: &Slug) -> PathBuf {
+        self.articles_dir
+            .join(category.as_str())
+            .join(format!("{}.html", slug.as_str()))
+    }
+
+    pub(crate) fn article_outline_path(&self, category: &Category, slug: &Slug) -> PathBuf {
+        self.articles_dir
+            .join(category.as_str())
+            .join(format!("{}.outline.json", slug.as_str()))
+    }
 }
 
 pub(crate) fn write_article_page(
     site_directories: &SiteDirectories,
-    category: Category,
+    category: &Category,
     slug: &Slug,
     html: &str,
 ) -> Result<PathBuf> {
-    let article_dir = site_directories.articles_dir.join(category.as_str());
-    fs::create_dir_all(&article_dir)?;
-    let output_file_path = article_dir.join(format!("{}.html", slug.as_str()));
-    fs::write(&output_file_path, html)?;
+    let output_file_path = site_directories.article_page_path(category, slug);
+    if let Some(article_dir) = output_file_path.parent() {
+        fs::create_dir_all(article_dir)?;
+    }
+    write_atomically(&output_file_path, html.as_bytes())?;
     Ok(output_file_path)
 }
 
+/// Writes the heading outline the article page reads beside the article HTML.
+pub(crate) fn write_article_outline(
+    site_directories: &SiteDirectories,
+    category: &Category,
+    slug: &Slug,
+    toc: &[TocEntry],
+) -> Result<PathBuf> {
+    let output_file_path = site_directories.article_outline_path(category, slug);
+    if let Some(article_dir) = output_file_path.parent() {
+        fs::create_dir_all(article_dir)?;
+    }
+    write_json_pretty(
+        output_file_path,
+        &ArticleOutlineDocument { toc: toc.to_vec() },
+    )
+}
+
+/// Writes the page and outline of every article and returns their paths.
+pub(crate) fn write_articles(
+    site_directories: &SiteDirectories,
+    articles: &[PublishableArticle],
+) -> Result<BTreeSet<PathBuf>> {
+    let mut written = BTreeSet::new();
+    for PublishableArticle { meta, body } in articles {
+        written.insert(write_article_page(
+            site_directories,
+            &meta.category,
+            &meta.slug,
+            body.as_str(),
+        )?);
+        written.insert(write_article_outline(
+            site_directories,
+            &meta.category,
+            &meta.slug,
+            &meta.toc,
+        )?);
+    }
+    Ok(written)
+}
+
+/// Copies referenced attachments to their content-hashed asset paths.
+pub(crate) fn write_assets(
+    site_directories: &SiteDirectories,
+    attachments: &[Attachment],
+) -> Result<()> {
+    for attachment in attachments {
+        let output_file_path = site_directories
+            .assets_dir
+            .join(attachment.asset_name.as_str());
+        // Hashed names are immutable, so an existing file already holds the same content.
+        if !output_file_path.exists() {
+            let temp_path = temp_path(&output_file_path);
+            fs::copy(&attachment.source_path, &temp_path)?;
+            fs::rename(temp_path, output_file_path)?;
+        }
+    }
+    Ok(())
+}
+
+/// Writes every site document and returns the paths the site now consists of.
+///
+/// Documents are written before the indexes that list them, so a reader never follows an index
+/// entry to a document that is not there yet.
 pub(crate) fn write_site_artifacts(
     site_directories: &SiteDirectories,
     site_artifacts: &SiteArtifacts,
-) -> Result<()> {
-    write_json_pretty(
-        &site_directories.articles_dir.join("index.json"),
-        &ArticleIndexDocument::from(site_artifacts.article_index.as_slice()),
-    )?;
+) -> Result<BTreeSet<PathBuf>> {
+    let mut written = BTreeSet::new();
     for category_document in &site_artifacts.category_documents {
-        write_json_pretty(
-            &site_directories
+        written.insert(write_json_pretty(
+            site_directories
                 .categories_dir
                 .join(format!("{}.json", category_document.category)),
             category_document,
-        )?;
+        )?);
+    }
+    for series_document in &site_artifacts.series_documents {
+        written.insert(write_json_pretty(
+            site_directories
+                .series_dir
+                .join(format!("{}.json", series_document.series)),
+            series_document,
+        )?);
+    }
+    for tag_document in &site_artifacts.tag_documents {
+        written.insert(write_json_pretty(
+            site_directories.tags_dir.join(format!(
+                "{}.json",
+                tag_document.tag.parse::<Tag>()?.path_segment()
+            )),
+            tag_document,
+        )?);
     }
     for page_document in &site_artifacts.page_documents {
-        write_json_pretty(
-            &site_directories
+        written.insert(write_json_pretty(
+            site_directories
                 .pages_dir
                 .join(format!("{}.json", page_document.page)),
             page_document,
-        )?;
+        )?);
     }
     if let Some(home_fragment) = &site_artifacts.home_fragment {
-        write_json_pretty(&site_directories.home_fragment_path, home_fragment)?;
+        written.insert(write_json_pretty(
+            site_directories.home_fragment_path.clone(),
+            home_fragment,
+        )?);
     }
 
-    write_json_pretty(
-        &site_directories.metadata_dir.join("site.json"),
+    written.insert(write_json_pretty(
+        site_directories.tags_dir.join("index.json"),
+        &site_artifacts.tag_index,
+    )?);
+    written.insert(write_json_pretty(
+        site_directories.articles_dir.join("index.json"),
+        &ArticleIndexDocument::from(site_artifacts.article_index.as_slice()),
+    )?);
+    written.insert(write_json_pretty(
+        site_directories.metadata_dir.join("site.json"),
         &SiteMetadataDocument::from(&site_artifacts.site_metadata),
-    )?;
+    )?);
+    written.insert(write_json_pretty(
+        site_directories.redirects_path.clone(),
+        &site_artifacts.redirects,
+    )?);
+    written.insert(write_json_pretty(
+        site_directories.link_graph_path.clone(),
+        &site_artifacts.link_graph,
+    )?);
 
+    Ok(written)
+}
+
+/// Deletes artifacts an earlier build wrote that the current build no longer produces.
+///
+/// Runs after the current indexes are written, so nothing listed still points at them.
+pub(crate) fn remove_stale_artifacts(
+    previous: &BTreeSet<PathBuf>,
+    current: &BTreeSet<PathBuf>,
+) -> Result<()> {
+    for path in previous.difference(current) {
+        match fs::remove_file(path) {
+            Ok(()) => tracing::info!(path = %path.display(), "removed stale artifact"),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {}
+            Err(error) => return Err(error.into()),
+        }
+    }
     Ok(())
 }
 
-fn write_json_pretty(path: &Path, value: &impl Serialize) -> Result<()> {
-    let file = File::create(path)?;
-    let writer = BufWriter::new(file);
-    serde_json::to_writer_pretty(writer, value)?;
+fn write_json_pretty(path: PathBuf, value: &impl Serialize) -> Result<PathBuf> {
+    let json = serde_json::to_vec_pretty(value)?;
+    write_atomically(&path, &json)?;
+    Ok(path)
+}
+
+/// Replaces a file through a rename, so readers see the old or the new content but never a
+/// partial write; a file that already holds `contents` is left untouched.
+fn write_atomically(path: &Path, contents: &[u8]) -> Result<()> {
+    if fs::read(path).is_ok_and(|existing| existing == contents) {
+        return Ok(());
+    }
+    let temp_path = temp_path(path);
+    fs::write(&temp_path, contents)?;
+    fs::rename(temp_path, path)?;
     Ok(())
 }
 
+// Kept next to the target so the rename never crosses file systems.
+fn temp_path(path: &Path) -> PathBuf {
+    let mut temp_path = OsString::from(path.as_os_str());
+    temp_path.push(".tmp");
+    PathBuf::from(temp_path)
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::builder::build_site_artifacts;
     use super::*;
+    use crate::config::test_category_registry;
     use domain::{
-        ArticleMeta, CategoryLandingMeta, HomeFragmentArtifactDocument, PageArtifactDocument,
-        PageKey, SectionPath, Timestamp, Title,
+        ArticleMeta, AssetName, CategoryLandingMeta, HomeFragmentArtifactDocument,
+        PageArtifactDocument, PageKey, ReadingStats, RedirectDocument, SectionPath, Tag, Timestamp,
+        Title,
     };
     use tempfile::TempDir;
 
@@ -109,19 +273,27 @@ mod tests {
         ArticleMeta {
             slug: Slug::new("artifact00001".to_string()).unwrap(),
             title: Title::new("Artifact Test".to_string()).unwrap(),
-            category: Category::Tech,
+            category: Category::new("tech".to_string()).unwrap(),
             section_path: SectionPath::default(),
             description: Some("Artifact summary".to_string()),
-            tags: vec!["rust".to_string()],
+            tags: vec![Tag::new("rust".to_string()).unwrap()],
             priority: Some(1),
             created_at: Timestamp::new("2025-01-01T00:00:00+09:00".to_string()).unwrap(),
             updated_at: Timestamp::new("2025-01-01T00:00:00+09:00".to_string()).unwrap(),
+            toc: Vec::new(),
+            series: None,
+            publish_at: None,
+            links: Vec::new(),
+            related: Vec::new(),
+            backlinks: Vec::new(),
+            reading_stats: ReadingStats::default(),
+            excerpt: None,
         }
     }
 
     fn category_landing() -> CategoryLandingMeta {
         CategoryLandingMeta {
-            category: Category::Tech,
+            category: Category::new("tech".to_string()).unwrap(),
             title: Title::new("Tech".to_string()).unwrap(),
             description: Some("Tech landing".to_string()),
             updated_at: Timestamp::new("2025-01-01T00:00:00+09:00".to_string()).unwrap(),
@@ -152,27 +324,91 @@ mod tests {
                 html: "<p>Welcome</p>".to_string(),
                 updated_at: "2025-01-01T00:00:00+09:00".to_string(),
             }),
+            vec![RedirectDocument {
+                from: "/tech/old-artifact".to_string(),
+                to: "/tech/artifact00001".to_string(),
+            }],
+            domain::LinkGraphDocument::default(),
+            &test_category_registry(),
         )
         .unwrap();
 
         let article_path = write_article_page(
             &directories,
-            article.category,
+            &article.category,
             &article.slug,
             "<h1>Artifact Test</h1>",
         )
         .unwrap();
-        write_site_artifacts(&directories, &artifacts).unwrap();
+        let written = write_site_artifacts(&directories, &artifacts).unwrap();
 
+        assert_eq!(written.len(), 9);
+        assert!(written.iter().all(|path| path.exists()));
         for path in [
             article_path,
             directories.articles_dir.join("index.json"),
             directories.categories_dir.join("tech.json"),
             directories.pages_dir.join("about.json"),
+            directories.tags_dir.join("index.json"),
+            directories.tags_dir.join("rust.json"),
             directories.home_fragment_path.clone(),
             directories.metadata_dir.join("site.json"),
+            directories.redirects_path.clone(),
+            directories.link_graph_path.clone(),
         ] {
             assert!(path.exists(), "{} should exist", path.display());
         }
     }
+
+    #[test]
+    fn test_rewrites_replace_changed_files_and_remove_stale_ones() {
+        let temp_dir = TempDir::new().unwrap();
+        let directories = SiteDirectories::prepare(temp_dir.path()).unwrap();
+        let category = Category::new("tech".to_string()).unwrap();
+        let write = |slug: &str, html: &str| {
+            let slug = Slug::new(slug.to_string()).unwrap();
+            write_article_page(&directories, &category, &slug, html).unwrap()
+        };
+
+        let kept = write("kept00000001", "<p>v1</p>");
+        let dropped = write("dropped00001", "<p>v1</p>");
+        let modified = fs::metadata(&kept).unwrap().modified().unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        write("kept00000001", "<p>v1</p>");
+        assert_eq!(fs::metadata(&kept).unwrap().modified().unwrap(), modified);
+
+        write("kept00000001", "<p>v2</p>");
+        remove_stale_artifacts(
+            &BTreeSet::from([kept.clone(), dropped.clone()]),
+            &BTreeSet::from([kept.clone()]),
+        )
+        .unwrap();
+
+        assert_eq!(fs::read_to_string(&kept).unwrap(), "<p>v2</p>");
+        assert!(!dropped.exists());
+        assert!(!temp_path(&kept).exists());
+    }
+
+    #[test]
+    fn test_write_assets_copies_attachments_to_hashed_names() {
+        let temp_dir = TempDir::new().unwrap();
+        let directories = SiteDirectories::prepare(temp_dir.path().join("dist")).unwrap();
+        let source_path = temp_dir.path().join("diagram.png");
+        fs::write(&source_path, "png bytes").unwrap();
+
+        write_assets(
+            &directories,
+            &[Attachment {
+                source_key: "diagram.png".to_string(),
+                source_path,
+                asset_name: AssetName::new("00112233aabbccdd.png".to_string()).unwrap(),
+            }],
+        )
+        .unwrap();
+
+        assert_eq!(
+            fs::read_to_string(directories.assets_dir.join("00112233aabbccdd.png")).unwrap(),
+            "png bytes"
+        );
+    }
 }
@@ -0,0 +1,92 @@
+use crate::error::{PublishError, Result};
+use domain::AssetName;
+use sha2::{Digest, Sha256};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Vault attachment published under a content-hashed asset name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Attachment {
+    pub(crate) source_key: String,
+    pub(crate) source_path: PathBuf,
+    pub(crate) asset_name: AssetName,
+}
+
+/// Hashes attachment contents so unchanged files keep stable, long-cacheable names.
+pub(crate) fn hash_attachments(paths: Vec<PathBuf>, vault_dir: &Path) -> Result<Vec<Attachment>> {
+    paths
+        .into_iter()
+        .map(|source_path| {
+            let source_key = source_path
+                .strip_prefix(vault_dir)?
+                .to_string_lossy()
+                .into_owned();
+            let asset_name = generate_asset_name(&source_path)?;
+            Ok(Attachment {
+                source_key,
+                source_path,
+                asset_name,
+            })
+        })
+        .collect()
+}
+
+fn generate_asset_name(path: &Path) -> Result<AssetName> {
+    let extension = path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .ok_or_else(|| PublishError::InvalidPath(format!("{} has no extension", path.display())))?
+        .to_ascii_lowercase();
+
+    let mut hasher = Sha256::new();
+    hasher.update(fs::read(path)?);
+    let hash_result = hasher.finalize();
+
+    // Use the first 8 bytes to build a 16-character hex file stem.
+    let stem = hash_result[..8]
+        .iter()
+        .fold(String::with_capacity(16), |mut acc, byte| {
+            acc.push_str(&format!("{byte:02x}"));
+            acc
+        });
+
+    AssetName::new(format!("{stem}.{extension}")).map_err(Into::into)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_hash_attachments_names_files_by_content() {
+        let temp_dir = TempDir::new().unwrap();
+        let vault_dir = temp_dir.path();
+        fs::create_dir_all(vault_dir.join("attachments")).unwrap();
+        fs::write(vault_dir.join("attachments/Figure.PNG"), "same").unwrap();
+        fs::write(vault_dir.join("copy.png"), "same").unwrap();
+        fs::write(vault_dir.join("other.png"), "different").unwrap();
+
+        let attachments = hash_attachments(
+            vec![
+                vault_dir.join("attachments/Figure.PNG"),
+                vault_dir.join("copy.png"),
+                vault_dir.join("other.png"),
+            ],
+            vault_dir,
+        )
+        .unwrap();
+
+        assert_eq!(attachments[0].source_key, "attachments/Figure.PNG");
+        assert_eq!(
+            attachments[0].asset_name.as_str().len(),
+            "0123456789abcdef.png".len()
+        );
+        assert_eq!(attachments[0].asset_name.extension(), "png");
+        assert_eq!(attachments[0].asset_name, attachments[1].asset_name);
+        assert_ne!(attachments[0].asset_name, attachments[2].asset_name);
+    }
+}
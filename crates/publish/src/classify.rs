@@ -1,7 +1,17 @@
+use crate::diagnostics::{Diagnostic, DiagnosticCode};
 use crate::error::{PublishError, Result};
-use crate::vault::{ContentKind, ObsidianFrontMatter, ParsedObsidianFile, parse_obsidian_file};
-use domain::{Category, PageKey, SectionPath, Slug};
-use std::collections::HashSet;
+use crate::vault::{
+    ContentKind, ObsidianFrontMatter, ParsedObsidianFile, SourceCache, StrictFrontmatter,
+};
+use chrono::{DateTime, Utc};
+use domain::{
+    Category, CategoryRegistry, PageKey, RedirectDocument, SectionPath, SeriesKey, SeriesPart,
+    Slug, Timestamp,
+};
+use std::collections::{HashMap, HashSet, hash_map::Entry};
+use std::fmt;
+use std::hash::Hash;
+use std::iter;
 use std::path::{Path, PathBuf};
 use tracing::{error, warn};
 
@@ -12,8 +22,35 @@ pub(crate) struct ParsedArticleFile {
     pub(crate) source_key: String,
     /// Category-relative directories used to group articles in category navigation.
     pub(crate) section_path: SectionPath,
+    /// Former article locations that redirect to the current one.
+    pub(crate) aliases: Vec<ArticlePath>,
+    pub(crate) series: Option<SeriesPart>,
+    pub(crate) publish_at: Option<Timestamp>,
     pub(crate) markdown_body: String,
     pub(crate) front_matter: ObsidianFrontMatter,
+    pub(crate) location: SourceLocation,
+}
+
+impl ParsedArticleFile {
+    pub(crate) fn path(&self) -> ArticlePath {
+        ArticlePath {
+            category: self.category.clone(),
+            slug: self.slug.clone(),
+        }
+    }
+}
+
+/// Category-qualified article location served at `/{category}/{slug}`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct ArticlePath {
+    pub(crate) category: Category,
+    pub(crate) slug: Slug,
+}
+
+impl fmt::Display for ArticlePath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "/{}/{}", self.category.as_str(), self.slug)
+    }
 }
 
 pub(crate) struct ParsedPageFile {
@@ -22,6 +59,7 @@ pub(crate) struct ParsedPageFile {
     pub(crate) source_key: String,
     pub(crate) markdown_body: String,
     pub(crate) front_matter: ObsidianFrontMatter,
+    pub(crate) location: SourceLocation,
 }
 
 pub(crate) struct ParsedHomeFile {
@@ -29,6 +67,7 @@ pub(crate) struct ParsedHomeFile {
     pub(crate) source_key: String,
     pub(crate) markdown_body: String,
     pub(crate) front_matter: ObsidianFrontMatter,
+    pub(crate) location: SourceLocation,
 }
 
 pub(crate) struct ParsedCategoryFile {
@@ -37,6 +76,80 @@ pub(crate) struct ParsedCategoryFile {
     pub(crate) source_key: String,
     pub(crate) markdown_body: String,
     pub(crate) front_matter: ObsidianFrontMatter,
+    pub(crate) location: SourceLocation,
+}
+
+/// Where a note's body sits in the vault, so diagnostics can point at its source lines.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SourceLocation {
+    /// Markdown file path as scanned from the vault.
+    pub(crate) path: PathBuf,
+    /// One-based line where `markdown_body` starts.
+    pub(crate) body_line: usize,
+    /// One-based lines of the frontmatter fields, by key.
+    pub(crate) field_lines: HashMap<String, usize>,
+}
+
+impl SourceLocation {
+    /// Places `diagnostic` in this file, at the line of the frontmatter `field` when it is set.
+    pub(crate) fn at_field(&self, diagnostic: Diagnostic, field: &str) -> Diagnostic {
+        let diagnostic = diagnostic.in_file(&self.path);
+        match self.field_lines.get(field) {
+            Some(line) => diagnostic.at(*line, 1),
+            None => diagnostic,
+        }
+    }
+}
+
+/// A rule broken across notes, with the frontmatter field of every note involved.
+#[derive(Debug)]
+pub(crate) struct SiteConflict {
+    pub(crate) error: PublishError,
+    pub(crate) sources: Vec<(SourceLocation, &'static str)>,
+}
+
+impl SiteConflict {
+    fn new(error: PublishError) -> Self {
+        Self {
+            error,
+            sources: Vec::new(),
+        }
+    }
+
+    fn involving(mut self, location: &SourceLocation, field: &'static str) -> Self {
+        self.sources.push((location.clone(), field));
+        self
+    }
+
+    /// One diagnostic per note involved, at the field that causes the conflict.
+    pub(crate) fn diagnostics(&self, code: DiagnosticCode) -> Vec<Diagnostic> {
+        let message = self.error.to_string();
+        self.sources
+            .iter()
+            .map(|(location, field)| {
+                location.at_field(Diagnostic::error(code, message.clone()), field)
+            })
+            .collect()
+    }
+}
+
+impl From<SiteConflict> for PublishError {
+    fn from(conflict: SiteConflict) -> Self {
+        conflict.error
+    }
+}
+
+/// Completed content held back because its `publish_at` is still ahead.
+pub(crate) struct ScheduledFile {
+    pub(crate) source_key: String,
+    pub(crate) publish_at: Timestamp,
+}
+
+/// Publish clock and whether articles scheduled after it still go into the release.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Schedule {
+    pub(crate) now: DateTime<Utc>,
+    pub(crate) include_scheduled_articles: bool,
 }
 
 enum ClassifiedFile {
@@ -44,6 +157,7 @@ enum ClassifiedFile {
     Page(ParsedPageFile),
     Home(ParsedHomeFile),
     Category(ParsedCategoryFile),
+    Scheduled(ScheduledFile),
 }
 
 #[derive(Default)]
@@ -52,11 +166,59 @@ pub(crate) struct ClassifiedFiles {
     pub(crate) pages: Vec<ParsedPageFile>,
     pub(crate) home: Option<ParsedHomeFile>,
     pub(crate) categories: Vec<ParsedCategoryFile>,
+    pub(crate) scheduled: Vec<ScheduledFile>,
     pub(crate) skipped: usize,
-    pub(crate) errors: usize,
+    /// Files that could not be classified, each reported with the position at fault.
+    pub(crate) errors: Vec<Diagnostic>,
 }
 
 impl ClassifiedFiles {
+    /// Markdown body and source location of every note that is published.
+    pub(crate) fn notes(&self) -> impl Iterator<Item = (&str, &SourceLocation)> {
+        self.keyed_notes()
+            .map(|(_, markdown_body, location)| (markdown_body, location))
+    }
+
+    /// Markdown body and location of the note published from `source_key`.
+    pub(crate) fn note(&self, source_key: &str) -> Option<(&str, &SourceLocation)> {
+        self.keyed_notes()
+            .find(|(key, _, _)| *key == source_key)
+            .map(|(_, markdown_body, location)| (markdown_body, location))
+    }
+
+    /// Source key, Markdown body and source location of every note that is published.
+    pub(crate) fn keyed_notes(&self) -> impl Iterator<Item = (&str, &str, &SourceLocation)> {
+        let articles = self.articles.iter().map(|file| {
+            (
+                file.source_key.as_str(),
+                file.markdown_body.as_str(),
+                &file.location,
+            )
+        });
+        let pages = self.pages.iter().map(|file| {
+            (
+                file.source_key.as_str(),
+                file.markdown_body.as_str(),
+                &file.location,
+            )
+        });
+        let home = self.home.iter().map(|file| {
+            (
+                file.source_key.as_str(),
+                file.markdown_body.as_str(),
+                &file.location,
+            )
+        });
+        let categories = self.categories.iter().map(|file| {
+            (
+                file.source_key.as_str(),
+                file.markdown_body.as_str(),
+                &file.location,
+            )
+        });
+        articles.chain(pages).chain(home).chain(categories)
+    }
+
     fn add(&mut self, file: ClassifiedFile) -> Result<()> {
         match file {
             ClassifiedFile::Article(file) => self.articles.push(file),
@@ -70,76 +232,193 @@ impl ClassifiedFiles {
                 self.home = Some(file);
             }
             ClassifiedFile::Category(file) => self.categories.push(file),
+            ClassifiedFile::Scheduled(file) => self.scheduled.push(file),
         }
         Ok(())
     }
 }
 
+/// Classifies `markdown_files`, parsing only the notes `sources` does not already hold.
 pub(crate) fn classify_obsidian_files(
     markdown_files: Vec<PathBuf>,
+    sources: &mut SourceCache,
     obsidian_dir: &Path,
+    registry: &CategoryRegistry,
+    schedule: Schedule,
+    mut strict: Option<StrictFrontmatter<'_>>,
 ) -> ClassifiedFiles {
     let mut classified_files = ClassifiedFiles::default();
 
     for file_path in markdown_files {
-        match classify_file(&file_path, obsidian_dir) {
-            Ok(Some(file)) => {
-                if let Err(error) = classified_files.add(file) {
-                    classified_files.errors += 1;
+        let diagnostic = match classify_file(
+            &file_path,
+            sources,
+            obsidian_dir,
+            registry,
+            schedule,
+            strict.as_mut(),
+        ) {
+            Ok(Some(file)) => match classified_files.add(file) {
+                Ok(()) => continue,
+                Err(error) => {
                     error!(file_path = %file_path.display(), %error, "failed to process file");
+                    Diagnostic::error(DiagnosticCode::DuplicateRoute, error.to_string())
                 }
-            }
+            },
             Ok(None) => {
                 classified_files.skipped += 1;
                 warn!(file_path = %file_path.display(), "skipped incomplete file");
+                continue;
             }
-            Err(error) => {
-                classified_files.errors += 1;
+            Err(FileError { error, position }) => {
                 error!(file_path = %file_path.display(), %error, "failed to process file");
+                let diagnostic =
+                    Diagnostic::error(DiagnosticCode::InvalidFrontmatter, error.to_string());
+                match position.or_else(|| yaml_error_position(&error)) {
+                    Some((line, column)) => diagnostic.at(line, column),
+                    None => diagnostic,
+                }
             }
-        }
+        };
+        classified_files.errors.push(diagnostic.in_file(&file_path));
+    }
+
+    for finding in strict.map(StrictFrontmatter::finish).into_iter().flatten() {
+        error!(diagnostic = %finding, "strict frontmatter check failed");
+        classified_files.errors.push(finding);
     }
 
     classified_files
 }
 
-fn classify_file(file_path: &Path, obsidian_dir: &Path) -> Result<Option<ClassifiedFile>> {
-    let Some(parsed_file) = parse_obsidian_file(file_path)? else {
+/// A file that failed classification, with the `(line, column)` in it that is at fault.
+struct FileError {
+    error: PublishError,
+    position: Option<(usize, usize)>,
+}
+
+impl From<PublishError> for FileError {
+    fn from(error: PublishError) -> Self {
+        Self {
+            error,
+            position: None,
+        }
+    }
+}
+
+fn yaml_error_position(error: &PublishError) -> Option<(usize, usize)> {
+    match error {
+        PublishError::Yaml(error) => error
+            .location()
+            .map(|location| (location.line(), location.column())),
+        _ => None,
+    }
+}
+
+fn classify_file(
+    file_path: &Path,
+    sources: &mut SourceCache,
+    obsidian_dir: &Path,
+    registry: &CategoryRegistry,
+    schedule: Schedule,
+    strict: Option<&mut StrictFrontmatter<'_>>,
+) -> std::result::Result<Option<ClassifiedFile>, FileError> {
+    let Some(parsed_file) = sources.parse(file_path)? else {
         return Ok(None);
     };
     if !parsed_file.front_matter.is_completed {
         return Ok(None);
     }
+    if let Some(strict) = strict {
+        strict.check_file(
+            file_path,
+            &parsed_file.front_matter,
+            &parsed_file.field_lines,
+        );
+    }
 
+    let field_lines = parsed_file.field_lines.clone();
+    classify_parsed_file(file_path, parsed_file, obsidian_dir, registry, schedule).map_err(
+        |error| {
+            let position = match &error {
+                PublishError::InvalidField { field, .. } => {
+                    field_lines.get(*field).map(|line| (*line, 1))
+                }
+                _ => None,
+            };
+            FileError { error, position }
+        },
+    )
+}
+
+fn classify_parsed_file(
+    file_path: &Path,
+    parsed_file: ParsedObsidianFile,
+    obsidian_dir: &Path,
+    registry: &CategoryRegistry,
+    schedule: Schedule,
+) -> Result<Option<ClassifiedFile>> {
     let source_key = derive_source_key(file_path, obsidian_dir)?;
+    let location = SourceLocation {
+        path: file_path.to_path_buf(),
+        body_line: parsed_file.body_line,
+        field_lines: parsed_file.field_lines.clone(),
+    };
+    let publish_at = parsed_file
+        .front_matter
+        .publish_at
+        .as_deref()
+        .map(|publish_at| Timestamp::new(publish_at.to_string()))
+        .transpose()
+        .map_err(invalid_field("publish_at"))?;
+    if let Some(publish_at) = &publish_at
+        && publish_at.to_utc() > schedule.now
+        // Only articles can be hidden by the server, so other scheduled content waits for a later run.
+        && !(schedule.include_scheduled_articles
+            && parsed_file.front_matter.kind == ContentKind::Article)
+    {
+        return Ok(Some(ClassifiedFile::Scheduled(ScheduledFile {
+            source_key,
+            publish_at: publish_at.clone(),
+        })));
+    }
+
     let classified_file = match parsed_file.front_matter.kind {
         ContentKind::Article => ClassifiedFile::Article(process_article_file(
             file_path,
             parsed_file,
             obsidian_dir,
             source_key,
+            publish_at,
+            registry,
+            location,
         )?),
         ContentKind::Page => {
-            let page = parse_page_key(parsed_file.front_matter.page.as_deref())?;
+            let page = parse_page_key(parsed_file.front_matter.page.as_deref())
+                .map_err(invalid_field("page"))?;
             ClassifiedFile::Page(ParsedPageFile {
                 page,
                 source_key,
                 markdown_body: parsed_file.markdown_body,
                 front_matter: parsed_file.front_matter,
+                location,
             })
         }
         ContentKind::Home => ClassifiedFile::Home(ParsedHomeFile {
             source_key,
             markdown_body: parsed_file.markdown_body,
             front_matter: parsed_file.front_matter,
+            location,
         }),
         ContentKind::Category => {
-            let category = parse_category(parsed_file.front_matter.category.as_deref())?;
+            let category = parse_category(parsed_file.front_matter.category.as_deref(), registry)
+                .map_err(invalid_field("category"))?;
             ClassifiedFile::Category(ParsedCategoryFile {
                 category,
                 source_key,
                 markdown_body: parsed_file.markdown_body,
                 front_matter: parsed_file.front_matter,
+                location,
             })
         }
     };
@@ -152,15 +431,38 @@ fn process_article_file(
     parsed_file: ParsedObsidianFile,
     obsidian_dir: &Path,
     source_key: String,
+    publish_at: Option<Timestamp>,
+    registry: &CategoryRegistry,
+    location: SourceLocation,
 ) -> Result<ParsedArticleFile> {
     let relative_path = file_path.strip_prefix(obsidian_dir)?;
-    let category = parse_category(parsed_file.front_matter.category.as_deref())?;
+    let category = parse_category(parsed_file.front_matter.category.as_deref(), registry)
+        .map_err(invalid_field("category"))?;
     let category_relative_path = relative_path.strip_prefix(category.as_str())?;
-    let slug = crate::slug::generate_slug(
-        &parsed_file.front_matter.title,
-        relative_path,
-        &parsed_file.front_matter.created,
-    )?;
+    let slug = match parsed_file.front_matter.slug.as_deref() {
+        Some(slug) => Slug::new(slug.trim().to_string()).map_err(invalid_field("slug"))?,
+        None => crate::slug::generate_slug(
+            &parsed_file.front_matter.title,
+            relative_path,
+            &parsed_file.front_matter.created,
+        )?,
+    };
+    let aliases = parsed_file
+        .front_matter
+        .aliases
+        .iter()
+        .map(|alias| parse_alias(alias, &category, registry))
+        .collect::<Result<Vec<_>>>()
+        .map_err(invalid_field("aliases"))?;
+    let series_field = match parsed_file.front_matter.series {
+        Some(_) => "series",
+        None => "series_order",
+    };
+    let series = parse_series_part(
+        parsed_file.front_matter.series.as_deref(),
+        parsed_file.front_matter.series_order,
+    )
+    .map_err(invalid_field(series_field))?;
     let section_path = derive_section_path(category_relative_path);
 
     Ok(ParsedArticleFile {
@@ -168,11 +470,40 @@ fn process_article_file(
         slug,
         source_key,
         section_path,
+        aliases,
+        series,
+        publish_at,
         markdown_body: parsed_file.markdown_body,
+        location,
         front_matter: parsed_file.front_matter,
     })
 }
 
+/// Names the frontmatter field an error came from, so diagnostics can point at its line.
+fn invalid_field<E: Into<PublishError>>(field: &'static str) -> impl FnOnce(E) -> PublishError {
+    move |error| PublishError::InvalidField {
+        field,
+        source: Box::new(error.into()),
+    }
+}
+
+/// Requires `series` and `series_order` to be set together; part numbering is checked per series later.
+fn parse_series_part(series: Option<&str>, order: Option<u32>) -> Result<Option<SeriesPart>> {
+    match (series, order) {
+        (None, None) => Ok(None),
+        (Some(series), Some(order)) => Ok(Some(SeriesPart {
+            series: SeriesKey::new(series.trim().to_string())?,
+            order,
+        })),
+        (Some(series), None) => Err(PublishError::Parse(format!(
+            "Series {series} requires series_order"
+        ))),
+        (None, Some(_)) => Err(PublishError::Parse(
+            "series_order requires series".to_string(),
+        )),
+    }
+}
+
 fn derive_source_key(file_path: &Path, obsidian_dir: &Path) -> Result<String> {
     Ok(file_path
         .strip_prefix(obsidian_dir)?
@@ -194,52 +525,181 @@ fn derive_section_path(category_relative_path: &Path) -> SectionPath {
     SectionPath::new(segments)
 }
 
-pub(crate) fn ensure_unique_page_keys(pages: &[ParsedPageFile]) -> Result<()> {
-    let mut seen = HashSet::with_capacity(pages.len());
-    for parsed_page in pages {
-        if !seen.insert(parsed_page.page.as_str()) {
-            return Err(PublishError::Parse(format!(
-                "Duplicate page key detected: {}",
+/// Rejects page keys claimed by more than one page, one conflict per key.
+pub(crate) fn ensure_unique_page_keys(pages: &[ParsedPageFile]) -> Vec<SiteConflict> {
+    duplicates(
+        pages
+            .iter()
+            .map(|parsed_page| (parsed_page.page.as_str(), &parsed_page.location)),
+    )
+    .into_iter()
+    .map(|(page, locations)| {
+        locations.into_iter().fold(
+            SiteConflict::new(PublishError::Parse(format!(
+                "Duplicate page key detected: {page}"
+            ))),
+            |conflict, location| conflict.involving(location, "page"),
+        )
+    })
+    .collect()
+}
+
+/// Rejects page keys that collide with a configured category, which owns the shared top-level URL.
+pub(crate) fn ensure_page_keys_do_not_shadow_categories(
+    pages: &[ParsedPageFile],
+    registry: &CategoryRegistry,
+) -> Vec<SiteConflict> {
+    pages
+        .iter()
+        .filter(|parsed_page| registry.resolve(parsed_page.page.as_str()).is_ok())
+        .map(|parsed_page| {
+            SiteConflict::new(PublishError::Parse(format!(
+                "Page key {} shadows the category with the same key",
                 parsed_page.page.as_str()
-            )));
-        }
-    }
-    Ok(())
+            )))
+            .involving(&parsed_page.location, "page")
+        })
+        .collect()
+}
+
+/// Rejects categories with more than one landing note, one conflict per category.
+pub(crate) fn ensure_unique_category_landings(
+    categories: &[ParsedCategoryFile],
+) -> Vec<SiteConflict> {
+    duplicates(
+        categories
+            .iter()
+            .map(|parsed_category| (parsed_category.category.as_str(), &parsed_category.location)),
+    )
+    .into_iter()
+    .map(|(category, locations)| {
+        locations.into_iter().fold(
+            SiteConflict::new(PublishError::Parse(format!(
+                "Duplicate category landing detected: {category}"
+            ))),
+            |conflict, location| conflict.involving(location, "category"),
+        )
+    })
+    .collect()
+}
+
+/// Rejects articles whose slugs or aliases resolve to the same URL, one conflict per URL.
+pub(crate) fn ensure_unique_article_paths(articles: &[ParsedArticleFile]) -> Vec<SiteConflict> {
+    let claims = articles.iter().flat_map(|article| {
+        iter::once((article.path(), (article, "slug"))).chain(
+            article
+                .aliases
+                .iter()
+                .map(move |alias| (alias.clone(), (article, "aliases"))),
+        )
+    });
+    duplicates(claims)
+        .into_iter()
+        .map(|(path, claimants)| {
+            let conflict = SiteConflict::new(PublishError::SlugCollision {
+                path: path.to_string(),
+                first: claimants[0].0.source_key.clone(),
+                second: claimants[1].0.source_key.clone(),
+            });
+            claimants
+                .into_iter()
+                .fold(conflict, |conflict, (article, field)| {
+                    conflict.involving(&article.location, field)
+                })
+        })
+        .collect()
 }
 
-pub(crate) fn ensure_unique_category_landings(categories: &[ParsedCategoryFile]) -> Result<()> {
-    let mut seen = HashSet::with_capacity(categories.len());
-    for parsed_category in categories {
-        if !seen.insert(parsed_category.category.as_str()) {
-            return Err(PublishError::Parse(format!(
-                "Duplicate category landing detected: {}",
-                parsed_category.category.as_str()
-            )));
+/// Keys claimed more than once, each with every value claiming it.
+fn duplicates<K: Clone + Eq + Hash, V>(
+    claims: impl IntoIterator<Item = (K, V)>,
+) -> Vec<(K, Vec<V>)> {
+    let mut groups = grouped(claims);
+    groups.retain(|(_, values)| values.len() > 1);
+    groups
+}
+
+/// Groups values by key, in the order the keys are first seen.
+fn grouped<K: Clone + Eq + Hash, V>(claims: impl IntoIterator<Item = (K, V)>) -> Vec<(K, Vec<V>)> {
+    let mut positions: HashMap<K, usize> = HashMap::new();
+    let mut groups: Vec<(K, Vec<V>)> = Vec::new();
+    for (key, value) in claims {
+        match positions.entry(key) {
+            Entry::Occupied(entry) => groups[*entry.get()].1.push(value),
+            Entry::Vacant(entry) => {
+                groups.push((entry.key().clone(), vec![value]));
+                entry.insert(groups.len() - 1);
+            }
         }
     }
-    Ok(())
+    groups
 }
 
+/// Maps every article alias to the current article URL, sorted by the former URL.
+pub(crate) fn collect_redirects(articles: &[ParsedArticleFile]) -> Vec<RedirectDocument> {
+    let mut redirects: Vec<_> = articles
+        .iter()
+        .flat_map(|article| {
+            let to = article.path().to_string();
+            article.aliases.iter().map(move |alias| RedirectDocument {
+                from: alias.to_string(),
+                to: to.clone(),
+            })
+        })
+        .collect();
+    redirects.sort_unstable_by(|left, right| left.from.cmp(&right.from));
+    redirects
+}
+
+/// Rejects articles filed under a category without a landing note, one conflict per category
+/// naming every such article.
 pub(crate) fn ensure_category_landings(
     articles: &[ParsedArticleFile],
     categories: &[ParsedCategoryFile],
-) -> Result<()> {
-    let category_landings: HashSet<_> = categories.iter().map(|file| file.category).collect();
-    let missing = articles
-        .iter()
-        .map(|file| file.category)
-        .find(|category| !category_landings.contains(category));
-
-    match missing {
-        Some(category) => Err(PublishError::MissingCategoryLanding { category }),
-        None => Ok(()),
-    }
+) -> Vec<SiteConflict> {
+    let category_landings: HashSet<_> = categories.iter().map(|file| &file.category).collect();
+    grouped(
+        articles
+            .iter()
+            .filter(|article| !category_landings.contains(&article.category))
+            .map(|article| (&article.category, &article.location)),
+    )
+    .into_iter()
+    .map(|(category, locations)| {
+        locations.into_iter().fold(
+            SiteConflict::new(PublishError::MissingCategoryLanding {
+                category: category.clone(),
+            }),
+            |conflict, location| conflict.involving(location, "category"),
+        )
+    })
+    .collect()
 }
 
-fn parse_category(category: Option<&str>) -> Result<Category> {
+/// Resolves a frontmatter category against the categories declared in the site config.
+fn parse_category(category: Option<&str>, registry: &CategoryRegistry) -> Result<Category> {
     let category = category
         .ok_or_else(|| PublishError::Parse("Completed content requires a category".to_string()))?;
-    category.parse().map_err(Into::into)
+    registry.resolve(category).map_err(Into::into)
+}
+
+/// Accepts a bare former slug in the article's category or a former `/category/slug` path.
+fn parse_alias(
+    alias: &str,
+    category: &Category,
+    registry: &CategoryRegistry,
+) -> Result<ArticlePath> {
+    let alias = alias.trim();
+    let alias = alias.strip_prefix('/').unwrap_or(alias);
+    let alias = alias.strip_suffix(".html").unwrap_or(alias);
+    let (category, slug) = match alias.split_once('/') {
+        Some((category, slug)) => (registry.resolve(category)?, slug),
+        None => (category.clone(), alias),
+    };
+    Ok(ArticlePath {
+        category,
+        slug: Slug::new(slug.to_string())?,
+    })
 }
 
 fn parse_page_key(page: Option<&str>) -> Result<PageKey> {
@@ -251,53 +711,69 @@ fn parse_page_key(page: Option<&str>) -> Result<PageKey> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::test_category_registry;
     use rstest::rstest;
+    use std::fs;
 
     const TEST_TIMESTAMP: &str = "2025-01-01T00:00:00+09:00";
 
-    fn front_matter(kind: ContentKind) -> ObsidianFrontMatter {
-        ObsidianFrontMatter {
-            title: "Test".to_string(),
-            kind,
-            tags: None,
-            summary: None,
-            is_completed: true,
-            priority: None,
-            created: TEST_TIMESTAMP.to_string(),
-            updated: TEST_TIMESTAMP.to_string(),
-            category: None,
-            page: None,
-        }
-    }
-
     fn parsed_article(category: Category) -> ParsedObsidianFile {
-        let mut front_matter = front_matter(ContentKind::Article);
+        let mut front_matter = ObsidianFrontMatter::for_test(ContentKind::Article);
         front_matter.category = Some(category.as_str().to_string());
         ParsedObsidianFile {
             front_matter,
             markdown_body: String::new(),
+            body_line: 1,
+            field_lines: HashMap::new(),
+        }
+    }
+
+    fn parsed_article_file(source_key: &str, slug: &str, aliases: &[&str]) -> ParsedArticleFile {
+        ParsedArticleFile {
+            category: Category::new("tech".to_string()).unwrap(),
+            slug: Slug::new(slug.to_string()).unwrap(),
+            source_key: source_key.to_string(),
+            section_path: SectionPath::default(),
+            aliases: aliases
+                .iter()
+                .map(|alias| {
+                    parse_alias(
+                        alias,
+                        &Category::new("tech".to_string()).unwrap(),
+                        &test_category_registry(),
+                    )
+                    .unwrap()
+                })
+                .collect(),
+            series: None,
+            publish_at: None,
+            markdown_body: String::new(),
+            front_matter: ObsidianFrontMatter::for_test(ContentKind::Article),
+            location: SourceLocation::default(),
         }
     }
 
     fn parsed_category(category: Category) -> ParsedCategoryFile {
-        let mut front_matter = front_matter(ContentKind::Category);
+        let mut front_matter = ObsidianFrontMatter::for_test(ContentKind::Category);
         front_matter.category = Some(category.as_str().to_string());
         ParsedCategoryFile {
-            category,
             source_key: format!("{}/index", category.as_str()),
+            category,
             markdown_body: String::new(),
             front_matter,
+            location: SourceLocation::default(),
         }
     }
 
     fn parsed_page(page: &str) -> ParsedPageFile {
-        let mut front_matter = front_matter(ContentKind::Page);
+        let mut front_matter = ObsidianFrontMatter::for_test(ContentKind::Page);
         front_matter.page = Some(page.to_string());
         ParsedPageFile {
             page: PageKey::new(page.to_string()).unwrap(),
             source_key: format!("pages/{page}"),
             markdown_body: String::new(),
             front_matter,
+            location: SourceLocation::default(),
         }
     }
 
@@ -305,7 +781,8 @@ mod tests {
         ParsedHomeFile {
             source_key: "home".to_string(),
             markdown_body: String::new(),
-            front_matter: front_matter(ContentKind::Home),
+            front_matter: ObsidianFrontMatter::for_test(ContentKind::Home),
+            location: SourceLocation::default(),
         }
     }
 
@@ -323,33 +800,303 @@ mod tests {
 
     #[test]
     fn test_ensure_unique_category_landings_rejects_duplicates() {
-        let categories = vec![
-            parsed_category(Category::Tech),
-            parsed_category(Category::Tech),
+        let mut categories = vec![
+            parsed_category(Category::new("tech".to_string()).unwrap()),
+            parsed_category(Category::new("tech".to_string()).unwrap()),
         ];
+        for (parsed_category, path) in categories
+            .iter_mut()
+            .zip(["tech/index.md", "tech/about.md"])
+        {
+            parsed_category.location = SourceLocation {
+                path: PathBuf::from(path),
+                body_line: 6,
+                field_lines: HashMap::from([("category".to_string(), 3)]),
+            };
+        }
 
-        let result = ensure_unique_category_landings(&categories);
+        let conflicts = ensure_unique_category_landings(&categories);
+        let [conflict] = conflicts.as_slice() else {
+            panic!("expected one conflict, got {}", conflicts.len());
+        };
 
         assert!(
-            matches!(result, Err(PublishError::Parse(message)) if message.contains("Duplicate category landing"))
+            matches!(&conflict.error, PublishError::Parse(message) if message.contains("Duplicate category landing"))
+        );
+        let positions: Vec<_> = conflict
+            .diagnostics(DiagnosticCode::DuplicateRoute)
+            .into_iter()
+            .map(|diagnostic| (diagnostic.file.unwrap(), diagnostic.line))
+            .collect();
+        assert_eq!(
+            positions,
+            [
+                ("tech/index.md".to_string(), Some(3)),
+                ("tech/about.md".to_string(), Some(3)),
+            ]
         );
     }
 
     #[test]
     fn test_process_article_file_rejects_category_path_mismatch() {
         let obsidian_dir = Path::new("/vault");
-        let parsed_file = parsed_article(Category::Tech);
+        let parsed_file = parsed_article(Category::new("tech".to_string()).unwrap());
 
         let result = process_article_file(
             Path::new("/vault/daily/article.md"),
             parsed_file,
             obsidian_dir,
             "daily/article".to_string(),
+            None,
+            &test_category_registry(),
+            SourceLocation::default(),
         );
 
         assert!(matches!(result, Err(PublishError::StripPrefix(_))));
     }
 
+    #[test]
+    fn test_process_article_file_prefers_explicit_slug_and_parses_aliases() {
+        let mut parsed_file = parsed_article(Category::new("tech".to_string()).unwrap());
+        parsed_file.front_matter.slug = Some("stable-article".to_string());
+        parsed_file.front_matter.aliases =
+            vec!["0123456789ab".to_string(), "/daily/old-article".to_string()];
+
+        let article = process_article_file(
+            Path::new("/vault/tech/article.md"),
+            parsed_file,
+            Path::new("/vault"),
+            "tech/article".to_string(),
+            None,
+            &test_category_registry(),
+            SourceLocation::default(),
+        )
+        .unwrap();
+
+        assert_eq!(article.path().to_string(), "/tech/stable-article");
+        assert_eq!(
+            article
+                .aliases
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>(),
+            ["/tech/0123456789ab", "/daily/old-article"]
+        );
+    }
+
+    #[rstest]
+    #[case::skips_future_content(false, ContentKind::Article, 0, 1)]
+    #[case::keeps_future_articles(true, ContentKind::Article, 1, 0)]
+    #[case::skips_future_pages(true, ContentKind::Page, 0, 1)]
+    fn test_classify_obsidian_files_holds_back_scheduled_content(
+        #[case] include_scheduled_articles: bool,
+        #[case] kind: ContentKind,
+        #[case] article_count: usize,
+        #[case] scheduled_count: usize,
+    ) {
+        let vault = tempfile::TempDir::new().unwrap();
+        let kind = match kind {
+            ContentKind::Page => "kind: page\npage: contact",
+            _ => "kind: article",
+        };
+        fs::create_dir_all(vault.path().join("tech")).unwrap();
+        let file_path = vault.path().join("tech/scheduled.md");
+        fs::write(
+            &file_path,
+            format!(
+                "---\ntitle: Scheduled\n{kind}\ncategory: tech\nis_completed: true\ncreated: \"{TEST_TIMESTAMP}\"\nupdated: \"{TEST_TIMESTAMP}\"\npublish_at: \"2025-01-02T09:00:00+09:00\"\n---\nBody\n"
+            ),
+        )
+        .unwrap();
+        let schedule = |now: &str| Schedule {
+            now: DateTime::parse_from_rfc3339(now).unwrap().to_utc(),
+            include_scheduled_articles,
+        };
+        // The note is parsed once; only its classification follows the clock.
+        let mut sources = SourceCache::default();
+
+        let files = classify_obsidian_files(
+            vec![file_path.clone()],
+            &mut sources,
+            vault.path(),
+            &test_category_registry(),
+            schedule("2025-01-01T23:59:59Z"),
+            None,
+        );
+        assert_eq!(files.articles.len(), article_count);
+        assert_eq!(files.scheduled.len(), scheduled_count);
+        assert!(files.errors.is_empty());
+        if let Some(scheduled) = files.scheduled.first() {
+            assert_eq!(scheduled.source_key, "tech/scheduled");
+            assert_eq!(scheduled.publish_at.as_str(), "2025-01-02T09:00:00+09:00");
+        }
+
+        let files = classify_obsidian_files(
+            vec![file_path],
+            &mut sources,
+            vault.path(),
+            &test_category_registry(),
+            schedule("2025-01-02T00:00:00Z"),
+            None,
+        );
+        assert!(files.scheduled.is_empty());
+        assert_eq!(files.articles.len() + files.pages.len(), 1);
+    }
+
+    #[test]
+    fn test_classify_obsidian_files_reports_the_line_of_the_rejected_field() {
+        let vault = tempfile::TempDir::new().unwrap();
+        fs::create_dir_all(vault.path().join("tech")).unwrap();
+        let invalid_slug = vault.path().join("tech/invalid-slug.md");
+        fs::write(
+            &invalid_slug,
+            format!(
+                "---\ntitle: Invalid\ncategory: tech\nslug: Not A Slug\nis_completed: true\ncreated: \"{TEST_TIMESTAMP}\"\nupdated: \"{TEST_TIMESTAMP}\"\n---\nBody\n"
+            ),
+        )
+        .unwrap();
+        let invalid_yaml = vault.path().join("tech/invalid-yaml.md");
+        fs::write(
+            &invalid_yaml,
+            "---\ntitle: Invalid\nis_completed: maybe\ncreated: x\nupdated: x\n---\nBody\n",
+        )
+        .unwrap();
+
+        let files = classify_obsidian_files(
+            vec![invalid_slug.clone(), invalid_yaml.clone()],
+            &mut SourceCache::default(),
+            vault.path(),
+            &test_category_registry(),
+            Schedule {
+                now: Utc::now(),
+                include_scheduled_articles: false,
+            },
+            None,
+        );
+
+        let positions: Vec<_> = files
+            .errors
+            .iter()
+            .map(|diagnostic| {
+                (
+                    diagnostic.file.clone().unwrap(),
+                    diagnostic.line,
+                    diagnostic.code,
+                )
+            })
+            .collect();
+        assert_eq!(
+            positions,
+            [
+                (
+                    invalid_slug.display().to_string(),
+                    Some(4),
+                    DiagnosticCode::InvalidFrontmatter
+                ),
+                (
+                    invalid_yaml.display().to_string(),
+                    Some(3),
+                    DiagnosticCode::InvalidFrontmatter
+                ),
+            ]
+        );
+        assert!(files.errors[0].message.contains("`slug`"));
+    }
+
+    #[test]
+    fn test_parse_series_part_reads_key_and_order() {
+        let part = parse_series_part(Some(" rust-async "), Some(2))
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(part.series.as_str(), "rust-async");
+        assert_eq!(part.order, 2);
+        assert!(parse_series_part(None, None).unwrap().is_none());
+    }
+
+    #[rstest]
+    #[case::missing_order(Some("rust-async"), None)]
+    #[case::missing_series(None, Some(1))]
+    #[case::invalid_key(Some("Rust Async"), Some(1))]
+    fn test_parse_series_part_rejects_incomplete_or_invalid_series(
+        #[case] series: Option<&str>,
+        #[case] order: Option<u32>,
+    ) {
+        assert!(parse_series_part(series, order).is_err());
+    }
+
+    #[rstest]
+    #[case::unknown_category("/unknown/old-article")]
+    #[case::nested_path("/tech/old/article")]
+    #[case::invalid_slug("old article")]
+    fn test_parse_alias_rejects_invalid_paths(#[case] alias: &str) {
+        assert!(
+            parse_alias(
+                alias,
+                &Category::new("tech".to_string()).unwrap(),
+                &test_category_registry(),
+            )
+            .is_err()
+        );
+    }
+
+    #[rstest]
+    #[case::duplicate_slug("tech/second", "shared-slug", &[])]
+    #[case::alias_claims_slug("tech/second", "second", &["shared-slug"])]
+    #[case::alias_path_claims_slug("tech/second", "second", &["/tech/shared-slug"])]
+    fn test_ensure_unique_article_paths_rejects_collisions(
+        #[case] source_key: &str,
+        #[case] slug: &str,
+        #[case] aliases: &[&str],
+    ) {
+        let articles = vec![
+            parsed_article_file("tech/first", "shared-slug", &[]),
+            parsed_article_file(source_key, slug, aliases),
+        ];
+
+        let conflicts = ensure_unique_article_paths(&articles);
+        let [conflict] = conflicts.as_slice() else {
+            panic!("expected one conflict, got {}", conflicts.len());
+        };
+        assert!(matches!(
+            &conflict.error,
+            PublishError::SlugCollision { path, first, second }
+                if path == "/tech/shared-slug" && first == "tech/first" && second == source_key
+        ));
+        let fields: Vec<_> = conflict.sources.iter().map(|(_, field)| *field).collect();
+        let expected_field = if aliases.is_empty() {
+            "slug"
+        } else {
+            "aliases"
+        };
+        assert_eq!(fields, ["slug", expected_field]);
+    }
+
+    #[test]
+    fn test_collect_redirects_points_aliases_at_current_paths() {
+        let articles = vec![
+            parsed_article_file("tech/first", "first", &["old-first", "/daily/older-first"]),
+            parsed_article_file("tech/second", "second", &[]),
+        ];
+
+        assert!(ensure_unique_article_paths(&articles).is_empty());
+        let redirects = collect_redirects(&articles);
+
+        assert_eq!(
+            redirects,
+            [
+                RedirectDocument {
+                    from: "/daily/older-first".to_string(),
+                    to: "/tech/first".to_string(),
+                },
+                RedirectDocument {
+                    from: "/tech/old-first".to_string(),
+                    to: "/tech/first".to_string(),
+                },
+            ]
+        );
+    }
+
     #[test]
     fn test_derive_source_key_uses_extensionless_vault_relative_path() {
         let source_key =
@@ -383,24 +1130,105 @@ mod tests {
 
     #[test]
     fn test_parse_category_success() {
-        assert_eq!(parse_category(Some("tech")).unwrap(), Category::Tech);
+        assert_eq!(
+            parse_category(Some("tech"), &test_category_registry())
+                .unwrap()
+                .as_str(),
+            "tech"
+        );
+    }
+
+    #[test]
+    fn test_parse_category_rejects_unregistered_category() {
+        assert!(matches!(
+            parse_category(Some("cooking"), &test_category_registry()),
+            Err(PublishError::Domain(domain::DomainError::InvalidCategory { category }))
+                if category == "cooking"
+        ));
     }
 
     #[test]
     fn test_parse_category_rejects_missing_value() {
         assert!(matches!(
-            parse_category(None),
+            parse_category(None, &test_category_registry()),
             Err(PublishError::Parse(message)) if message.contains("requires a category")
         ));
     }
 
+    #[test]
+    fn test_ensure_page_keys_do_not_shadow_categories() {
+        let registry = test_category_registry();
+
+        assert!(
+            ensure_page_keys_do_not_shadow_categories(
+                &[parsed_page("about"), parsed_page("contact")],
+                &registry
+            )
+            .is_empty()
+        );
+        assert!(matches!(
+            ensure_page_keys_do_not_shadow_categories(&[parsed_page("tech")], &registry).as_slice(),
+            [SiteConflict { error: PublishError::Parse(message), sources }]
+                if message.contains("Page key tech shadows") && sources.len() == 1
+        ));
+    }
+
     #[test]
     fn test_ensure_unique_page_keys_rejects_duplicates() {
         let parsed_pages = vec![parsed_page("about"), parsed_page("about")];
 
         assert!(matches!(
-            ensure_unique_page_keys(&parsed_pages),
-            Err(PublishError::Parse(message)) if message.contains("Duplicate page key")
+            ensure_unique_page_keys(&parsed_pages).as_slice(),
+            [SiteConflict { error: PublishError::Parse(message), sources }]
+                if message.contains("Duplicate page key") && sources.len() == 2
         ));
     }
+
+    #[test]
+    fn test_site_checks_report_every_conflict() {
+        let parsed_pages = vec![
+            parsed_page("about"),
+            parsed_page("contact"),
+            parsed_page("about"),
+            parsed_page("contact"),
+            parsed_page("about"),
+        ];
+        let articles = vec![
+            parsed_article_file("tech/first", "first", &[]),
+            parsed_article_file("tech/second", "first", &[]),
+            parsed_article_file("tech/third", "third", &["second-old"]),
+            parsed_article_file("tech/fourth", "second-old", &[]),
+        ];
+
+        let page_conflicts: Vec<_> = ensure_unique_page_keys(&parsed_pages)
+            .into_iter()
+            .map(|conflict| (conflict.error.to_string(), conflict.sources.len()))
+            .collect();
+        let path_conflicts: Vec<_> = ensure_unique_article_paths(&articles)
+            .into_iter()
+            .map(|conflict| conflict.error.to_string())
+            .collect();
+
+        assert_eq!(
+            page_conflicts,
+            [
+                (
+                    "failed to parse file content: Duplicate page key detected: about".to_string(),
+                    3
+                ),
+                (
+                    "failed to parse file content: Duplicate page key detected: contact"
+                        .to_string(),
+                    2
+                ),
+            ]
+        );
+        assert_eq!(
+            path_conflicts,
+            [
+                "article URL /tech/first is claimed by both tech/first and tech/second",
+                "article URL /tech/second-old is claimed by both tech/third and tech/fourth",
+            ]
+        );
+    }
 }
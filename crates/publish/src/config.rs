@@ -0,0 +1,367 @@
+use crate::diagnostics::Severity;
+use crate::error::{PublishError, Result};
+use domain::{CategoryRegistry, TagSynonyms};
+use serde::Deserialize;
+use std::{collections::BTreeMap, path::Path};
+
+/// Site-wide settings that live outside the Obsidian vault.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SiteConfig {
+    pub categories: CategoryRegistry,
+    #[serde(default)]
+    pub tag_synonyms: TagSynonyms,
+    /// Writes articles whose `publish_at` is still ahead into the release instead of skipping
+    /// them, leaving the server to hide them until that time.
+    #[serde(default)]
+    pub include_scheduled_articles: bool,
+    #[serde(default)]
+    pub broken_links: BrokenLinkPolicy,
+    /// Rejects frontmatter that publishing would otherwise accept silently, such as misspelled
+    /// keys, `updated` before `created`, or timestamp offsets that differ across the vault.
+    #[serde(default)]
+    pub strict_frontmatter: bool,
+    #[serde(default)]
+    pub raw_html: RawHtmlConfig,
+}
+
+/// Raw HTML tags kept from Markdown, with the attributes each one may carry.
+///
+/// Either list replaces its default when given. Whatever is allowed, `href`, `src` and `cite`
+/// are still checked like Markdown links.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RawHtmlConfig {
+    /// Allowed tags and the attributes accepted on each besides `attributes`.
+    pub tags: BTreeMap<String, Vec<String>>,
+    /// Attributes accepted on every allowed tag.
+    pub attributes: Vec<String>,
+}
+
+const DEFAULT_RAW_HTML_TAGS: &[(&str, &[&str])] = &[
+    ("a", &["href"]),
+    ("abbr", &[]),
+    ("b", &[]),
+    ("bdi", &[]),
+    ("blockquote", &["cite"]),
+    ("br", &[]),
+    ("caption", &[]),
+    ("cite", &[]),
+    ("code", &[]),
+    ("dd", &[]),
+    ("del", &["cite", "datetime"]),
+    ("details", &["open"]),
+    ("dfn", &[]),
+    ("div", &[]),
+    ("dl", &[]),
+    ("dt", &[]),
+    ("em", &[]),
+    ("figcaption", &[]),
+    ("figure", &[]),
+    ("hr", &[]),
+    ("i", &[]),
+    ("img", &["src", "alt", "width", "height"]),
+    ("ins", &["cite", "datetime"]),
+    ("kbd", &[]),
+    ("li", &["value"]),
+    ("mark", &[]),
+    ("ol", &["start", "reversed"]),
+    ("pre", &[]),
+    ("q", &["cite"]),
+    ("rp", &[]),
+    ("rt", &[]),
+    ("ruby", &[]),
+    ("s", &[]),
+    ("samp", &[]),
+    ("small", &[]),
+    ("span", &[]),
+    ("strong", &[]),
+    ("sub", &[]),
+    ("summary", &[]),
+    ("sup", &[]),
+    ("table", &[]),
+    ("tbody", &[]),
+    ("td", &["colspan", "rowspan"]),
+    ("tfoot", &[]),
+    ("th", &["colspan", "rowspan", "scope"]),
+    ("thead", &[]),
+    ("time", &["datetime"]),
+    ("tr", &[]),
+    ("u", &[]),
+    ("ul", &[]),
+    ("var", &[]),
+    ("wbr", &[]),
+];
+
+const DEFAULT_RAW_HTML_ATTRIBUTES: &[&str] = &["title", "lang", "dir"];
+
+/// Tags that run, style, embed or submit content, or change how the page resolves URLs.
+const FORBIDDEN_RAW_HTML_TAGS: &[&str] = &[
+    "base", "embed", "form", "iframe", "link", "meta", "object", "script", "style",
+];
+
+/// Attributes that apply styles or carry a whole document, beside the `on*` event handlers.
+const FORBIDDEN_RAW_HTML_ATTRIBUTES: &[&str] = &["srcdoc", "style"];
+
+impl Default for RawHtmlConfig {
+    fn default() -> Self {
+        let strings = |names: &[&str]| names.iter().map(ToString::to_string).collect();
+        Self {
+            tags: DEFAULT_RAW_HTML_TAGS
+                .iter()
+                .map(|(tag, attributes)| (tag.to_string(), strings(attributes)))
+                .collect(),
+            attributes: strings(DEFAULT_RAW_HTML_ATTRIBUTES),
+        }
+    }
+}
+
+impl RawHtmlConfig {
+    /// Looks up an allowed tag regardless of case, returning its configured name and attributes.
+    pub(crate) fn tag(&self, name: &str) -> Option<(&str, &[String])> {
+        self.tags
+            .get_key_value(name.to_ascii_lowercase().as_str())
+            .map(|(name, attributes)| (name.as_str(), attributes.as_slice()))
+    }
+
+    /// Whether `attribute`, already lowercased, may appear on a tag allowing `tag_attributes`.
+    pub(crate) fn allows_attribute(&self, tag_attributes: &[String], attribute: &str) -> bool {
+        tag_attributes
+            .iter()
+            .chain(&self.attributes)
+            .any(|allowed| allowed == attribute)
+    }
+
+    fn validate(&self) -> Result<()> {
+        let attributes = || self.tags.values().flatten().chain(&self.attributes);
+        if let Some(name) = self
+            .tags
+            .keys()
+            .chain(attributes())
+            .find(|name| !is_html_name(name))
+        {
+            return Err(PublishError::InvalidConfig(format!(
+                "raw_html name {name:?} must be lowercase ASCII letters, digits or hyphens"
+            )));
+        }
+        if let Some(tag) = FORBIDDEN_RAW_HTML_TAGS
+            .iter()
+            .find(|tag| self.tags.contains_key(**tag))
+        {
+            return Err(PublishError::InvalidConfig(format!(
+                "raw_html cannot allow <{tag}>"
+            )));
+        }
+        if let Some(handler) = attributes().find(|name| is_event_handler(name)) {
+            return Err(PublishError::InvalidConfig(format!(
+                "raw_html cannot allow the event handler attribute {handler}"
+            )));
+        }
+        if let Some(attribute) =
+            attributes().find(|name| FORBIDDEN_RAW_HTML_ATTRIBUTES.contains(&name.as_str()))
+        {
+            return Err(PublishError::InvalidConfig(format!(
+                "raw_html cannot allow the {attribute} attribute"
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// How WikiLinks, embeds, and heading fragments that resolve to nothing are handled.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BrokenLinkPolicy {
+    /// Publish them as `/{target}` links without reporting them.
+    Ignore,
+    /// Publish them and report each one with its source file.
+    #[default]
+    Warn,
+    /// Fail the run before any artifact is written.
+    Error,
+}
+
+impl BrokenLinkPolicy {
+    /// Severity broken links are reported with, or `None` when they are ignored.
+    pub(crate) fn severity(self) -> Option<Severity> {
+        match self {
+            Self::Ignore => None,
+            Self::Warn => Some(Severity::Warning),
+            Self::Error => Some(Severity::Error),
+        }
+    }
+}
+
+fn is_html_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+}
+
+// `open` on `<details>` is the only standard attribute that starts like an event handler.
+fn is_event_handler(attribute: &str) -> bool {
+    attribute.starts_with("on") && attribute != "open"
+}
+
+impl SiteConfig {
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|error| PublishError::InvalidConfig(format!("{}: {error}", path.display())))?;
+        Self::from_yaml(&content)
+            .map_err(|error| PublishError::InvalidConfig(format!("{}: {error}", path.display())))
+    }
+
+    pub fn from_yaml(content: &str) -> Result<Self> {
+        let config: Self = serde_yaml::from_str(content)?;
+        if config.categories.is_empty() {
+            return Err(PublishError::InvalidConfig(
+                "at least one category is required".to_string(),
+            ));
+        }
+        config.raw_html.validate()?;
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+pub(crate) fn test_category_registry() -> CategoryRegistry {
+    SiteConfig::from_yaml(include_str!("../site.yaml"))
+        .unwrap()
+        .categories
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rstest::rstest;
+
+    #[test]
+    fn test_site_config_declares_categories_in_sort_order() {
+        let registry = test_category_registry();
+
+        let keys: Vec<_> = registry
+            .iter()
+            .map(|definition| definition.key.as_str())
+            .collect();
+        assert_eq!(keys, ["tech", "daily", "statistics", "physics"]);
+        assert_eq!(
+            registry.display_name(&registry.resolve("tech").unwrap()),
+            Ok("技術")
+        );
+    }
+
+    #[test]
+    fn test_site_config_reads_tag_synonyms() {
+        let config = SiteConfig::from_yaml(
+            "categories:\n  - key: tech\n    display_name: 技術\ntag_synonyms:\n  kubernetes: [k8s, Kube]",
+        )
+        .unwrap();
+
+        let tags = config
+            .tag_synonyms
+            .canonical_tags(["K8S".to_string()])
+            .unwrap();
+        assert_eq!(tags[0].as_str(), "kubernetes");
+    }
+
+    #[test]
+    fn test_site_config_reads_broken_link_policy() {
+        let categories = "categories:\n  - key: tech\n    display_name: 技術\n";
+
+        let config = SiteConfig::from_yaml(categories).unwrap();
+        assert_eq!(config.broken_links, BrokenLinkPolicy::Warn);
+
+        let config = SiteConfig::from_yaml(&format!("{categories}broken_links: error")).unwrap();
+        assert_eq!(config.broken_links, BrokenLinkPolicy::Error);
+
+        assert!(SiteConfig::from_yaml(&format!("{categories}broken_links: fail")).is_err());
+    }
+
+    #[test]
+    fn test_site_config_strict_frontmatter_is_opt_in() {
+        let categories = "categories:\n  - key: tech\n    display_name: 技術\n";
+
+        assert!(
+            !SiteConfig::from_yaml(categories)
+                .unwrap()
+                .strict_frontmatter
+        );
+        assert!(
+            SiteConfig::from_yaml(&format!("{categories}strict_frontmatter: true"))
+                .unwrap()
+                .strict_frontmatter
+        );
+    }
+
+    #[test]
+    fn test_site_config_reads_raw_html_allowlist() {
+        let categories = "categories:\n  - key: tech\n    display_name: 技術\n";
+        assert_eq!(
+            SiteConfig::from_yaml(categories).unwrap().raw_html,
+            RawHtmlConfig::default()
+        );
+
+        let config = SiteConfig::from_yaml(&format!(
+            "{categories}raw_html:\n  tags:\n    a: [href]\n    kbd: []\n    section: [id]"
+        ))
+        .unwrap();
+
+        let (name, attributes) = config.raw_html.tag("SECTION").unwrap();
+        assert_eq!(name, "section");
+        assert!(config.raw_html.allows_attribute(attributes, "id"));
+        assert!(config.raw_html.allows_attribute(attributes, "title"));
+        assert!(config.raw_html.tag("kbd").is_some());
+        assert!(config.raw_html.tag("img").is_none());
+    }
+
+    #[test]
+    fn test_site_config_rejects_invalid_categories() {
+        for content in [
+            "categories: []",
+            "categories:\n  - key: Tech Notes\n    display_name: 技術",
+            "categories:\n  - key: assets\n    display_name: Assets",
+            "categories:\n  - key: tech\n    display_name: 技術\n  - key: tech\n    display_name: Tech",
+            "categories:\n  - key: tech\n    display_name: 技術\ntheme: dark",
+            "categories:\n  - key: tech\n    display_name: 技術\ntag_synonyms:\n  rust: [rs]\n  ruby: [rs]",
+            "categories:\n  - key: tech\n    display_name: 技術\nraw_html:\n  tags:\n    Section: []",
+            "categories:\n  - key: tech\n    display_name: 技術\nraw_html:\n  elements: [section]",
+        ] {
+            assert!(SiteConfig::from_yaml(content).is_err(), "{content}");
+        }
+    }
+
+    #[rstest]
+    #[case::script("tags:\n    script: []")]
+    #[case::style("tags:\n    style: []")]
+    #[case::iframe("tags:\n    iframe: [src]")]
+    #[case::object("tags:\n    object: [data]")]
+    #[case::embed("tags:\n    embed: [src]")]
+    #[case::form("tags:\n    form: [action]")]
+    #[case::base("tags:\n    base: [href]")]
+    #[case::meta("tags:\n    meta: [content]")]
+    #[case::link("tags:\n    link: [href]")]
+    #[case::event_handler("attributes: [onclick]")]
+    #[case::style_attribute("attributes: [style]")]
+    #[case::style_on_a_tag("tags:\n    span: [style]")]
+    #[case::srcdoc("tags:\n    div: [srcdoc]")]
+    fn test_site_config_rejects_raw_html_that_runs_or_styles_content(#[case] raw_html: &str) {
+        let content =
+            format!("categories:\n  - key: tech\n    display_name: 技術\nraw_html:\n  {raw_html}");
+
+        assert!(matches!(
+            SiteConfig::from_yaml(&content),
+            Err(PublishError::InvalidConfig(_))
+        ));
+    }
+
+    #[test]
+    fn test_site_config_load_reports_path() {
+        let result = SiteConfig::load(Path::new("/nonexistent/site.yaml"));
+
+        assert!(matches!(
+            result,
+            Err(PublishError::InvalidConfig(message)) if message.contains("/nonexistent/site.yaml")
+        ));
+    }
+}
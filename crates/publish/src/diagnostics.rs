@@ -0,0 +1,208 @@
+use serde::Serialize;
+use std::{fmt, path::Path};
+
+/// How a diagnostic affects publishing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// Publishing succeeds, but the page probably does not read as the note intended.
+    Warning,
+    /// Publishing fails until the note is fixed.
+    Error,
+}
+
+impl Severity {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Warning => "warning",
+            Self::Error => "error",
+        }
+    }
+}
+
+/// Stable identifier of a diagnostic, for editors and hooks that filter by kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum DiagnosticCode {
+    /// The frontmatter is not valid YAML or one of its fields is rejected.
+    InvalidFrontmatter,
+    /// Strict mode: a frontmatter key that nothing reads, usually a typo.
+    UnknownField,
+    /// Strict mode: `updated` is earlier than `created`.
+    TimestampOrder,
+    /// Strict mode: an empty tag or the same tag listed twice.
+    InvalidTags,
+    /// Strict mode: `page` or `category` on a content kind that does not use it.
+    MisplacedField,
+    /// Strict mode: a timestamp offset that differs from the rest of the vault.
+    InconsistentOffset,
+    /// Notes claim the same URL, page key, category landing, or home.
+    DuplicateRoute,
+    /// Articles are filed under a category without a landing note.
+    MissingCategoryLanding,
+    /// Notes embed each other in a cycle.
+    EmbedCycle,
+    /// A WikiLink or embed names nothing that is published.
+    UnresolvedLink,
+    /// TeX math that cannot be converted to MathML.
+    InvalidMath,
+    /// Raw HTML escaped, stripped or rewritten by the `raw_html` allowlist.
+    RejectedRawHtml,
+    /// Content rejected while rendering or assembling the site.
+    InvalidContent,
+}
+
+impl DiagnosticCode {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::InvalidFrontmatter => "invalid-frontmatter",
+            Self::UnknownField => "unknown-field",
+            Self::TimestampOrder => "timestamp-order",
+            Self::InvalidTags => "invalid-tags",
+            Self::MisplacedField => "misplaced-field",
+            Self::InconsistentOffset => "inconsistent-offset",
+            Self::DuplicateRoute => "duplicate-route",
+            Self::MissingCategoryLanding => "missing-category-landing",
+            Self::EmbedCycle => "embed-cycle",
+            Self::UnresolvedLink => "unresolved-link",
+            Self::InvalidMath => "invalid-math",
+            Self::RejectedRawHtml => "rejected-raw-html",
+            Self::InvalidContent => "invalid-content",
+        }
+    }
+}
+
+/// A problem found in the vault, positioned in the note that causes it when one does.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Diagnostic {
+    /// Markdown file path as scanned from the vault; absent for problems no single note owns,
+    /// such as a site that fails to assemble.
+    pub file: Option<String>,
+    /// One-based line.
+    pub line: Option<usize>,
+    /// One-based column, counted in characters.
+    pub column: Option<usize>,
+    pub severity: Severity,
+    pub code: DiagnosticCode,
+    pub message: String,
+}
+
+impl Diagnostic {
+    pub(crate) fn error(code: DiagnosticCode, message: impl Into<String>) -> Self {
+        Self::new(Severity::Error, code, message.into())
+    }
+
+    pub(crate) fn warning(code: DiagnosticCode, message: impl Into<String>) -> Self {
+        Self::new(Severity::Warning, code, message.into())
+    }
+
+    pub(crate) fn new(severity: Severity, code: DiagnosticCode, message: String) -> Self {
+        Self {
+            file: None,
+            line: None,
+            column: None,
+            severity,
+            code,
+            message,
+        }
+    }
+
+    pub(crate) fn in_file(mut self, path: &Path) -> Self {
+        self.file = Some(path.to_string_lossy().into_owned());
+        self
+    }
+
+    pub(crate) fn at(mut self, line: usize, column: usize) -> Self {
+        self.line = Some(line);
+        self.column = Some(column);
+        self
+    }
+
+    /// Positions the diagnostic `offset` bytes into a note body that starts at `body_line`.
+    pub(crate) fn at_offset(self, body: &str, body_line: usize, offset: usize) -> Self {
+        let before = &body[..offset];
+        let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+        self.at(
+            body_line + before.matches('\n').count(),
+            before[line_start..].chars().count() + 1,
+        )
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
+
+/// Formats as `file:line:column: severity[code]: message`, the form compilers and editors use.
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{file}:")?;
+            if let Some(line) = self.line {
+                write!(f, "{line}:")?;
+            }
+            if let Some(column) = self.column {
+                write!(f, "{column}:")?;
+            }
+            write!(f, " ")?;
+        }
+        write!(
+            f,
+            "{}[{}]: {}",
+            self.severity.as_str(),
+            self.code.as_str(),
+            self.message
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_at_offset_counts_lines_from_the_body_and_columns_in_characters() {
+        let body = "# 見出し\n本文の[[missing]]\n";
+        let offset = body.find("[[").unwrap();
+
+        let diagnostic = Diagnostic::warning(DiagnosticCode::UnresolvedLink, "not found")
+            .at_offset(body, 8, offset);
+
+        assert_eq!((diagnostic.line, diagnostic.column), (Some(9), Some(4)));
+    }
+
+    #[test]
+    fn test_display_omits_missing_positions() {
+        let diagnostic = Diagnostic::error(DiagnosticCode::InvalidFrontmatter, "bad slug")
+            .in_file(Path::new("vault/tech/note.md"));
+        assert_eq!(
+            diagnostic.clone().at(3, 1).to_string(),
+            "vault/tech/note.md:3:1: error[invalid-frontmatter]: bad slug"
+        );
+        assert_eq!(
+            diagnostic.to_string(),
+            "vault/tech/note.md: error[invalid-frontmatter]: bad slug"
+        );
+        assert_eq!(
+            Diagnostic::error(DiagnosticCode::EmbedCycle, "a -> b -> a").to_string(),
+            "error[embed-cycle]: a -> b -> a"
+        );
+    }
+
+    #[test]
+    fn test_serializes_codes_and_severities_in_kebab_case() {
+        let diagnostic = Diagnostic::warning(DiagnosticCode::UnresolvedLink, "not found").at(2, 5);
+
+        assert_eq!(
+            serde_json::to_value(&diagnostic).unwrap(),
+            serde_json::json!({
+                "file": null,
+                "line": 2,
+                "column": 5,
+                "severity": "warning",
+                "code": "unresolved-link",
+                "message": "not found",
+            })
+        );
+    }
+}
@@ -16,6 +16,9 @@ pub enum PublishError {
     #[error("bookmark network request failed: {0}")]
     Network(#[from] reqwest::Error),
 
+    #[error("failed to watch the vault: {0}")]
+    Watch(#[from] notify::Error),
+
     #[error("blocking task failed: {0}")]
     Join(#[from] tokio::task::JoinError),
 
@@ -25,9 +28,19 @@ pub enum PublishError {
     #[error("invalid file path: {0}")]
     InvalidPath(String),
 
+    #[error("invalid site config: {0}")]
+    InvalidConfig(String),
+
     #[error("invalid Obsidian source directory: {0}")]
     InvalidSourceDirectory(String),
 
+    #[error("invalid frontmatter field `{field}`: {source}")]
+    InvalidField {
+        field: &'static str,
+        #[source]
+        source: Box<PublishError>,
+    },
+
     #[error("failed to parse file content: {0}")]
     Parse(String),
 
@@ -40,6 +53,56 @@ pub enum PublishError {
     #[error("publish rejected {count} invalid content file(s)")]
     ContentErrors { count: usize },
 
+    #[error("found {count} broken internal link(s)")]
+    BrokenLinks { count: usize },
+
+    #[error("recursive note embed: {cycle}")]
+    EmbedCycle { cycle: String },
+
     #[error("missing category landing: {category}")]
     MissingCategoryLanding { category: domain::Category },
+
+    #[error("article URL {path} is claimed by both {first} and {second}")]
+    SlugCollision {
+        path: String,
+        first: String,
+        second: String,
+    },
+}
+
+/// Broad cause of a failed publish, which the CLI reports through its exit code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// The vault content is invalid; fixing the notes makes the run succeed.
+    Content,
+    /// `site.yaml` is missing or invalid.
+    Config,
+    /// Reading the vault, writing artifacts, or a background task failed.
+    Io,
+}
+
+impl PublishError {
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Self::InvalidField { source, .. } => source.kind(),
+            Self::InvalidConfig(_) => ErrorKind::Config,
+            Self::Io(_)
+            | Self::Json(_)
+            | Self::Network(_)
+            | Self::Watch(_)
+            | Self::Join(_)
+            | Self::StripPrefix(_)
+            | Self::InvalidSourceDirectory(_) => ErrorKind::Io,
+            Self::Yaml(_)
+            | Self::InvalidPath(_)
+            | Self::Parse(_)
+            | Self::ArtifactValidation(_)
+            | Self::Domain(_)
+            | Self::ContentErrors { .. }
+            | Self::BrokenLinks { .. }
+            | Self::EmbedCycle { .. }
+            | Self::MissingCategoryLanding { .. }
+            | Self::SlugCollision { .. } => ErrorKind::Content,
+        }
+    }
 }
@@ -1,14 +1,22 @@
 #![warn(unreachable_pub)]
 
 mod artifacts;
+mod assets;
 mod classify;
+mod config;
+mod diagnostics;
 mod error;
 mod links;
 mod pipeline;
 mod render;
 mod slug;
 mod vault;
+mod watch;
 
-pub use error::{PublishError, Result};
-pub use pipeline::{publish, publish_with_bookmark_enricher};
-pub use render::BookmarkEnricher;
+pub use config::{BrokenLinkPolicy, RawHtmlConfig, SiteConfig};
+pub use diagnostics::{Diagnostic, DiagnosticCode, Severity};
+pub use error::{ErrorKind, PublishError, Result};
+pub use pipeline::{
+    DEFAULT_CONCURRENCY, PublishOptions, check, publish, publish_with_bookmark_enricher,
+};
+pub use render::{BookmarkEnricher, passthrough_bookmark_enricher};
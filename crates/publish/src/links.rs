@@ -1,111 +1,519 @@
+use crate::assets::Attachment;
 use crate::classify::ClassifiedFiles;
-use pulldown_cmark::{CowStr, Event, LinkType, Tag};
-use std::collections::HashMap;
-
-const ROUTED_PAGE_KEYS: &[&str] = &["about"];
+use crate::render::{collect_anchors, heading_id, markdown_options, strip_comments};
+use domain::{
+    ArticleRef, LinkGraphDocument, LinkGraphEdgeDocument, LinkGraphNodeDocument, build_asset_path,
+};
+use pulldown_cmark::{CowStr, Event, LinkType, Parser, Tag, TagEnd};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::hash::{Hash, Hasher};
+use std::sync::Mutex;
 
 /// Published content hrefs indexed by extensionless source keys.
 #[derive(Default)]
 pub(crate) struct Index {
     routes: HashMap<String, String>,
+    articles: HashMap<String, ArticleRef>,
+    notes: HashMap<String, NoteSource>,
+    attachments: HashMap<String, Attachment>,
+    referenced_attachments: Mutex<BTreeSet<String>>,
+    /// Other published notes each rendered body links to, in first-link order.
+    note_links: Mutex<HashMap<String, Vec<String>>>,
 }
 
 impl Index {
     pub(crate) fn from_classified_files(files: &ClassifiedFiles) -> Self {
-        let article_routes = files.articles.iter().map(|article| {
+        let article_notes = files.articles.iter().map(|article| {
             (
-                article.source_key.clone(),
+                &article.source_key,
                 format!("/{}/{}", article.category.as_str(), article.slug),
+                &article.front_matter.title,
+                &article.markdown_body,
             )
         });
-        let page_routes = files
-            .pages
-            .iter()
-            .filter(|page| ROUTED_PAGE_KEYS.contains(&page.page.as_str()))
-            .map(|page| (page.source_key.clone(), format!("/{}", page.page.as_str())));
-        let home_routes = files
-            .home
-            .iter()
-            .map(|home| (home.source_key.clone(), "/".to_string()));
-        let category_routes = files.categories.iter().map(|category| {
+        let page_notes = files.pages.iter().map(|page| {
             (
-                category.source_key.clone(),
+                &page.source_key,
+                format!("/{}", page.page.as_str()),
+                &page.front_matter.title,
+                &page.markdown_body,
+            )
+        });
+        let home_notes = files.home.iter().map(|home| {
+            (
+                &home.source_key,
+                "/".to_string(),
+                &home.front_matter.title,
+                &home.markdown_body,
+            )
+        });
+        let category_notes = files.categories.iter().map(|category| {
+            (
+                &category.source_key,
                 format!("/{}", category.category.as_str()),
+                &category.front_matter.title,
+                &category.markdown_body,
             )
         });
 
-        let routes = article_routes
-            .chain(page_routes)
-            .chain(home_routes)
-            .chain(category_routes)
+        let mut index = Self::default();
+        for (source_key, href, title, markdown_body) in article_notes
+            .chain(page_notes)
+            .chain(home_notes)
+            .chain(category_notes)
+        {
+            // Comments are private, so embeds and fragment links never see them.
+            let markdown_body = strip_comments(markdown_body).into_owned();
+            index.routes.insert(source_key.clone(), href);
+            index.notes.insert(
+                source_key.clone(),
+                NoteSource {
+                    title: title.clone(),
+                    anchors: collect_anchors(&markdown_body),
+                    markdown_body,
+                },
+            );
+        }
+        index.articles = files
+            .articles
+            .iter()
+            .map(|article| {
+                let article_ref = ArticleRef {
+                    category: article.category.clone(),
+                    slug: article.slug.clone(),
+                };
+                (article.source_key.clone(), article_ref)
+            })
             .collect();
+        index
+    }
 
-        Self { routes }
+    /// Adds vault attachments that links and embeds may reference by file name.
+    pub(crate) fn with_attachments(mut self, attachments: Vec<Attachment>) -> Self {
+        self.attachments = attachments
+            .into_iter()
+            .map(|attachment| (attachment.source_key.clone(), attachment))
+            .collect();
+        self
     }
 
     /// Resolve an exact vault-relative key or an Obsidian-style filename reference.
     fn resolve(&self, target: &str) -> Option<&str> {
-        self.routes.get(target).map(String::as_str).or_else(|| {
-            let suffix = format!("/{target}");
-            self.routes.iter().find_map(|(source_key, href)| {
-                source_key.ends_with(&suffix).then_some(href.as_str())
+        let source_key = self.resolve_source_key(target)?;
+        self.routes.get(source_key).map(String::as_str)
+    }
+
+    /// Resolve a note target with a `#Heading` or `#^block-id` fragment the note defines.
+    fn resolve_fragment(&self, target: &str, fragment: &str) -> Option<String> {
+        let source_key = self.resolve_source_key(target)?;
+        let anchor = fragment_anchor(fragment);
+        let note = self.notes.get(source_key)?;
+        let href = self.routes.get(source_key)?;
+
+        note.anchors
+            .contains(&anchor)
+            .then(|| format!("{href}#{anchor}"))
+    }
+
+    fn resolve_source_key(&self, target: &str) -> Option<&str> {
+        if let Some((source_key, _)) = self.routes.get_key_value(target) {
+            return Some(source_key);
+        }
+        let suffix = format!("/{target}");
+        self.routes
+            .keys()
+            .filter(|source_key| source_key.ends_with(&suffix))
+            .min_by_key(|source_key| source_key.as_str())
+            .map(String::as_str)
+    }
+
+    /// Other published articles a rendered note body links to, deduplicated in first-link order.
+    pub(crate) fn linked_articles(&self, source_key: &str) -> Vec<ArticleRef> {
+        let note_links = self
+            .note_links
+            .lock()
+            .expect("note link lock should not be poisoned");
+        note_links
+            .get(source_key)
+            .into_iter()
+            .flatten()
+            .filter_map(|linked_key| self.articles.get(linked_key).cloned())
+            .collect()
+    }
+
+    /// Link graph of every published note, exported by route from the links seen while rendering.
+    pub(crate) fn link_graph(&self) -> LinkGraphDocument {
+        let mut nodes: Vec<_> = self
+            .routes
+            .iter()
+            .filter_map(|(source_key, path)| {
+                Some(LinkGraphNodeDocument {
+                    path: path.clone(),
+                    title: self.notes.get(source_key)?.title.clone(),
+                })
             })
+            .collect();
+        nodes.sort_by(|a, b| a.path.cmp(&b.path));
+
+        let note_links = self
+            .note_links
+            .lock()
+            .expect("note link lock should not be poisoned");
+        let mut edges: Vec<_> = note_links
+            .iter()
+            .flat_map(|(source_key, linked_keys)| {
+                let source = &self.routes[source_key];
+                linked_keys.iter().map(|linked_key| LinkGraphEdgeDocument {
+                    source: source.clone(),
+                    target: self.routes[linked_key].clone(),
+                })
+            })
+            .collect();
+        edges.sort_by(|a, b| (&a.source, &a.target).cmp(&(&b.source, &b.target)));
+
+        LinkGraphDocument { nodes, edges }
+    }
+
+    /// Remembers that the body of `source_key` links to a WikiLink `target`, if it names
+    /// another published note.
+    fn record_note_link(&self, source_key: &str, target: &str) {
+        let note_target = target
+            .split_once('#')
+            .map_or(target, |(note, _)| note.trim());
+        let Some(linked_key) = self.resolve_source_key(note_target) else {
+            return;
+        };
+        if linked_key == source_key || !self.routes.contains_key(source_key) {
+            return;
+        }
+        let mut note_links = self
+            .note_links
+            .lock()
+            .expect("note link lock should not be poisoned");
+        let linked_keys = note_links.entry(source_key.to_string()).or_default();
+        if !linked_keys.iter().any(|key| key == linked_key) {
+            linked_keys.push(linked_key.to_string());
+        }
+    }
+
+    /// Resolve a note embed target to the published note it transcludes.
+    pub(crate) fn embedded_note(&self, target: &str) -> Option<EmbeddedNote<'_>> {
+        self.note(self.resolve_source_key(target)?)
+    }
+
+    /// All published notes that embeds may transclude.
+    pub(crate) fn embedded_notes(&self) -> impl Iterator<Item = EmbeddedNote<'_>> {
+        self.notes
+            .keys()
+            .filter_map(|source_key| self.note(source_key))
+    }
+
+    fn note(&self, source_key: &str) -> Option<EmbeddedNote<'_>> {
+        let (source_key, note) = self.notes.get_key_value(source_key)?;
+        Some(EmbeddedNote {
+            source_key,
+            href: self.routes.get(source_key)?,
+            title: &note.title,
+            markdown_body: &note.markdown_body,
         })
     }
+
+    /// Whether a WikiLink or embed target names a published note, heading, block, or attachment.
+    ///
+    /// A bare `#Heading` or `#^block-id` fragment names an anchor of the note at `source_key`.
+    pub(crate) fn resolves_wikilink(&self, target: &str, source_key: &str) -> bool {
+        match target.split_once('#') {
+            Some((note_target, fragment)) if note_target.trim().is_empty() => {
+                self.resolve_fragment(source_key, fragment).is_some()
+            }
+            Some((note_target, fragment)) => {
+                let note_target = note_target.trim();
+                self.resolve_fragment(note_target, fragment).is_some()
+                    || self.find_attachment(note_target).is_some()
+            }
+            None => self.resolve(target).is_some() || self.find_attachment(target).is_some(),
+        }
+    }
+
+    /// Resolve an attachment reference and remember it so only referenced files are published.
+    fn resolve_attachment(&self, target: &str) -> Option<&Attachment> {
+        let attachment = self.find_attachment(target)?;
+
+        self.referenced_attachments
+            .lock()
+            .expect("attachment reference lock should not be poisoned")
+            .insert(attachment.source_key.clone());
+        Some(attachment)
+    }
+
+    fn find_attachment(&self, target: &str) -> Option<&Attachment> {
+        self.attachments.get(target).or_else(|| {
+            let suffix = format!("/{target}");
+            self.attachments
+                .iter()
+                .filter(|(source_key, _)| source_key.ends_with(&suffix))
+                .min_by_key(|(source_key, _)| source_key.as_str())
+                .map(|(_, attachment)| attachment)
+        })
+    }
+
+    /// Feeds everything rendering a body reads from other notes and attachments into `state`.
+    ///
+    /// Covers the resolution of every link and embed and, through embeds, the embedded bodies,
+    /// so an unchanged hash means a cached render of the body is still current.
+    pub(crate) fn hash_dependencies(&self, markdown: &str, state: &mut impl Hasher) {
+        self.hash_body_dependencies(markdown, state, &mut HashSet::new());
+    }
+
+    fn hash_body_dependencies<'a>(
+        &'a self,
+        markdown: &str,
+        state: &mut impl Hasher,
+        embedded: &mut HashSet<&'a str>,
+    ) {
+        let markdown = strip_comments(markdown);
+        for event in Parser::new_ext(&markdown, markdown_options()) {
+            let (target, is_note_embed) = match &event {
+                Event::Start(Tag::Link {
+                    link_type: LinkType::WikiLink { has_pothole },
+                    dest_url,
+                    ..
+                }) => (wikilink_target(dest_url, *has_pothole), false),
+                Event::Start(Tag::Image {
+                    link_type: LinkType::WikiLink { has_pothole },
+                    dest_url,
+                    ..
+                }) => (wikilink_target(dest_url, *has_pothole), true),
+                Event::Start(Tag::Image { dest_url, .. }) => {
+                    (dest_url.trim().trim_start_matches("./"), false)
+                }
+                _ => continue,
+            };
+            let (note_target, fragment) = match target.split_once('#') {
+                Some((note_target, fragment)) => (note_target.trim(), Some(fragment)),
+                None => (target, None),
+            };
+
+            target.hash(state);
+            match fragment {
+                Some(fragment) => self.resolve_fragment(note_target, fragment).hash(state),
+                None => self.resolve(target).hash(state),
+            }
+            self.find_attachment(note_target)
+                .map(|attachment| attachment.asset_name.as_str())
+                .hash(state);
+
+            if is_note_embed && let Some(note) = self.embedded_note(note_target) {
+                (note.title, note.markdown_body).hash(state);
+                // Embed cycles are rejected before rendering; this only keeps the walk finite.
+                if embedded.insert(note.source_key) {
+                    self.hash_body_dependencies(note.markdown_body, state, embedded);
+                }
+            }
+        }
+    }
+
+    /// Records the notes and attachments a body references, as rendering it would, for a reused
+    /// render.
+    pub(crate) fn record_references(&self, source_key: &str, markdown: &str) {
+        let markdown = strip_comments(markdown);
+        resolve_wikilinks(
+            Parser::new_ext(&markdown, markdown_options()),
+            self,
+            Some(source_key),
+        )
+        .for_each(drop);
+    }
+
+    /// Attachments referenced by rendered content, deduplicated by asset name.
+    pub(crate) fn referenced_attachments(&self) -> Vec<Attachment> {
+        let referenced = self
+            .referenced_attachments
+            .lock()
+            .expect("attachment reference lock should not be poisoned");
+        referenced
+            .iter()
+            .filter_map(|source_key| self.attachments.get(source_key))
+            .map(|attachment| (attachment.asset_name.clone(), attachment.clone()))
+            .collect::<BTreeMap<_, _>>()
+            .into_values()
+            .collect()
+    }
 }
 
-/// Resolve Obsidian WikiLink events to published URLs.
+/// Markdown source of a published note, kept for build-time transclusion.
+struct NoteSource {
+    title: String,
+    markdown_body: String,
+    anchors: HashSet<String>,
+}
+
+/// Published note resolved from a `![[note]]` embed.
+pub(crate) struct EmbeddedNote<'a> {
+    pub(crate) source_key: &'a str,
+    pub(crate) href: &'a str,
+    pub(crate) title: &'a str,
+    pub(crate) markdown_body: &'a str,
+}
+
+/// Resolve Obsidian WikiLink events and local attachment embeds to published URLs.
+///
+/// Links to other notes are recorded as links of `source_key`, the note whose own body the
+/// events come from; transcluded bodies pass `None`.
 pub(crate) fn resolve_wikilinks<'a>(
     events: impl Iterator<Item = Event<'a>> + 'a,
     index: &'a Index,
+    source_key: Option<&'a str>,
 ) -> impl Iterator<Item = Event<'a>> + 'a {
+    // Embedded PDFs and audio cannot render as images, so they are published as links instead.
+    let mut embeds_as_links = Vec::new();
+
     events.map(move |event| match event {
         Event::Start(Tag::Link {
             link_type: link_type @ LinkType::WikiLink { has_pothole },
             dest_url,
             title,
             id,
-        }) => Event::Start(Tag::Link {
-            link_type,
-            dest_url: resolve_wikilink_destination(&dest_url, has_pothole, index),
-            title,
-            id,
-        }),
+        }) => {
+            if let Some(source_key) = source_key {
+                index.record_note_link(source_key, wikilink_target(&dest_url, has_pothole));
+            }
+            Event::Start(Tag::Link {
+                link_type,
+                dest_url: resolve_wikilink_destination(&dest_url, has_pothole, index).into_href(),
+                title,
+                id,
+            })
+        }
         Event::Start(Tag::Image {
-            link_type: link_type @ LinkType::WikiLink { has_pothole },
-            dest_url,
-            title,
-            id,
-        }) => Event::Start(Tag::Image {
             link_type,
-            dest_url: resolve_wikilink_destination(&dest_url, has_pothole, index),
+            dest_url,
             title,
             id,
-        }),
+        }) => {
+            let destination = match link_type {
+                LinkType::WikiLink { has_pothole } => {
+                    resolve_wikilink_destination(&dest_url, has_pothole, index)
+                }
+                _ => resolve_local_image_destination(dest_url, index),
+            };
+            let embeds_as_link = destination.is_linked_attachment();
+            embeds_as_links.push(embeds_as_link);
+
+            if embeds_as_link {
+                Event::Start(Tag::Link {
+                    link_type,
+                    dest_url: destination.into_href(),
+                    title,
+                    id,
+                })
+            } else {
+                Event::Start(Tag::Image {
+                    link_type,
+                    dest_url: destination.into_href(),
+                    title,
+                    id,
+                })
+            }
+        }
+        Event::End(TagEnd::Image) => {
+            if embeds_as_links.pop().unwrap_or(false) {
+                Event::End(TagEnd::Link)
+            } else {
+                Event::End(TagEnd::Image)
+            }
+        }
         event => event,
     })
 }
 
-fn resolve_wikilink_destination<'a>(
-    target: &str,
-    has_pothole: bool,
-    index: &'a Index,
-) -> CowStr<'a> {
-    let target = target.trim();
+/// Published destination of a link or embed.
+enum Destination<'a> {
+    Href(CowStr<'a>),
+    Attachment(&'a Attachment),
+}
+
+impl<'a> Destination<'a> {
+    fn is_linked_attachment(&self) -> bool {
+        matches!(self, Self::Attachment(attachment) if !attachment.asset_name.is_image())
+    }
+
+    fn into_href(self) -> CowStr<'a> {
+        match self {
+            Self::Href(href) => href,
+            Self::Attachment(attachment) => build_asset_path(&attachment.asset_name).into(),
+        }
+    }
+}
+
+/// Normalize a WikiLink destination into its vault target.
+pub(crate) fn wikilink_target(dest_url: &str, has_pothole: bool) -> &str {
+    let target = dest_url.trim();
     // pulldown-cmark keeps the escape before a piped WikiLink delimiter in its target.
-    let target = if has_pothole {
+    if has_pothole {
         target.strip_suffix('\\').unwrap_or(target)
     } else {
         target
+    }
+}
+
+fn resolve_wikilink_destination<'a>(
+    dest_url: &str,
+    has_pothole: bool,
+    index: &'a Index,
+) -> Destination<'a> {
+    let target = wikilink_target(dest_url, has_pothole);
+    let (note_target, fragment) = match target.split_once('#') {
+        Some((note_target, fragment)) => (note_target.trim(), Some(fragment)),
+        None => (target, None),
     };
 
-    match index.resolve(target) {
-        Some(href) => href.into(),
+    match fragment {
+        Some(fragment) if note_target.is_empty() => {
+            return Destination::Href(format!("#{}", fragment_anchor(fragment)).into());
+        }
+        Some(fragment) => {
+            if let Some(href) = index.resolve_fragment(note_target, fragment) {
+                return Destination::Href(href.into());
+            }
+        }
         None => {
-            tracing::warn!(%target, "internal link target was not found");
-            format!("/{target}").into()
+            if let Some(href) = index.resolve(target) {
+                return Destination::Href(href.into());
+            }
         }
     }
+    if let Some(attachment) = index.resolve_attachment(note_target) {
+        return Destination::Attachment(attachment);
+    }
+
+    // Broken links are gathered with their source files before rendering, per the site policy.
+    tracing::debug!(%target, "internal link target was not found");
+    Destination::Href(format!("/{target}").into())
+}
+
+// Obsidian heading fragments name the heading text; nested `A#B` fragments target the last heading.
+fn fragment_anchor(fragment: &str) -> String {
+    let fragment = fragment.rsplit('#').next().unwrap_or(fragment).trim();
+    if fragment.starts_with('^') {
+        fragment.to_string()
+    } else {
+        heading_id(fragment)
+    }
+}
+
+// Markdown image syntax may point at a vault attachment with a relative path.
+fn resolve_local_image_destination<'a>(dest_url: CowStr<'a>, index: &'a Index) -> Destination<'a> {
+    let target = dest_url.trim();
+    if target.is_empty() || target.starts_with('/') || target.contains(':') {
+        return Destination::Href(dest_url);
+    }
+
+    let target = target.trim_start_matches("./");
+    match index.resolve_attachment(target) {
+        Some(attachment) => Destination::Attachment(attachment),
+        None => Destination::Href(dest_url),
+    }
 }
 
 #[cfg(test)]
@@ -113,11 +521,13 @@ mod tests {
     use super::*;
     use crate::classify::{
         ClassifiedFiles, ParsedArticleFile, ParsedCategoryFile, ParsedHomeFile, ParsedPageFile,
+        SourceLocation,
     };
     use crate::vault::{ContentKind, ObsidianFrontMatter};
-    use domain::{Category, SectionPath, Slug};
+    use domain::{AssetName, Category, SectionPath, Slug};
     use pulldown_cmark::{Options, Parser};
     use rstest::rstest;
+    use std::path::PathBuf;
 
     fn index(routes: &[(&str, &str)]) -> Index {
         Index {
@@ -125,28 +535,34 @@ mod tests {
                 .iter()
                 .map(|(source_key, href)| ((*source_key).to_string(), (*href).to_string()))
                 .collect(),
+            ..Index::default()
+        }
+    }
+
+    fn attachment(source_key: &str, asset_name: &str) -> Attachment {
+        Attachment {
+            source_key: source_key.to_string(),
+            source_path: PathBuf::from(source_key),
+            asset_name: AssetName::new(asset_name.to_string()).unwrap(),
         }
     }
 
     fn article(source_key: &str, category: Category, slug: &str) -> ParsedArticleFile {
         ParsedArticleFile {
-            category,
+            category: category.clone(),
             slug: Slug::new(slug.to_string()).unwrap(),
             source_key: source_key.to_string(),
             section_path: SectionPath::default(),
+            aliases: vec![],
+            series: None,
+            publish_at: None,
             markdown_body: "# Article".to_string(),
             front_matter: ObsidianFrontMatter {
                 title: "Article".to_string(),
-                kind: ContentKind::Article,
-                tags: None,
-                summary: None,
-                priority: None,
-                created: "2025-01-01T00:00:00+09:00".to_string(),
-                updated: "2025-01-01T00:00:00+09:00".to_string(),
-                is_completed: true,
                 category: Some(category.as_str().to_string()),
-                page: None,
+                ..ObsidianFrontMatter::for_test(ContentKind::Article)
             },
+            location: SourceLocation::default(),
         }
     }
 
@@ -155,7 +571,8 @@ mod tests {
             page: domain::PageKey::new(page.to_string()).unwrap(),
             source_key: source_key.to_string(),
             markdown_body: "# Page".to_string(),
-            front_matter: front_matter(ContentKind::Page),
+            front_matter: ObsidianFrontMatter::for_test(ContentKind::Page),
+            location: SourceLocation::default(),
         }
     }
 
@@ -163,7 +580,8 @@ mod tests {
         ParsedHomeFile {
             source_key: source_key.to_string(),
             markdown_body: "# Home".to_string(),
-            front_matter: front_matter(ContentKind::Home),
+            front_matter: ObsidianFrontMatter::for_test(ContentKind::Home),
+            location: SourceLocation::default(),
         }
     }
 
@@ -172,22 +590,8 @@ mod tests {
             category,
             source_key: source_key.to_string(),
             markdown_body: "# Category".to_string(),
-            front_matter: front_matter(ContentKind::Category),
-        }
-    }
-
-    fn front_matter(kind: ContentKind) -> ObsidianFrontMatter {
-        ObsidianFrontMatter {
-            title: "Content".to_string(),
-            kind,
-            tags: None,
-            summary: None,
-            priority: None,
-            created: "2025-01-01T00:00:00+09:00".to_string(),
-            updated: "2025-01-01T00:00:00+09:00".to_string(),
-            is_completed: true,
-            category: None,
-            page: None,
+            front_matter: ObsidianFrontMatter::for_test(ContentKind::Category),
+            location: SourceLocation::default(),
         }
     }
 
@@ -197,25 +601,18 @@ mod tests {
             pages: Vec::new(),
             home: None,
             categories: Vec::new(),
+            scheduled: Vec::new(),
             skipped: 0,
-            errors: 0,
+            errors: Vec::new(),
         }
     }
 
     fn resolved_destinations(markdown: &str, index: &Index) -> Vec<(&'static str, String)> {
         let parser = Parser::new_ext(markdown, Options::ENABLE_WIKILINKS);
-        resolve_wikilinks(parser, index)
+        resolve_wikilinks(parser, index, None)
             .filter_map(|event| match event {
-                Event::Start(Tag::Link {
-                    link_type: LinkType::WikiLink { .. },
-                    dest_url,
-                    ..
-                }) => Some(("link", dest_url.to_string())),
-                Event::Start(Tag::Image {
-                    link_type: LinkType::WikiLink { .. },
-                    dest_url,
-                    ..
-                }) => Some(("image", dest_url.to_string())),
+                Event::Start(Tag::Link { dest_url, .. }) => Some(("link", dest_url.to_string())),
+                Event::Start(Tag::Image { dest_url, .. }) => Some(("image", dest_url.to_string())),
                 _ => None,
             })
             .collect()
@@ -224,12 +621,20 @@ mod tests {
     #[test]
     fn index_is_built_from_all_published_content() {
         let files = ClassifiedFiles {
-            articles: vec![article("tech/article", Category::Tech, "slug")],
+            articles: vec![article(
+                "tech/article",
+                Category::new("tech".to_string()).unwrap(),
+                "slug",
+            )],
             pages: vec![page("pages/about", "about")],
             home: Some(home("home")),
-            categories: vec![category("tech/index", Category::Tech)],
+            categories: vec![category(
+                "tech/index",
+                Category::new("tech".to_string()).unwrap(),
+            )],
+            scheduled: Vec::new(),
             skipped: 0,
-            errors: 0,
+            errors: Vec::new(),
         };
 
         let index = Index::from_classified_files(&files);
@@ -241,20 +646,21 @@ mod tests {
     }
 
     #[test]
-    fn index_excludes_pages_without_a_published_route() {
+    fn index_routes_every_static_page() {
         let files = ClassifiedFiles {
             articles: Vec::new(),
             pages: vec![page("pages/contact", "contact")],
             home: None,
             categories: Vec::new(),
+            scheduled: Vec::new(),
             skipped: 0,
-            errors: 0,
+            errors: Vec::new(),
         };
 
         let index = Index::from_classified_files(&files);
 
-        assert_eq!(index.resolve("pages/contact"), None);
-        assert_eq!(index.resolve("contact"), None);
+        assert_eq!(index.resolve("pages/contact"), Some("/contact"));
+        assert_eq!(index.resolve("contact"), Some("/contact"));
     }
 
     #[test]
@@ -267,8 +673,16 @@ mod tests {
     #[test]
     fn index_preserves_distinct_source_keys() {
         let files = classified_files(vec![
-            article("dir1/test", Category::Tech, "slug1"),
-            article("dir2/test", Category::Daily, "slug2"),
+            article(
+                "dir1/test",
+                Category::new("tech".to_string()).unwrap(),
+                "slug1",
+            ),
+            article(
+                "dir2/test",
+                Category::new("daily".to_string()).unwrap(),
+                "slug2",
+            ),
         ]);
 
         let index = Index::from_classified_files(&files);
@@ -290,6 +704,70 @@ mod tests {
         assert_eq!(index.resolve("missing"), None);
     }
 
+    #[test]
+    fn index_resolves_ambiguous_suffix_targets_to_the_first_source_key() {
+        let index = index(&[
+            ("zeta/name", "/tech/zeta"),
+            ("alpha/name", "/tech/alpha"),
+            ("mid/name", "/tech/mid"),
+        ]);
+
+        assert_eq!(index.resolve("name"), Some("/tech/alpha"));
+    }
+
+    #[test]
+    fn linked_articles_lists_other_articles_once_in_link_order() {
+        let tech = Category::new("tech".to_string()).unwrap();
+        let files = ClassifiedFiles {
+            pages: vec![page("pages/about", "about")],
+            ..classified_files(vec![
+                article("tech/current", tech.clone(), "current"),
+                article("tech/wasm", tech.clone(), "wasm"),
+                article("tech/rust", tech.clone(), "rust"),
+            ])
+        };
+        let index = Index::from_classified_files(&files);
+        let markdown = "[[rust#Setup]], [[about]], [[current]], [[tech/wasm|WASM]], [[rust]] \
+                        and ![[wasm]]\n\n`[[current]]` %% [[private]] %%";
+
+        index.record_references("tech/current", markdown);
+        let linked: Vec<_> = index
+            .linked_articles("tech/current")
+            .into_iter()
+            .map(|article| article.slug.as_str().to_string())
+            .collect();
+
+        assert_eq!(linked, ["rust", "wasm"]);
+    }
+
+    #[test]
+    fn link_graph_connects_published_routes() {
+        let tech = Category::new("tech".to_string()).unwrap();
+        let mut about = page("pages/about", "about");
+        about.markdown_body = "See [[article]] and [[missing]].".to_string();
+        let mut linking = article("tech/article", tech, "slug");
+        linking.markdown_body = "Back to [[about]], [[about#Profile]] and [[article]].".to_string();
+        let files = ClassifiedFiles {
+            pages: vec![about],
+            ..classified_files(vec![linking])
+        };
+
+        let index = Index::from_classified_files(&files);
+        for note in index.embedded_notes() {
+            index.record_references(note.source_key, note.markdown_body);
+        }
+        let graph = index.link_graph();
+
+        let nodes: Vec<_> = graph.nodes.iter().map(|node| node.path.as_str()).collect();
+        assert_eq!(nodes, ["/about", "/tech/slug"]);
+        let edges: Vec<_> = graph
+            .edges
+            .iter()
+            .map(|edge| (edge.source.as_str(), edge.target.as_str()))
+            .collect();
+        assert_eq!(edges, [("/about", "/tech/slug"), ("/tech/slug", "/about")]);
+    }
+
     #[test]
     fn resolve_wikilinks_to_published_urls() {
         let index = index(&[
@@ -325,6 +803,96 @@ mod tests {
         );
     }
 
+    #[test]
+    fn resolve_wikilinks_to_heading_and_block_fragments() {
+        let mut article = article(
+            "notes/derivation",
+            Category::new("physics".to_string()).unwrap(),
+            "slug",
+        );
+        article.markdown_body =
+            "# 運動方程式\n\nNewton's law. ^newton\n\n## Energy Conservation".to_string();
+        let index = Index::from_classified_files(&classified_files(vec![article]));
+
+        assert_eq!(
+            resolved_destinations(
+                "[[derivation#運動方程式]] [[derivation#^newton|law]] [[derivation#運動方程式#Energy Conservation]] [[#Local Heading]] [[derivation#Missing]] [[derivation#^missing]]",
+                &index,
+            ),
+            vec![
+                ("link", "/physics/slug#運動方程式".to_string()),
+                ("link", "/physics/slug#^newton".to_string()),
+                ("link", "/physics/slug#energy-conservation".to_string()),
+                ("link", "#local-heading".to_string()),
+                ("link", "/derivation#Missing".to_string()),
+                ("link", "/derivation#^missing".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn resolve_wikilinks_to_hashed_attachments() {
+        let index = index(&[("article", "/tech/slug")]).with_attachments(vec![
+            attachment("attachments/diagram.png", "00112233aabbccdd.png"),
+            attachment("attachments/paper.pdf", "44556677eeff0011.pdf"),
+            attachment("attachments/unused.png", "8899aabbccddeeff.png"),
+        ]);
+
+        assert_eq!(
+            resolved_destinations(
+                "![[diagram.png]] ![Figure](attachments/diagram.png) ![[paper.pdf]] [[paper.pdf|PDF]] ![[missing.png]]",
+                &index,
+            ),
+            vec![
+                ("image", "/media/00112233aabbccdd.png".to_string()),
+                ("image", "/media/00112233aabbccdd.png".to_string()),
+                ("link", "/media/44556677eeff0011.pdf".to_string()),
+                ("link", "/media/44556677eeff0011.pdf".to_string()),
+                ("image", "/missing.png".to_string()),
+            ]
+        );
+        assert_eq!(
+            index
+                .referenced_attachments()
+                .iter()
+                .map(|attachment| attachment.source_key.as_str())
+                .collect::<Vec<_>>(),
+            vec!["attachments/diagram.png", "attachments/paper.pdf"]
+        );
+    }
+
+    #[test]
+    fn resolve_wikilinks_keeps_remote_image_destinations() {
+        let index =
+            Index::default().with_attachments(vec![attachment("logo.png", "00112233aabbccdd.png")]);
+
+        assert_eq!(
+            resolved_destinations(
+                "![remote](https://example.com/logo.png) ![root](/logo.png)",
+                &index
+            ),
+            vec![
+                ("image", "https://example.com/logo.png".to_string()),
+                ("image", "/logo.png".to_string()),
+            ]
+        );
+        assert!(index.referenced_attachments().is_empty());
+    }
+
+    #[test]
+    fn resolve_wikilinks_closes_linked_attachment_embeds() {
+        let index =
+            Index::default().with_attachments(vec![attachment("talk.mp3", "00112233aabbccdd.mp3")]);
+        let parser = Parser::new_ext("![[talk.mp3|Talk]]", Options::ENABLE_WIKILINKS);
+        let mut html = String::new();
+        pulldown_cmark::html::push_html(&mut html, resolve_wikilinks(parser, &index, None));
+
+        assert_eq!(
+            html,
+            "<p><a href=\"/media/00112233aabbccdd.mp3\">Talk</a></p>\n"
+        );
+    }
+
     #[rstest]
     #[case::inline_code("`[[ignored]]` and [[article]]")]
     #[case::fenced_code_block("```markdown\n[[ignored]]\n```\n\n[[article]]")]
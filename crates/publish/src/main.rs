@@ -1,18 +1,179 @@
-use anyhow::Result;
-use publish::publish;
-use std::path::Path;
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use publish::{
+    DEFAULT_CONCURRENCY, Diagnostic, ErrorKind, PublishError, PublishOptions, Result, SiteConfig,
+};
+use std::{num::NonZeroUsize, path::PathBuf, process::ExitCode};
+use tracing::level_filters::LevelFilter;
 
+const SITE_CONFIG: &str = "crates/publish/site.yaml";
 const OBSIDIAN_DIR: &str = "crates/publish/obsidian/Publish";
 const OUTPUT_DIR: &str = "crates/publish/dist";
 
+// Exit codes follow sysexits.h so scripts can tell broken notes from a broken environment.
+const EXIT_CONTENT_ERROR: u8 = 65;
+const EXIT_IO_ERROR: u8 = 74;
+const EXIT_CONFIG_ERROR: u8 = 78;
+
+/// Builds the blog's site artifacts from the Obsidian vault.
+#[derive(Parser)]
+#[command(version)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Most verbose log level to print.
+    #[arg(long, global = true, default_value_t = LevelFilter::INFO)]
+    log_level: LevelFilter,
+
+    #[arg(long, global = true, value_enum, default_value_t = LogFormat::Pretty)]
+    log_format: LogFormat,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Renders the vault and writes the site artifacts.
+    Build(BuildArgs),
+    /// Builds, then rebuilds the notes that change until interrupted.
+    Watch(BuildArgs),
+    /// Lints the vault without writing anything and prints per-file diagnostics.
+    Check(CheckArgs),
+}
+
+#[derive(Args)]
+struct SourceArgs {
+    /// Site configuration that declares categories and tag synonyms.
+    #[arg(long, default_value = SITE_CONFIG)]
+    config: PathBuf,
+
+    /// Obsidian directory whose notes are published.
+    #[arg(long, default_value = OBSIDIAN_DIR)]
+    input: PathBuf,
+}
+
+#[derive(Args)]
+struct BuildArgs {
+    #[command(flatten)]
+    source: SourceArgs,
+
+    /// Directory that receives the `site/` artifact tree.
+    #[arg(long, default_value = OUTPUT_DIR)]
+    output: PathBuf,
+
+    /// Documents rendered and written at once.
+    #[arg(long, default_value_t = DEFAULT_CONCURRENCY)]
+    concurrency: NonZeroUsize,
+
+    /// Keep bookmarks as plain links instead of fetching OGP metadata.
+    #[arg(long)]
+    offline: bool,
+
+    /// Directory that keeps rendered note bodies between runs; unchanged notes skip rendering.
+    #[arg(long)]
+    cache_dir: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct CheckArgs {
+    #[command(flatten)]
+    source: SourceArgs,
+
+    /// How diagnostics are printed to stdout.
+    #[arg(long, value_enum, default_value_t = DiagnosticFormat::Human)]
+    format: DiagnosticFormat,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum DiagnosticFormat {
+    /// `file:line:column: severity[code]: message` lines.
+    Human,
+    /// One JSON array, for editors and pre-commit hooks.
+    Json,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum LogFormat {
+    /// Human-readable lines.
+    Pretty,
+    /// One JSON object per line, for log collectors.
+    Json,
+}
+
 #[tokio::main]
-async fn main() -> Result<()> {
-    tracing_subscriber::fmt()
-        .with_max_level(tracing::Level::INFO)
-        .try_init()
-        .map_err(anyhow::Error::from_boxed)?;
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
 
-    publish(Path::new(OBSIDIAN_DIR), Path::new(OUTPUT_DIR)).await?;
+    let subscriber = tracing_subscriber::fmt().with_max_level(cli.log_level);
+    let initialized = match cli.log_format {
+        LogFormat::Pretty => subscriber.try_init(),
+        LogFormat::Json => subscriber.json().try_init(),
+    };
+    if let Err(error) = initialized {
+        eprintln!("failed to initialize logging: {error}");
+        return ExitCode::FAILURE;
+    }
 
+    match run(cli.command).await {
+        Ok(exit_code) => exit_code,
+        Err(error) => {
+            tracing::error!(%error, "publish failed");
+            ExitCode::from(exit_code(&error))
+        }
+    }
+}
+
+async fn run(command: Command) -> Result<ExitCode> {
+    match command {
+        Command::Build(args) => {
+            publish_options(args)?.publish().await?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Watch(args) => {
+            publish_options(args)?.watch().await?;
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Check(args) => {
+            let config = SiteConfig::load(&args.source.config)?;
+            let diagnostics = publish::check(&config, &args.source.input).await?;
+            print_diagnostics(&diagnostics, args.format)?;
+            if diagnostics.iter().any(Diagnostic::is_error) {
+                Ok(ExitCode::from(EXIT_CONTENT_ERROR))
+            } else {
+                Ok(ExitCode::SUCCESS)
+            }
+        }
+    }
+}
+
+fn publish_options(args: BuildArgs) -> Result<PublishOptions> {
+    let config = SiteConfig::load(&args.source.config)?;
+    let mut options = PublishOptions::new(config, args.source.input, args.output)
+        .with_concurrency(args.concurrency);
+    if let Some(cache_dir) = args.cache_dir {
+        options = options.with_cache_dir(cache_dir);
+    }
+    Ok(if args.offline {
+        options.offline()
+    } else {
+        options
+    })
+}
+
+fn print_diagnostics(diagnostics: &[Diagnostic], format: DiagnosticFormat) -> Result<()> {
+    match format {
+        DiagnosticFormat::Human => {
+            for diagnostic in diagnostics {
+                println!("{diagnostic}");
+            }
+        }
+        DiagnosticFormat::Json => println!("{}", serde_json::to_string(diagnostics)?),
+    }
     Ok(())
 }
+
+fn exit_code(error: &PublishError) -> u8 {
+    match error.kind() {
+        ErrorKind::Content => EXIT_CONTENT_ERROR,
+        ErrorKind::Config => EXIT_CONFIG_ERROR,
+        ErrorKind::Io => EXIT_IO_ERROR,
+    }
+}
@@ -1,37 +1,407 @@
 use crate::artifacts::{
-    SiteDirectories, build_site_artifacts, validate_site_artifacts, write_article_page,
+    SiteDirectories, build_site_artifacts, validate_site_artifacts, write_articles, write_assets,
     write_site_artifacts,
 };
+use crate::assets::hash_attachments;
 use crate::classify::{
-    ParsedArticleFile, classify_obsidian_files, ensure_category_landings,
+    Schedule, classify_obsidian_files, collect_redirects, ensure_category_landings,
+    ensure_page_keys_do_not_shadow_categories, ensure_unique_article_paths,
     ensure_unique_category_landings, ensure_unique_page_keys,
 };
+use crate::config::{BrokenLinkPolicy, RawHtmlConfig, SiteConfig};
+use crate::diagnostics::{Diagnostic, DiagnosticCode};
 use crate::error::{PublishError, Result};
 use crate::render::{
-    BookmarkEnricher, render_article, render_category, render_home, render_page,
+    BodyEntry, BodyStore, BookmarkEnricher, CachedNote, NoteCache, RenderCache, broken_links,
+    ensure_acyclic_embeds, invalid_math, lint_markdown, passthrough_bookmark_enricher,
+    rejected_raw_html, render_article, render_category, render_home, render_page,
     rich_bookmark_enricher,
 };
-use crate::vault::{scan_markdown_files, validate_obsidian_dir};
-use crate::{classify, links};
+use crate::vault::{
+    SourceCache, StrictFrontmatter, scan_attachment_files, scan_markdown_files,
+    validate_obsidian_dir,
+};
+use crate::{classify, links, watch};
+use chrono::{DateTime, Utc};
 use futures::{StreamExt, stream};
-use std::{path::Path, sync::Arc};
-use tracing::info;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    future::Future,
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+use tracing::{error, info, warn};
+
+/// Documents rendered and written at once unless [`PublishOptions::with_concurrency`] says otherwise.
+pub const DEFAULT_CONCURRENCY: NonZeroUsize = NonZeroUsize::new(4).unwrap();
 
-pub async fn publish(obsidian_dir: &Path, output_dir: &Path) -> Result<()> {
-    publish_with_bookmark_enricher(obsidian_dir, output_dir, rich_bookmark_enricher()).await
+/// Tells a run what time it is, which decides the scheduled articles it releases.
+type Clock = Arc<dyn Fn() -> DateTime<Utc> + Send + Sync>;
+
+/// Everything one publish run needs, so callers never depend on env vars or the working directory.
+#[derive(Clone)]
+pub struct PublishOptions {
+    config: SiteConfig,
+    obsidian_dir: PathBuf,
+    output_dir: PathBuf,
+    concurrency: NonZeroUsize,
+    enrich: BookmarkEnricher,
+    /// Names the enricher in build cache keys, so bodies enriched differently are never mixed.
+    enricher_name: String,
+    cache_dir: Option<PathBuf>,
+    clock: Clock,
+}
+
+impl PublishOptions {
+    /// Publishes `obsidian_dir` into `output_dir` with rich bookmarks and default concurrency.
+    pub fn new(
+        config: SiteConfig,
+        obsidian_dir: impl Into<PathBuf>,
+        output_dir: impl Into<PathBuf>,
+    ) -> Self {
+        Self {
+            config,
+            obsidian_dir: obsidian_dir.into(),
+            output_dir: output_dir.into(),
+            concurrency: DEFAULT_CONCURRENCY,
+            enrich: rich_bookmark_enricher(),
+            enricher_name: "rich".to_string(),
+            cache_dir: None,
+            clock: Arc::new(Utc::now),
+        }
+    }
+
+    pub fn with_concurrency(mut self, concurrency: NonZeroUsize) -> Self {
+        self.concurrency = concurrency;
+        self
+    }
+
+    /// Enriches bookmarks with `enrich`, which `name` identifies in the build cache.
+    ///
+    /// Bodies are reused only by an enricher of the same name, so give enrichers that render
+    /// differently different names.
+    pub fn with_bookmark_enricher(
+        mut self,
+        name: impl Into<String>,
+        enrich: BookmarkEnricher,
+    ) -> Self {
+        self.enricher_name = name.into();
+        self.enrich = enrich;
+        self
+    }
+
+    /// Keeps bookmarks as plain links instead of fetching their OGP metadata.
+    pub fn offline(self) -> Self {
+        self.with_bookmark_enricher("offline", passthrough_bookmark_enricher())
+    }
+
+    /// Keeps rendered note bodies in `cache_dir`, so later runs skip rendering and bookmark
+    /// enrichment for notes whose source and link targets are unchanged.
+    pub fn with_cache_dir(mut self, cache_dir: impl Into<PathBuf>) -> Self {
+        self.cache_dir = Some(cache_dir.into());
+        self
+    }
+
+    /// Reads the publish time from `clock` instead of the system clock, so a run can be
+    /// pinned to the moment scheduled articles should (or should not yet) be released.
+    pub fn with_clock(mut self, clock: impl Fn() -> DateTime<Utc> + Send + Sync + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    pub async fn publish(&self) -> Result<()> {
+        self.rebuild(&mut self.render_cache()?, &mut SourceCache::default())
+            .await?;
+        Ok(())
+    }
+
+    /// Publishes, then republishes whenever a note or attachment in the vault changes, until
+    /// Ctrl-C.
+    ///
+    /// Each rebuild reads only the vault files that changed and renders only the notes that
+    /// changed and the notes whose links or embeds reach them. Nothing is written until the
+    /// whole site is built, so a rebuild that fails on a note leaves the last written site in
+    /// place.
+    pub async fn watch(&self) -> Result<()> {
+        watch::watch(self, async {
+            // Without a signal handler the watch runs until the process is killed.
+            if tokio::signal::ctrl_c().await.is_err() {
+                std::future::pending::<()>().await;
+            }
+        })
+        .await
+    }
+
+    /// Runs [`check`] on this vault at the time the clock reports.
+    pub async fn check(&self) -> Result<Vec<Diagnostic>> {
+        check_at(&self.config, &self.obsidian_dir, (self.clock)()).await
+    }
+
+    pub(crate) fn obsidian_dir(&self) -> &Path {
+        &self.obsidian_dir
+    }
+
+    /// Opens the render cache a run starts from, backed by the cache directory if there is one.
+    pub(crate) fn render_cache(&self) -> Result<RenderCache> {
+        match &self.cache_dir {
+            Some(cache_dir) => {
+                RenderCache::open(cache_dir, &self.enricher_name, &self.config.raw_html)
+            }
+            None => Ok(RenderCache::default()),
+        }
+    }
+
+    /// Publishes with renders reused from `cache` and vault files read through `sources`,
+    /// returning every artifact path the site uses.
+    pub(crate) async fn rebuild(
+        &self,
+        cache: &mut RenderCache,
+        sources: &mut SourceCache,
+    ) -> Result<BTreeSet<PathBuf>> {
+        run(self, cache, sources).await
+    }
+}
+
+pub async fn publish(config: &SiteConfig, obsidian_dir: &Path, output_dir: &Path) -> Result<()> {
+    PublishOptions::new(config.clone(), obsidian_dir, output_dir)
+        .publish()
+        .await
 }
 
-#[tracing::instrument(
-    name = "publish",
-    skip_all,
-    fields(input_dir = %obsidian_dir.display(), output_dir = %output_dir.display()),
-    err
-)]
 pub async fn publish_with_bookmark_enricher(
+    config: &SiteConfig,
     obsidian_dir: &Path,
     output_dir: &Path,
+    enricher_name: &str,
     enrich: BookmarkEnricher,
 ) -> Result<()> {
+    PublishOptions::new(config.clone(), obsidian_dir, output_dir)
+        .with_bookmark_enricher(enricher_name, enrich)
+        .publish()
+        .await
+}
+
+/// Runs every publish step short of writing artifacts and reports what would fail or look wrong.
+///
+/// Only a vault that cannot be read is an `Err`; problems in the notes come back as diagnostics,
+/// sorted by file and position. Bookmarks are not fetched, so the check works offline.
+pub async fn check(config: &SiteConfig, obsidian_dir: &Path) -> Result<Vec<Diagnostic>> {
+    check_at(config, obsidian_dir, Utc::now()).await
+}
+
+#[tracing::instrument(name = "check", skip_all, fields(input_dir = %obsidian_dir.display()), err)]
+async fn check_at(
+    config: &SiteConfig,
+    obsidian_dir: &Path,
+    now: DateTime<Utc>,
+) -> Result<Vec<Diagnostic>> {
+    validate_obsidian_dir(obsidian_dir)?;
+
+    let markdown_files = scan_markdown_files(obsidian_dir)?;
+    let schedule = Schedule {
+        now,
+        include_scheduled_articles: config.include_scheduled_articles,
+    };
+    let mut classified_files = classify_obsidian_files(
+        markdown_files,
+        &mut SourceCache::default(),
+        obsidian_dir,
+        &config.categories,
+        schedule,
+        config
+            .strict_frontmatter
+            .then(|| StrictFrontmatter::new(&config.tag_synonyms)),
+    );
+    let mut diagnostics = std::mem::take(&mut classified_files.errors);
+
+    let site_checks = [
+        (
+            DiagnosticCode::DuplicateRoute,
+            ensure_unique_page_keys(&classified_files.pages),
+        ),
+        (
+            DiagnosticCode::DuplicateRoute,
+            ensure_page_keys_do_not_shadow_categories(&classified_files.pages, &config.categories),
+        ),
+        (
+            DiagnosticCode::DuplicateRoute,
+            ensure_unique_category_landings(&classified_files.categories),
+        ),
+        (
+            DiagnosticCode::DuplicateRoute,
+            ensure_unique_article_paths(&classified_files.articles),
+        ),
+        (
+            DiagnosticCode::MissingCategoryLanding,
+            ensure_category_landings(&classified_files.articles, &classified_files.categories),
+        ),
+    ];
+    for (code, conflicts) in site_checks {
+        for conflict in conflicts {
+            diagnostics.extend(conflict.diagnostics(code));
+        }
+    }
+
+    let attachment_files = scan_attachment_files(obsidian_dir)?;
+    let vault_dir = obsidian_dir.to_path_buf();
+    let attachments =
+        tokio::task::spawn_blocking(move || hash_attachments(attachment_files, &vault_dir))
+            .await??;
+    let link_index =
+        links::Index::from_classified_files(&classified_files).with_attachments(attachments);
+    if let Err(cycle) = ensure_acyclic_embeds(&link_index) {
+        diagnostics.extend(cycle.diagnostics(&link_index, &classified_files));
+    }
+
+    for (source_key, markdown_body, location) in classified_files.keyed_notes() {
+        diagnostics.extend(lint_markdown(
+            markdown_body,
+            source_key,
+            location,
+            &link_index,
+            config.broken_links,
+            &config.raw_html,
+        ));
+    }
+
+    // Rendering a vault that already failed would only repeat the same problems.
+    if !diagnostics.iter().any(Diagnostic::is_error) {
+        diagnostics.extend(check_rendering(config, classified_files, &link_index).await);
+    }
+
+    diagnostics.sort_by(|left, right| {
+        (&left.file, left.line, left.column).cmp(&(&right.file, right.line, right.column))
+    });
+    info!(
+        error_count = diagnostics.iter().filter(|d| d.is_error()).count(),
+        warning_count = diagnostics.iter().filter(|d| !d.is_error()).count(),
+        "check completed"
+    );
+    Ok(diagnostics)
+}
+
+/// Renders every note and assembles the site in memory, reporting what `publish` would reject.
+async fn check_rendering(
+    config: &SiteConfig,
+    classified_files: classify::ClassifiedFiles,
+    link_index: &links::Index,
+) -> Vec<Diagnostic> {
+    let enrich = passthrough_bookmark_enricher();
+    let redirects = collect_redirects(&classified_files.articles);
+    let mut diagnostics = Vec::new();
+
+    let mut article_metas = Vec::with_capacity(classified_files.articles.len());
+    for parsed_file in classified_files.articles {
+        let path = parsed_file.location.path.clone();
+        match render_article(
+            parsed_file,
+            link_index,
+            &config.raw_html,
+            &config.tag_synonyms,
+            Arc::clone(&enrich),
+            BodyEntry::default(),
+        )
+        .await
+        {
+            Ok(article) => article_metas.push(article.meta),
+            Err(error) => diagnostics.push(
+                Diagnostic::error(DiagnosticCode::InvalidContent, error.to_string()).in_file(&path),
+            ),
+        }
+    }
+
+    let mut page_documents = Vec::with_capacity(classified_files.pages.len());
+    for parsed_file in classified_files.pages {
+        page_documents.push(
+            render_page(
+                parsed_file,
+                link_index,
+                &config.raw_html,
+                Arc::clone(&enrich),
+                BodyEntry::default(),
+            )
+            .await,
+        );
+    }
+
+    let home_fragment = match classified_files.home {
+        Some(parsed_file) => Some(
+            render_home(
+                parsed_file,
+                link_index,
+                &config.raw_html,
+                Arc::clone(&enrich),
+                BodyEntry::default(),
+            )
+            .await,
+        ),
+        None => None,
+    };
+
+    let mut category_landings = Vec::with_capacity(classified_files.categories.len());
+    for parsed_file in classified_files.categories {
+        let path = parsed_file.location.path.clone();
+        match render_category(
+            parsed_file,
+            link_index,
+            &config.raw_html,
+            Arc::clone(&enrich),
+            BodyEntry::default(),
+        )
+        .await
+        {
+            Ok(landing) => category_landings.push(landing),
+            Err(error) => diagnostics.push(
+                Diagnostic::error(DiagnosticCode::InvalidContent, error.to_string()).in_file(&path),
+            ),
+        }
+    }
+
+    if diagnostics.is_empty()
+        && let Err(error) = build_site_artifacts(
+            article_metas,
+            category_landings,
+            page_documents,
+            home_fragment,
+            redirects,
+            link_index.link_graph(),
+            &config.categories,
+        )
+    {
+        diagnostics.push(Diagnostic::error(
+            DiagnosticCode::InvalidContent,
+            error.to_string(),
+        ));
+    }
+
+    diagnostics
+}
+
+#[tracing::instrument(
+    name = "publish",
+    skip_all,
+    fields(
+        input_dir = %options.obsidian_dir.display(),
+        output_dir = %options.output_dir.display(),
+    ),
+    err
+)]
+async fn run(
+    options: &PublishOptions,
+    cache: &mut RenderCache,
+    sources: &mut SourceCache,
+) -> Result<BTreeSet<PathBuf>> {
+    let PublishOptions {
+        config,
+        obsidian_dir,
+        output_dir,
+        enrich,
+        clock,
+        ..
+    } = options;
+    let concurrency = options.concurrency.get();
+    let now = clock();
     validate_obsidian_dir(obsidian_dir)?;
 
     let start_time = std::time::Instant::now();
@@ -40,28 +410,88 @@ pub async fn publish_with_bookmark_enricher(
     let markdown_files = scan_markdown_files(obsidian_dir)?;
     info!(file_count = markdown_files.len(), "scanned markdown files");
 
-    let classified_files = classify_obsidian_files(markdown_files, obsidian_dir);
+    let schedule = Schedule {
+        now,
+        include_scheduled_articles: config.include_scheduled_articles,
+    };
+    let classified_files = classify_obsidian_files(
+        markdown_files,
+        sources,
+        obsidian_dir,
+        &config.categories,
+        schedule,
+        config
+            .strict_frontmatter
+            .then(|| StrictFrontmatter::new(&config.tag_synonyms)),
+    );
 
     info!(
         article_count = classified_files.articles.len(),
         page_count = classified_files.pages.len(),
         home_count = usize::from(classified_files.home.is_some()),
         category_count = classified_files.categories.len(),
+        scheduled_count = classified_files.scheduled.len(),
         skipped_count = classified_files.skipped,
-        error_count = classified_files.errors,
+        error_count = classified_files.errors.len(),
         "classified markdown files"
     );
-    if classified_files.errors > 0 {
+    if !classified_files.errors.is_empty() {
         return Err(PublishError::ContentErrors {
-            count: classified_files.errors,
+            count: classified_files.errors.len(),
         });
     }
 
-    ensure_unique_page_keys(&classified_files.pages)?;
-    ensure_unique_category_landings(&classified_files.categories)?;
-    ensure_category_landings(&classified_files.articles, &classified_files.categories)?;
+    for scheduled in &classified_files.scheduled {
+        info!(
+            source_key = scheduled.source_key,
+            publish_at = %scheduled.publish_at,
+            "held back scheduled content"
+        );
+    }
+    for article in &classified_files.articles {
+        if let Some(publish_at) = &article.publish_at
+            && publish_at.to_utc() > schedule.now
+        {
+            info!(
+                source_key = article.source_key,
+                publish_at = %publish_at,
+                "included scheduled article hidden until publish_at"
+            );
+        }
+    }
+
+    // A publish stops at the first conflict; `check` reports every one.
+    let conflicts = [
+        ensure_unique_page_keys(&classified_files.pages),
+        ensure_page_keys_do_not_shadow_categories(&classified_files.pages, &config.categories),
+        ensure_unique_category_landings(&classified_files.categories),
+        ensure_unique_article_paths(&classified_files.articles),
+        ensure_category_landings(&classified_files.articles, &classified_files.categories),
+    ];
+    if let Some(conflict) = conflicts.into_iter().flatten().next() {
+        return Err(conflict.into());
+    }
+
+    let attachment_files = scan_attachment_files(obsidian_dir)?;
+    info!(
+        file_count = attachment_files.len(),
+        "scanned attachment files"
+    );
+    let (mut attachments, unhashed_files) = sources.split_attachments(attachment_files);
+    let vault_dir = obsidian_dir.to_path_buf();
+    let hashed =
+        tokio::task::spawn_blocking(move || hash_attachments(unhashed_files, &vault_dir)).await??;
+    info!(hashed_count = hashed.len(), "hashed attachment files");
+    sources.insert_attachments(&hashed);
+    attachments.extend(hashed);
 
-    let link_index = links::Index::from_classified_files(&classified_files);
+    let link_index =
+        links::Index::from_classified_files(&classified_files).with_attachments(attachments);
+    ensure_acyclic_embeds(&link_index)?;
+    let broken_links = collect_broken_links(&classified_files, &link_index, config.broken_links)?;
+    report_invalid_math(&classified_files);
+    let rejected_raw_html = collect_rejected_raw_html(&classified_files, &config.raw_html);
+    let redirects = collect_redirects(&classified_files.articles);
     let classify::ClassifiedFiles {
         articles,
         pages,
@@ -71,55 +501,116 @@ pub async fn publish_with_bookmark_enricher(
         ..
     } = classified_files;
 
-    let site_directories = SiteDirectories::prepare(output_dir)?;
-
-    const CONCURRENT_LIMIT: usize = 4;
-
-    // Drain each batch before propagating errors so started blocking writes can finish.
-    let article_results = stream::iter(articles)
-        .map(|parsed_file| {
-            process_article(
+    // Rendered articles are written with the rest of the site once it has been built.
+    let articles = render_with_cache(
+        "article",
+        articles,
+        &mut cache.articles,
+        &cache.bodies,
+        &link_index,
+        concurrency,
+        |parsed_file, body_entry| {
+            render_article(
                 parsed_file,
                 &link_index,
-                Arc::clone(&enrich),
-                site_directories.clone(),
+                &config.raw_html,
+                &config.tag_synonyms,
+                Arc::clone(enrich),
+                body_entry,
             )
-        })
-        .buffer_unordered(CONCURRENT_LIMIT)
-        .collect::<Vec<_>>()
-        .await;
-    let article_metas = article_results.into_iter().collect::<Result<Vec<_>>>()?;
+        },
+    )
+    .await?;
 
-    let page_documents = stream::iter(pages)
-        .map(|parsed_file| render_page(parsed_file, &link_index, Arc::clone(&enrich)))
-        .buffer_unordered(CONCURRENT_LIMIT)
-        .collect::<Vec<_>>()
-        .await;
+    let page_documents = render_with_cache(
+        "page",
+        pages,
+        &mut cache.pages,
+        &cache.bodies,
+        &link_index,
+        concurrency,
+        |parsed_file, body_entry| {
+            let page = render_page(
+                parsed_file,
+                &link_index,
+                &config.raw_html,
+                Arc::clone(enrich),
+                body_entry,
+            );
+            async { Ok(page.await) }
+        },
+    )
+    .await?;
 
-    let home_fragment = match home {
-        Some(parsed_file) => Some(render_home(parsed_file, &link_index, Arc::clone(&enrich)).await),
-        None => None,
-    };
+    let home_fragment = render_with_cache(
+        "home",
+        home,
+        &mut cache.home,
+        &cache.bodies,
+        &link_index,
+        concurrency,
+        |parsed_file, body_entry| {
+            let home = render_home(
+                parsed_file,
+                &link_index,
+                &config.raw_html,
+                Arc::clone(enrich),
+                body_entry,
+            );
+            async { Ok(home.await) }
+        },
+    )
+    .await?
+    .pop();
 
-    let category_results = stream::iter(categories)
-        .map(|parsed_file| render_category(parsed_file, &link_index, Arc::clone(&enrich)))
-        .buffer_unordered(CONCURRENT_LIMIT)
-        .collect::<Vec<_>>()
-        .await;
-    let category_landings = category_results.into_iter().collect::<Result<Vec<_>>>()?;
+    let category_landings = render_with_cache(
+        "category",
+        categories,
+        &mut cache.categories,
+        &cache.bodies,
+        &link_index,
+        concurrency,
+        |parsed_file, body_entry| {
+            render_category(
+                parsed_file,
+                &link_index,
+                &config.raw_html,
+                Arc::clone(enrich),
+                body_entry,
+            )
+        },
+    )
+    .await?;
 
+    // Links are recorded while rendering, so the graph is complete only once every note is.
+    let link_graph = link_index.link_graph();
+    info!(
+        node_count = link_graph.nodes.len(),
+        edge_count = link_graph.edges.len(),
+        "built link graph"
+    );
     let site_artifacts = build_site_artifacts(
-        article_metas,
+        articles
+            .iter()
+            .map(|article| article.meta.clone())
+            .collect(),
         category_landings,
         page_documents,
         home_fragment,
+        redirects,
+        link_graph,
+        &config.categories,
     )?;
-    let site_directories_for_write = site_directories.clone();
-    let site_artifacts = tokio::task::spawn_blocking(move || {
-        write_site_artifacts(&site_directories_for_write, &site_artifacts)?;
-        Ok::<_, PublishError>(site_artifacts)
+    let referenced_attachments = link_index.referenced_attachments();
+    let site_directories = SiteDirectories::prepare(output_dir)?;
+    let (site_artifacts, artifact_paths) = tokio::task::spawn_blocking(move || {
+        write_assets(&site_directories, &referenced_attachments)?;
+        let mut written = write_articles(&site_directories, &articles)?;
+        written.extend(write_site_artifacts(&site_directories, &site_artifacts)?);
+        Ok::<_, PublishError>((site_artifacts, written))
     })
     .await??;
+    info!(file_count = artifact_paths.len(), "wrote site artifacts");
 
     let site_root = output_dir.join("site");
     let validation =
@@ -127,18 +618,28 @@ pub async fn publish_with_bookmark_enricher(
     info!(
         article_count = validation.article_count,
         category_count = validation.category_count,
+        asset_count = validation.asset_count,
         "validated site artifacts"
     );
 
+    if let Err(error) = cache.prune() {
+        warn!(%error, "failed to prune the build cache");
+    }
+
     let processed_count = site_artifacts.article_index.len();
     let duration = start_time.elapsed();
+    let (cache_hit_count, cache_miss_count) = cache.bodies.take_counts();
 
     info!(
         processed_count,
         skipped_count = skipped,
+        cache_hit_count,
+        cache_miss_count,
         processing_time_ms = duration.as_millis(),
         "publish completed"
     );
+    report_broken_links(&broken_links);
+    report_rejected_raw_html(&rejected_raw_html);
 
     if !site_artifacts.article_index.is_empty() {
         for article in &site_artifacts.article_index {
@@ -150,28 +651,147 @@ pub async fn publish_with_bookmark_enricher(
         }
     }
 
-    Ok(())
+    Ok(artifact_paths)
 }
 
-#[tracing::instrument(skip_all, fields(source_key = %parsed_file.source_key), err)]
-async fn process_article(
-    parsed_file: ParsedArticleFile,
+/// Renders the notes of one kind, reusing cached renders of notes whose inputs are unchanged.
+async fn render_with_cache<'a, N, T, F, Fut>(
+    kind: &'static str,
+    notes: impl IntoIterator<Item = N>,
+    cache: &mut NoteCache<T>,
+    bodies: &'a BodyStore,
     link_index: &links::Index,
-    enrich: BookmarkEnricher,
-    site_directories: SiteDirectories,
-) -> Result<domain::ArticleMeta> {
-    let article = render_article(parsed_file, link_index, enrich).await?;
-    let (meta, output_file_path) = tokio::task::spawn_blocking(move || {
-        let output_file_path = write_article_page(
-            &site_directories,
-            article.meta.category,
-            &article.meta.slug,
-            article.body.as_str(),
-        )?;
-        Ok::<_, PublishError>((article.meta, output_file_path))
-    })
-    .await??;
+    concurrency: usize,
+    render: F,
+) -> Result<Vec<T>>
+where
+    N: CachedNote,
+    T: Clone,
+    F: Fn(N, BodyEntry<'a>) -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let (mut rendered, stale) = cache.split(notes, link_index);
+    let reused_count = rendered.len();
+    let rendered_count = stale.len();
+
+    let results: Vec<_> = stream::iter(stale)
+        .map(|(note, cache_key)| {
+            let output = render(note, bodies.entry(cache_key.clone()));
+            async move { (cache_key, output.await) }
+        })
+        .buffer_unordered(concurrency)
+        .collect()
+        .await;
+    for (cache_key, result) in results {
+        let output = result?;
+        cache.insert(cache_key, output.clone());
+        rendered.push(output);
+    }
+
+    info!(kind, rendered_count, reused_count, "rendered notes");
+    Ok(rendered)
+}
+
+/// Gathers every broken link with its source file, failing before anything is written under
+/// [`BrokenLinkPolicy::Error`].
+fn collect_broken_links(
+    classified_files: &classify::ClassifiedFiles,
+    link_index: &links::Index,
+    policy: BrokenLinkPolicy,
+) -> Result<Vec<Diagnostic>> {
+    let found: Vec<_> = classified_files
+        .keyed_notes()
+        .flat_map(|(source_key, markdown_body, location)| {
+            broken_links(markdown_body, source_key, location, link_index, policy)
+        })
+        .collect();
+    for broken_link in &found {
+        if broken_link.is_error() {
+            error!(diagnostic = %broken_link, "broken internal link");
+        } else {
+            warn!(diagnostic = %broken_link, "broken internal link");
+        }
+    }
 
-    info!(output_file = %output_file_path.display(), "wrote artifact");
-    Ok(meta)
+    match policy {
+        BrokenLinkPolicy::Error if !found.is_empty() => {
+            Err(PublishError::BrokenLinks { count: found.len() })
+        }
+        _ => Ok(found),
+    }
+}
+
+/// Warns about each TeX math span that is published as its source, with its file and position.
+fn report_invalid_math(classified_files: &classify::ClassifiedFiles) {
+    for (markdown_body, location) in classified_files.notes() {
+        for diagnostic in invalid_math(markdown_body, location) {
+            warn!(%diagnostic, "invalid TeX math");
+        }
+    }
+}
+
+/// Warns about each raw HTML fragment the allowlist rejects, with its file and position.
+///
+/// Collected from the sources rather than while rendering, so notes reused from the render cache
+/// are reported too.
+fn collect_rejected_raw_html(
+    classified_files: &classify::ClassifiedFiles,
+    raw_html: &RawHtmlConfig,
+) -> Vec<Diagnostic> {
+    let found: Vec<_> = classified_files
+        .notes()
+        .flat_map(|(markdown_body, location)| rejected_raw_html(markdown_body, location, raw_html))
+        .collect();
+    for diagnostic in &found {
+        warn!(
+            file = diagnostic.file.as_deref().unwrap_or_default(),
+            %diagnostic,
+            "rejected raw HTML"
+        );
+    }
+    found
+}
+
+/// Counts diagnostics per source file for the end-of-run reports.
+fn count_by_file(diagnostics: &[Diagnostic]) -> BTreeMap<&str, usize> {
+    let mut counts_by_file: BTreeMap<&str, usize> = BTreeMap::new();
+    for diagnostic in diagnostics {
+        *counts_by_file
+            .entry(diagnostic.file.as_deref().unwrap_or_default())
+            .or_default() += 1;
+    }
+    counts_by_file
+}
+
+/// Ends the run with how many broken links each source file published anyway.
+fn report_broken_links(broken_links: &[Diagnostic]) {
+    let counts_by_file = count_by_file(broken_links);
+    for (file, broken_link_count) in &counts_by_file {
+        warn!(
+            file,
+            broken_link_count, "published with broken internal links"
+        );
+    }
+    if !broken_links.is_empty() {
+        warn!(
+            broken_link_count = broken_links.len(),
+            file_count = counts_by_file.len(),
+            "broken link report"
+        );
+    }
+}
+
+/// Ends the run with how much raw HTML each source file had rejected.
+fn report_rejected_raw_html(rejected_raw_html: &[Diagnostic]) {
+    let counts_by_file = count_by_file(rejected_raw_html);
+    for (file, rejected_count) in &counts_by_file {
+        warn!(file, rejected_count, "published with rejected raw HTML");
+    }
+    if !rejected_raw_html.is_empty() {
+        warn!(
+            rejected_count = rejected_raw_html.len(),
+            file_count = counts_by_file.len(),
+            "rejected raw HTML report"
+        );
+    }
 }
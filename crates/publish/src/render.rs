@@ -1,10 +1,28 @@
+mod anchor;
 mod body;
 mod bookmark;
+mod cache;
+mod callout;
+mod comment;
 mod document;
+mod embed;
+mod highlight;
 mod html;
+mod lint;
+mod mark;
+mod math;
 mod ogp;
 mod sanitize;
+mod tag;
+mod text;
+mod toc;
 
-pub use bookmark::BookmarkEnricher;
+pub(crate) use anchor::{collect_anchors, heading_id};
 pub(crate) use bookmark::rich_bookmark_enricher;
+pub use bookmark::{BookmarkEnricher, passthrough_bookmark_enricher};
+pub(crate) use cache::{BodyEntry, BodyStore, CachedNote, NoteCache, RenderCache};
+pub(crate) use comment::strip_comments;
 pub(crate) use document::{render_article, render_category, render_home, render_page};
+pub(crate) use embed::ensure_acyclic_embeds;
+pub(crate) use html::markdown_options;
+pub(crate) use lint::{broken_links, invalid_math, lint_markdown, rejected_raw_html};
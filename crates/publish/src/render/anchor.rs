@@ -0,0 +1,213 @@
+use super::html::markdown_options;
+use html_escape::encode_double_quoted_attribute;
+use pulldown_cmark::{Event, Parser, Tag, TagEnd};
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+use std::sync::LazyLock;
+
+static BLOCK_ID_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?:^|\s)\^([A-Za-z0-9-]+)\s*$").expect("Invalid block id regex pattern")
+});
+
+/// Assigns heading IDs and Obsidian `^block-id` paragraph anchors, returning the anchors used.
+pub(super) fn assign_anchors(events: Vec<Event<'_>>) -> (Vec<Event<'_>>, Vec<String>) {
+    anchor_events(events, true)
+}
+
+/// Removes `^block-id` markers without giving headings or paragraphs an id, for transcluded
+/// bodies whose anchors would repeat ids the host page already uses.
+pub(super) fn strip_anchors(events: Vec<Event<'_>>) -> Vec<Event<'_>> {
+    anchor_events(events, false).0
+}
+
+fn anchor_events(events: Vec<Event<'_>>, with_ids: bool) -> (Vec<Event<'_>>, Vec<String>) {
+    let mut result = Vec::with_capacity(events.len());
+    let mut anchors = Vec::new();
+    let mut heading_ids = HeadingIds::default();
+    let mut heading_start = None;
+    let mut paragraph_starts = Vec::new();
+
+    for event in events {
+        match event {
+            Event::Start(Tag::Heading { .. }) => {
+                heading_start = Some(result.len());
+                result.push(event);
+            }
+            Event::End(TagEnd::Heading(_)) => {
+                if let Some(start) = heading_start.take()
+                    && with_ids
+                {
+                    let id = heading_ids.next(&heading_text(&result[start + 1..]));
+                    if let Event::Start(Tag::Heading { id: heading_id, .. }) = &mut result[start] {
+                        *heading_id = Some(id.clone().into());
+                    }
+                    anchors.push(id);
+                }
+                result.push(event);
+            }
+            Event::Start(Tag::Paragraph) => {
+                paragraph_starts.push(result.len());
+                result.push(event);
+            }
+            Event::End(TagEnd::Paragraph) => {
+                let start = paragraph_starts.pop();
+                match start.zip(take_block_id(&mut result)) {
+                    Some((start, block_id)) if with_ids => {
+                        let anchor = format!("^{block_id}");
+                        result[start] = Event::Html(
+                            format!(r#"<p id="{}">"#, encode_double_quoted_attribute(&anchor))
+                                .into(),
+                        );
+                        result.push(Event::Html("</p>\n".into()));
+                        anchors.push(anchor);
+                    }
+                    _ => result.push(event),
+                }
+            }
+            event => result.push(event),
+        }
+    }
+
+    (result, anchors)
+}
+
+/// Anchors a note body exposes to `[[note#Heading]]` and `[[note#^block-id]]` links.
+pub(crate) fn collect_anchors(markdown: &str) -> HashSet<String> {
+    let events = Parser::new_ext(markdown, markdown_options()).collect();
+    assign_anchors(events).1.into_iter().collect()
+}
+
+/// Deterministic heading ID that keeps Japanese and other non-ASCII letters readable.
+pub(crate) fn heading_id(text: &str) -> String {
+    let mut id = String::with_capacity(text.len());
+    for c in text.trim().chars().flat_map(char::to_lowercase) {
+        if c.is_alphanumeric() || c == '_' {
+            id.push(c);
+        } else if (c.is_whitespace() || c == '-') && !id.is_empty() && !id.ends_with('-') {
+            id.push('-');
+        }
+    }
+
+    let id = id.trim_end_matches('-');
+    if id.is_empty() {
+        "section".to_string()
+    } else {
+        id.to_string()
+    }
+}
+
+/// Deduplicates heading IDs within one document by numbering repeats.
+#[derive(Default)]
+struct HeadingIds {
+    seen: HashMap<String, usize>,
+}
+
+impl HeadingIds {
+    fn next(&mut self, text: &str) -> String {
+        let id = heading_id(text);
+        let count = self.seen.entry(id.clone()).or_default();
+        *count += 1;
+        match *count {
+            1 => id,
+            count => format!("{id}-{}", count - 1),
+        }
+    }
+}
+
+pub(super) fn heading_text(events: &[Event<'_>]) -> String {
+    events
+        .iter()
+        .filter_map(|event| match event {
+            Event::Text(text) | Event::Code(text) | Event::InlineMath(text) => Some(text.as_ref()),
+            _ => None,
+        })
+        .collect()
+}
+
+// Removes a trailing `^block-id` marker from the paragraph text that was just emitted.
+fn take_block_id(result: &mut Vec<Event<'_>>) -> Option<String> {
+    let Some(Event::Text(text)) = result.last() else {
+        return None;
+    };
+    let captures = BLOCK_ID_RE.captures(text)?;
+    let block_id = captures[1].to_string();
+    let remaining = text[..captures.get(0)?.start()].trim_end().to_string();
+
+    result.pop();
+    if remaining.is_empty() {
+        if matches!(result.last(), Some(Event::SoftBreak | Event::HardBreak)) {
+            result.pop();
+        }
+    } else {
+        result.push(Event::Text(remaining.into()));
+    }
+
+    Some(block_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pulldown_cmark::html;
+    use rstest::rstest;
+
+    fn render(markdown: &str) -> String {
+        let events = Parser::new_ext(markdown, markdown_options()).collect();
+        let mut output = String::new();
+        html::push_html(&mut output, assign_anchors(events).0.into_iter());
+        output
+    }
+
+    #[rstest]
+    #[case::ascii("Hello World", "hello-world")]
+    #[case::punctuation("What's new? (v2.0)", "whats-new-v20")]
+    #[case::japanese("量子力学の 基礎", "量子力学の-基礎")]
+    #[case::full_width_punctuation("「波動関数」とは？", "波動関数とは")]
+    #[case::symbols_only("!!!", "section")]
+    fn test_heading_id(#[case] text: &str, #[case] expected: &str) {
+        assert_eq!(heading_id(text), expected);
+    }
+
+    #[test]
+    fn test_headings_receive_unique_ids() {
+        let html = render("# Intro\n\n## Example\n\n## Example\n\n### `code` and $x$");
+
+        assert_eq!(
+            html,
+            "<h1 id=\"intro\">Intro</h1>\n<h2 id=\"example\">Example</h2>\n<h2 id=\"example-1\">Example</h2>\n<h3 id=\"code-and-x\"><code>code</code> and <span class=\"math math-inline\">x</span></h3>\n"
+        );
+    }
+
+    #[rstest]
+    #[case::inline(
+        "Energy is conserved. ^energy-1",
+        "<p id=\"^energy-1\">Energy is conserved.</p>\n"
+    )]
+    #[case::own_line(
+        "Energy is conserved.\n^energy-1",
+        "<p id=\"^energy-1\">Energy is conserved.</p>\n"
+    )]
+    #[case::not_a_marker("2^10 is 1024", "<p>2^10 is 1024</p>\n")]
+    fn test_block_ids_anchor_paragraphs(#[case] markdown: &str, #[case] expected: &str) {
+        assert_eq!(render(markdown), expected);
+    }
+
+    #[test]
+    fn test_strip_anchors_drops_ids_and_block_markers() {
+        let events = Parser::new_ext("## Setup\n\nText ^block", markdown_options()).collect();
+        let mut output = String::new();
+        html::push_html(&mut output, strip_anchors(events).into_iter());
+
+        assert_eq!(output, "<h2>Setup</h2>\n<p>Text</p>\n");
+    }
+
+    #[test]
+    fn test_collect_anchors_includes_headings_and_blocks() {
+        let anchors = collect_anchors("# Title\n\nText ^block\n\n```\n# not a heading\n```");
+
+        assert_eq!(
+            anchors,
+            HashSet::from(["title".to_string(), "^block".to_string()])
+        );
+    }
+}
@@ -1,32 +1,88 @@
-use super::{bookmark::BookmarkEnricher, html::convert_markdown_to_html};
+use super::{
+    bookmark::BookmarkEnricher,
+    cache::{BodyEntry, RenderedBody},
+    html::{convert_markdown_to_html, convert_markdown_with_toc},
+};
+use crate::config::RawHtmlConfig;
 use crate::links;
+use domain::TocEntry;
 
 pub(super) async fn render(
+    source_key: &str,
     markdown: &str,
     link_index: &links::Index,
+    raw_html: &RawHtmlConfig,
     enrich: &BookmarkEnricher,
 ) -> String {
-    let html = convert_markdown_to_html(markdown, link_index);
+    let html = convert_markdown_to_html(source_key, markdown, link_index, raw_html);
     enrich(html).await
 }
 
+/// Renders an article body together with the outline used for its table of contents.
+pub(super) async fn render_with_toc(
+    source_key: &str,
+    markdown: &str,
+    link_index: &links::Index,
+    raw_html: &RawHtmlConfig,
+    enrich: &BookmarkEnricher,
+) -> (String, Vec<TocEntry>) {
+    let (html, toc) = convert_markdown_with_toc(source_key, markdown, link_index, raw_html);
+    (enrich(html).await, toc)
+}
+
+/// Renders a note body, or takes it from `entry` when nothing the render reads has changed.
+pub(super) async fn render_cached(
+    source_key: &str,
+    markdown: &str,
+    with_toc: bool,
+    link_index: &links::Index,
+    raw_html: &RawHtmlConfig,
+    enrich: &BookmarkEnricher,
+    entry: BodyEntry<'_>,
+) -> RenderedBody {
+    if let Some(body) = entry.get() {
+        link_index.record_references(source_key, markdown);
+        return body;
+    }
+
+    let body = if with_toc {
+        let (html, toc) = render_with_toc(source_key, markdown, link_index, raw_html, enrich).await;
+        RenderedBody { html, toc }
+    } else {
+        RenderedBody {
+            html: render(source_key, markdown, link_index, raw_html, enrich).await,
+            toc: Vec::new(),
+        }
+    };
+    entry.put(&body);
+    body
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{
-        classify::{ClassifiedFiles, ParsedArticleFile},
+        classify::{ClassifiedFiles, ParsedArticleFile, SourceLocation},
+        render::bookmark::passthrough_bookmark_enricher,
         vault::{ContentKind, ObsidianFrontMatter},
     };
     use domain::{Category, SectionPath, Slug};
     use indoc::indoc;
-    use std::sync::Arc;
 
     #[tokio::test]
     async fn test_render_converts_internal_links_to_html() {
         let files = ClassifiedFiles {
             articles: vec![
-                parsed_article("notes/article", Category::Tech, "def456"),
-                parsed_article("notes/reference", Category::Daily, "ghi789"),
+                parsed_article(
+                    "notes/article",
+                    Category::new("tech".to_string()).unwrap(),
+                    "def456",
+                ),
+                parsed_article(
+                    "notes/reference",
+                    Category::new("daily".to_string()).unwrap(),
+                    "ghi789",
+                ),
             ],
             ..Default::default()
         };
@@ -43,39 +99,163 @@ mod tests {
             - Regular item
         "#};
 
-        let html = render(markdown, &link_index, &enrich).await;
+        let html = render(
+            "notes/article",
+            markdown,
+            &link_index,
+            &RawHtmlConfig::default(),
+            &enrich,
+        )
+        .await;
 
-        assert!(html.contains("<h1>My Article</h1>"));
+        assert!(html.contains(r#"<h1 id="my-article">My Article</h1>"#));
         assert!(html.contains("<a href=\"/tech/def456\">link</a>"));
         assert!(html.contains("<a href=\"/daily/ghi789\">reference</a>"));
         assert!(html.contains("<strong>bold</strong>"));
         assert!(html.contains("<ul>"));
+        let linked: Vec<_> = link_index
+            .linked_articles("notes/article")
+            .into_iter()
+            .map(|article| article.slug.as_str().to_string())
+            .collect();
+        assert_eq!(linked, ["ghi789"]);
+    }
+
+    #[tokio::test]
+    async fn test_render_records_only_links_of_the_rendered_body() {
+        let tech = Category::new("tech".to_string()).unwrap();
+        let mut embedded = parsed_article("notes/embedded", tech.clone(), "embedded");
+        embedded.markdown_body = "See [[reference]].".to_string();
+        let files = ClassifiedFiles {
+            articles: vec![
+                parsed_article("notes/host", tech.clone(), "host"),
+                embedded,
+                parsed_article("notes/reference", tech, "reference"),
+            ],
+            ..Default::default()
+        };
+        let link_index = links::Index::from_classified_files(&files);
+
+        render(
+            "notes/host",
+            "![[embedded]]",
+            &link_index,
+            &RawHtmlConfig::default(),
+            &passthrough_bookmark_enricher(),
+        )
+        .await;
+
+        assert!(link_index.linked_articles("notes/host").is_empty());
+        assert!(link_index.link_graph().edges.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_render_expands_note_embeds_as_transclusions() {
+        let mut article = parsed_article(
+            "notes/article",
+            Category::new("tech".to_string()).unwrap(),
+            "def456",
+        );
+        article.markdown_body = "Embedded **body**.".to_string();
+        let files = ClassifiedFiles {
+            articles: vec![article],
+            ..Default::default()
+        };
+        let link_index = links::Index::from_classified_files(&files);
+        let enrich = passthrough_bookmark_enricher();
+
+        let html = render(
+            "notes/host",
+            "Before\n\n![[article]]\n\nAfter",
+            &link_index,
+            &RawHtmlConfig::default(),
+            &enrich,
+        )
+        .await;
+
+        assert_eq!(
+            html,
+            indoc! {r#"
+                <p>Before</p>
+                <div class="transclusion">
+                <div class="transclusion-body">
+                <p>Embedded <strong>body</strong>.</p>
+                </div>
+                <a class="transclusion-source" href="/tech/def456">Article</a>
+                </div>
+                <p>After</p>
+            "#}
+        );
     }
 
     #[tokio::test]
-    async fn test_render_converts_internal_embeds_to_images() {
+    async fn test_render_embeds_heading_sections() {
+        let mut article = parsed_article(
+            "notes/article",
+            Category::new("tech".to_string()).unwrap(),
+            "def456",
+        );
+        article.markdown_body = indoc! {r#"
+            # Title
+
+            Intro.
+
+            ## Setup
+
+            Setup text.
+
+            ### Details
+
+            Nested text.
+
+            ## Usage
+
+            Usage text.
+        "#}
+        .to_string();
         let files = ClassifiedFiles {
-            articles: vec![parsed_article("notes/article", Category::Tech, "def456")],
+            articles: vec![article],
             ..Default::default()
         };
         let link_index = links::Index::from_classified_files(&files);
         let enrich = passthrough_bookmark_enricher();
 
         let html = render(
-            "Embed ![[article]] and ![[article|Alt text]].",
+            "notes/host",
+            "## Setup\n\n![[article#Setup]] ![[article#Missing|Fallback]]",
             &link_index,
+            &RawHtmlConfig::default(),
             &enrich,
         )
         .await;
 
-        assert!(html.contains(r#"<img src="/tech/def456" alt="article" />"#));
-        assert!(html.contains(r#"<img src="/tech/def456" alt="Alt text" />"#));
+        assert!(
+            html.contains(r#"<h2 id="setup">Setup</h2>"#) && html.contains("<h2>Setup</h2>"),
+            "unexpected html:\n{html}"
+        );
+        assert_eq!(
+            html.matches(r#"id="setup""#).count(),
+            1,
+            "transcluded headings must not repeat ids:\n{html}"
+        );
+        assert!(
+            html.contains("<p>Nested text.</p>"),
+            "unexpected html:\n{html}"
+        );
+        assert!(!html.contains("Intro."), "unexpected html:\n{html}");
+        assert!(!html.contains("Usage text."), "unexpected html:\n{html}");
+        assert!(html.contains(r#"href="/tech/def456">Article › Setup</a>"#));
+        assert!(html.contains(r#"<a href="/tech/def456">Fallback</a>"#));
     }
 
     #[tokio::test]
     async fn test_render_resolves_escaped_piped_wikilinks_inside_tables() {
         let files = ClassifiedFiles {
-            articles: vec![parsed_article("notes/article", Category::Tech, "def456")],
+            articles: vec![parsed_article(
+                "notes/article",
+                Category::new("tech".to_string()).unwrap(),
+                "def456",
+            )],
             ..Default::default()
         };
         let link_index = links::Index::from_classified_files(&files);
@@ -86,7 +266,14 @@ mod tests {
             | [[article\|Cell link]] | ![[article\|Cell embed]] |
         "#};
 
-        let html = render(markdown, &link_index, &enrich).await;
+        let html = render(
+            "notes/article",
+            markdown,
+            &link_index,
+            &RawHtmlConfig::default(),
+            &enrich,
+        )
+        .await;
 
         assert!(html.starts_with("<table>"), "unexpected html:\n{html}");
         assert!(
@@ -94,7 +281,7 @@ mod tests {
             "unexpected html:\n{html}"
         );
         assert!(
-            html.contains(r#"<th><img src="/tech/def456" alt="Header embed" /></th>"#),
+            html.contains(r#"<th><div class="transclusion">"#),
             "unexpected html:\n{html}"
         );
         assert!(
@@ -102,23 +289,30 @@ mod tests {
             "unexpected html:\n{html}"
         );
         assert!(
-            html.contains(r#"<td><img src="/tech/def456" alt="Cell embed" /></td>"#),
+            html.contains(r#"<td><div class="transclusion">"#),
             "unexpected html:\n{html}"
         );
+        assert!(!html.contains("<img"), "unexpected html:\n{html}");
     }
 
     #[tokio::test]
     async fn test_render_escapes_wikilink_text_and_destination() {
         let files = ClassifiedFiles {
-            articles: vec![parsed_article("notes/article", Category::Tech, "def456")],
+            articles: vec![parsed_article(
+                "notes/article",
+                Category::new("tech".to_string()).unwrap(),
+                "def456",
+            )],
             ..Default::default()
         };
         let link_index = links::Index::from_classified_files(&files);
         let enrich = passthrough_bookmark_enricher();
 
         let html = render(
+            "notes/host",
             "[[article|Display & <script>]] and [[File \"quoted\"|missing]]",
             &link_index,
+            &RawHtmlConfig::default(),
             &enrich,
         )
         .await;
@@ -127,29 +321,22 @@ mod tests {
         assert!(html.contains(r#"<a href="/File%20%22quoted%22">missing</a>"#));
     }
 
-    fn passthrough_bookmark_enricher() -> BookmarkEnricher {
-        Arc::new(|html| Box::pin(async move { html }))
-    }
-
     fn parsed_article(source_key: &str, category: Category, slug: &str) -> ParsedArticleFile {
         ParsedArticleFile {
-            category,
+            category: category.clone(),
             slug: Slug::new(slug.to_string()).unwrap(),
             source_key: source_key.to_string(),
             section_path: SectionPath::default(),
+            aliases: vec![],
+            series: None,
+            publish_at: None,
             markdown_body: String::new(),
             front_matter: ObsidianFrontMatter {
                 title: "Article".to_string(),
-                kind: ContentKind::Article,
-                tags: None,
-                summary: None,
-                is_completed: true,
-                priority: None,
-                created: "2025-01-01T00:00:00+09:00".to_string(),
-                updated: "2025-01-01T00:00:00+09:00".to_string(),
                 category: Some(category.as_str().to_string()),
-                page: None,
+                ..ObsidianFrontMatter::for_test(ContentKind::Article)
             },
+            location: SourceLocation::default(),
         }
     }
 }
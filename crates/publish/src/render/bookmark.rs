@@ -22,6 +22,11 @@ static SIMPLE_BOOKMARK_RE: LazyLock<Regex> = LazyLock::new(|| {
 /// Async function that enriches page HTML with rich bookmark cards.
 pub type BookmarkEnricher = Arc<dyn Fn(String) -> BoxFuture<'static, String> + Send + Sync>;
 
+/// Leaves simple bookmarks untouched, for builds that must not reach the network.
+pub fn passthrough_bookmark_enricher() -> BookmarkEnricher {
+    Arc::new(|html: String| Box::pin(async move { html }))
+}
+
 pub(crate) fn rich_bookmark_enricher() -> BookmarkEnricher {
     let fetcher = match ogp::Fetcher::new() {
         Ok(fetcher) => Some(fetcher),
@@ -0,0 +1,525 @@
+use crate::classify::{ParsedArticleFile, ParsedCategoryFile, ParsedHomeFile, ParsedPageFile};
+use crate::config::RawHtmlConfig;
+use crate::error::Result;
+use crate::links;
+use crate::vault::ObsidianFrontMatter;
+use domain::{
+    HomeFragmentArtifactDocument, PageArtifactDocument, PublishableArticle,
+    PublishableCategoryLanding, TocEntry,
+};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::{
+    collections::{HashMap, HashSet},
+    ffi::OsString,
+    fs,
+    hash::{Hash, Hasher},
+    path::{Path, PathBuf},
+    sync::atomic::{AtomicUsize, Ordering},
+    time::UNIX_EPOCH,
+};
+use tracing::warn;
+
+/// Rendered notes reused while nothing a note's render reads changes.
+///
+/// Whole renders stay in memory between watch rebuilds. A cache opened on a directory also keeps
+/// each note's enriched body there under the same fingerprint, so later runs skip rendering and
+/// bookmark enrichment as well.
+#[derive(Default)]
+pub(crate) struct RenderCache {
+    pub(crate) articles: NoteCache<PublishableArticle>,
+    pub(crate) pages: NoteCache<PageArtifactDocument>,
+    pub(crate) home: NoteCache<HomeFragmentArtifactDocument>,
+    pub(crate) categories: NoteCache<PublishableCategoryLanding>,
+    pub(crate) bodies: BodyStore,
+}
+
+/// Renders of one content kind, keyed by source key.
+pub(crate) struct NoteCache<T> {
+    entries: HashMap<String, CachedRender<T>>,
+}
+
+struct CachedRender<T> {
+    fingerprint: String,
+    rendered: T,
+}
+
+/// What a fresh render of a note is cached under.
+#[derive(Clone)]
+pub(crate) struct CacheKey {
+    source_key: String,
+    fingerprint: String,
+}
+
+/// Enriched note bodies kept on disk between runs, when the cache was opened on a directory.
+#[derive(Default)]
+pub(crate) struct BodyStore {
+    disk: Option<Disk>,
+    hit_count: AtomicUsize,
+    miss_count: AtomicUsize,
+}
+
+struct Disk {
+    dir: PathBuf,
+    /// Identity of the renderer, enricher and raw HTML allowlist every stored body came from.
+    renderer: String,
+}
+
+/// Enriched HTML and outline of a note body, as stored on disk.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct RenderedBody {
+    pub(crate) html: String,
+    pub(crate) toc: Vec<TocEntry>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct StoredBody {
+    renderer: String,
+    fingerprint: String,
+    body: RenderedBody,
+}
+
+/// The stored body a note's render may reuse or replace; the default stores nothing.
+#[derive(Default)]
+pub(crate) struct BodyEntry<'a>(Option<(&'a BodyStore, CacheKey)>);
+
+impl RenderCache {
+    /// Opens a cache that keeps bodies enriched by the enricher named `enricher` in `dir`.
+    ///
+    /// Without an identifiable `publish` binary there is no safe key, so bodies stay in memory.
+    pub(crate) fn open(dir: &Path, enricher: &str, raw_html: &RawHtmlConfig) -> Result<Self> {
+        let Some(binary) = binary_identity() else {
+            warn!("cannot identify the publish binary; build cache disabled");
+            return Ok(Self::default());
+        };
+        fs::create_dir_all(dir)?;
+        let mut hasher = StableHasher::default();
+        (binary, enricher, raw_html).hash(&mut hasher);
+        Ok(Self {
+            bodies: BodyStore {
+                disk: Some(Disk {
+                    dir: dir.to_path_buf(),
+                    renderer: hasher.hex_digest(),
+                }),
+                ..BodyStore::default()
+            },
+            ..Self::default()
+        })
+    }
+
+    /// Deletes the stored bodies of notes no longer published, once a run has rendered the rest.
+    pub(crate) fn prune(&self) -> Result<()> {
+        let Some(disk) = &self.bodies.disk else {
+            return Ok(());
+        };
+        let kept: HashSet<_> = self
+            .articles
+            .source_keys()
+            .chain(self.pages.source_keys())
+            .chain(self.home.source_keys())
+            .chain(self.categories.source_keys())
+            .map(|source_key| disk.entry_path(source_key))
+            .collect();
+        for entry in fs::read_dir(&disk.dir)? {
+            let path = entry?.path();
+            // Only entry names are ever removed, so a shared directory keeps its other files.
+            if is_entry_path(&path) && !kept.contains(&path) {
+                fs::remove_file(path)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<T> Default for NoteCache<T> {
+    fn default() -> Self {
+        Self {
+            entries: HashMap::new(),
+        }
+    }
+}
+
+impl<T: Clone> NoteCache<T> {
+    /// Splits notes into reusable renders and notes to render again, forgetting notes that are gone.
+    ///
+    /// Notes and attachments referenced by reused renders are recorded in `link_index` as
+    /// rendering would.
+    pub(crate) fn split<N: CachedNote>(
+        &mut self,
+        notes: impl IntoIterator<Item = N>,
+        link_index: &links::Index,
+    ) -> (Vec<T>, Vec<(N, CacheKey)>) {
+        let mut reused = Vec::new();
+        let mut stale = Vec::new();
+        let mut present = HashSet::new();
+        for note in notes {
+            let source_key = note.source_key().to_string();
+            let fingerprint = fingerprint(&note, link_index);
+            present.insert(source_key.clone());
+            match self.entries.get(&source_key) {
+                Some(cached) if cached.fingerprint == fingerprint => {
+                    link_index.record_references(note.source_key(), note.markdown_body());
+                    reused.push(cached.rendered.clone());
+                }
+                _ => stale.push((
+                    note,
+                    CacheKey {
+                        source_key,
+                        fingerprint,
+                    },
+                )),
+            }
+        }
+        self.entries
+            .retain(|source_key, _| present.contains(source_key));
+        (reused, stale)
+    }
+
+    /// Keeps a fresh render of a note returned by [`NoteCache::split`].
+    pub(crate) fn insert(&mut self, key: CacheKey, rendered: T) {
+        self.entries.insert(
+            key.source_key,
+            CachedRender {
+                fingerprint: key.fingerprint,
+                rendered,
+            },
+        );
+    }
+
+    fn source_keys(&self) -> impl Iterator<Item = &str> {
+        self.entries.keys().map(String::as_str)
+    }
+}
+
+impl BodyStore {
+    /// Hits and misses since the last call, so each run reports its own.
+    pub(crate) fn take_counts(&self) -> (usize, usize) {
+        (
+            self.hit_count.swap(0, Ordering::Relaxed),
+            self.miss_count.swap(0, Ordering::Relaxed),
+        )
+    }
+
+    /// Where the render of a note split as stale under `key` finds and keeps its body.
+    pub(crate) fn entry(&self, key: CacheKey) -> BodyEntry<'_> {
+        BodyEntry(Some((self, key)))
+    }
+
+    fn get(&self, key: &CacheKey) -> Option<RenderedBody> {
+        let disk = self.disk.as_ref()?;
+        let stored = fs::read(disk.entry_path(&key.source_key))
+            .ok()
+            .and_then(|json| serde_json::from_slice::<StoredBody>(&json).ok())
+            .filter(|stored| {
+                stored.renderer == disk.renderer && stored.fingerprint == key.fingerprint
+            });
+        let counter = match stored {
+            Some(_) => &self.hit_count,
+            None => &self.miss_count,
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        stored.map(|stored| stored.body)
+    }
+
+    // A failed write only costs the next run a render.
+    fn put(&self, key: &CacheKey, body: &RenderedBody) {
+        let Some(disk) = &self.disk else {
+            return;
+        };
+        let path = disk.entry_path(&key.source_key);
+        let stored = StoredBody {
+            renderer: disk.renderer.clone(),
+            fingerprint: key.fingerprint.clone(),
+            body: body.clone(),
+        };
+        if let Err(error) = write_entry(&path, &stored) {
+            warn!(%error, path = %path.display(), "failed to write build cache entry");
+        }
+    }
+}
+
+impl BodyEntry<'_> {
+    /// The stored body, if it was rendered from the same inputs.
+    pub(crate) fn get(&self) -> Option<RenderedBody> {
+        let (store, key) = self.0.as_ref()?;
+        store.get(key)
+    }
+
+    /// Stores a fresh render for later runs.
+    pub(crate) fn put(&self, body: &RenderedBody) {
+        if let Some((store, key)) = &self.0 {
+            store.put(key, body);
+        }
+    }
+}
+
+impl Disk {
+    // Source keys contain directories, so entries are named by their hash instead.
+    fn entry_path(&self, source_key: &str) -> PathBuf {
+        let name = hex(&Sha256::digest(source_key.as_bytes()));
+        self.dir.join(format!("{name}.json"))
+    }
+}
+
+fn is_entry_path(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|extension| extension == "json")
+        && path.file_stem().is_some_and(|stem| {
+            stem.len() == 64
+                && stem
+                    .to_str()
+                    .is_some_and(|stem| stem.bytes().all(|byte| byte.is_ascii_hexdigit()))
+        })
+}
+
+fn write_entry(path: &Path, stored: &StoredBody) -> Result<()> {
+    let mut temp_path = OsString::from(path.as_os_str());
+    temp_path.push(".tmp");
+    fs::write(&temp_path, serde_json::to_vec(stored)?)?;
+    fs::rename(temp_path, path)?;
+    Ok(())
+}
+
+// A rebuilt binary may render differently, so its size and build time are part of every key.
+fn binary_identity() -> Option<String> {
+    let metadata = std::env::current_exe().ok()?.metadata().ok()?;
+    let built_at = metadata.modified().ok()?.duration_since(UNIX_EPOCH).ok()?;
+    Some(format!(
+        "{}:{}:{}",
+        env!("CARGO_PKG_VERSION"),
+        metadata.len(),
+        built_at.as_nanos()
+    ))
+}
+
+/// Classified note whose render depends only on its own source and what its links resolve to.
+pub(crate) trait CachedNote {
+    fn source_key(&self) -> &str;
+    fn front_matter(&self) -> &ObsidianFrontMatter;
+    fn markdown_body(&self) -> &str;
+}
+
+macro_rules! impl_cached_note {
+    ($($parsed_file:ty),*) => {$(
+        impl CachedNote for $parsed_file {
+            fn source_key(&self) -> &str {
+                &self.source_key
+            }
+
+            fn front_matter(&self) -> &ObsidianFrontMatter {
+                &self.front_matter
+            }
+
+            fn markdown_body(&self) -> &str {
+                &self.markdown_body
+            }
+        }
+    )*};
+}
+
+impl_cached_note!(
+    ParsedArticleFile,
+    ParsedPageFile,
+    ParsedHomeFile,
+    ParsedCategoryFile
+);
+
+// Everything else a classified note carries is derived from its source key and frontmatter.
+fn fingerprint(note: &impl CachedNote, link_index: &links::Index) -> String {
+    let mut hasher = StableHasher::default();
+    (note.source_key(), note.front_matter(), note.markdown_body()).hash(&mut hasher);
+    link_index.hash_dependencies(note.markdown_body(), &mut hasher);
+    hasher.hex_digest()
+}
+
+/// SHA-256 behind [`Hasher`], so fingerprints stay the same from one run to the next.
+#[derive(Default)]
+struct StableHasher(Sha256);
+
+impl StableHasher {
+    fn hex_digest(self) -> String {
+        hex(&self.0.finalize())
+    }
+}
+
+impl Hasher for StableHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        let digest = self.0.clone().finalize();
+        u64::from_le_bytes(
+            digest[..8]
+                .try_into()
+                .expect("SHA-256 is longer than 8 bytes"),
+        )
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .fold(String::with_capacity(bytes.len() * 2), |mut acc, byte| {
+            acc.push_str(&format!("{byte:02x}"));
+            acc
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::classify::{ClassifiedFiles, SourceLocation};
+    use crate::vault::ContentKind;
+    use domain::PageKey;
+    use tempfile::TempDir;
+
+    fn key(source_key: &str, fingerprint: &str) -> CacheKey {
+        CacheKey {
+            source_key: source_key.to_string(),
+            fingerprint: fingerprint.to_string(),
+        }
+    }
+
+    fn body(html: &str) -> RenderedBody {
+        RenderedBody {
+            html: html.to_string(),
+            toc: Vec::new(),
+        }
+    }
+
+    fn page(source_key: &str, markdown_body: &str) -> ParsedPageFile {
+        ParsedPageFile {
+            page: PageKey::new(source_key.to_string()).unwrap(),
+            source_key: source_key.to_string(),
+            markdown_body: markdown_body.to_string(),
+            front_matter: ObsidianFrontMatter {
+                title: source_key.to_string(),
+                page: Some(source_key.to_string()),
+                ..ObsidianFrontMatter::for_test(ContentKind::Page)
+            },
+            location: SourceLocation::default(),
+        }
+    }
+
+    /// Source keys `split` asks to render again after `pages` were all rendered once.
+    fn rerendered(before: Vec<ParsedPageFile>, after: Vec<ParsedPageFile>) -> Vec<String> {
+        let mut cache = NoteCache::default();
+        let files = ClassifiedFiles {
+            pages: before,
+            ..Default::default()
+        };
+        let index = links::Index::from_classified_files(&files);
+        for (_, key) in cache.split(files.pages, &index).1 {
+            cache.insert(key, ());
+        }
+
+        let files = ClassifiedFiles {
+            pages: after,
+            ..Default::default()
+        };
+        let index = links::Index::from_classified_files(&files);
+        let mut stale: Vec<_> = cache
+            .split(files.pages, &index)
+            .1
+            .into_iter()
+            .map(|(_, key)| key.source_key)
+            .collect();
+        stale.sort();
+        stale
+    }
+
+    #[test]
+    fn test_split_rerenders_changed_notes_and_their_dependents() {
+        let before = || {
+            vec![
+                page("about", "See [[links]]."),
+                page("links", "# Links"),
+                page("embeds", "![[quote]]"),
+                page("quote", "Original quote."),
+                page("draft", "[[missing]]"),
+            ]
+        };
+
+        assert!(rerendered(before(), before()).is_empty());
+
+        let mut after = before();
+        after[3] = page("quote", "Edited quote.");
+        assert_eq!(rerendered(before(), after), ["embeds", "quote"]);
+
+        // A heading only matters to notes linking to it, not to plain links.
+        let mut after = before();
+        after[1] = page("links", "# Renamed");
+        assert_eq!(rerendered(before(), after), ["links"]);
+
+        let mut after = before();
+        after.push(page("missing", "Now it exists."));
+        assert_eq!(rerendered(before(), after), ["draft", "missing"]);
+    }
+
+    #[test]
+    fn test_stored_bodies_are_reused_only_under_the_same_key() {
+        let temp_dir = TempDir::new().unwrap();
+        let raw_html = RawHtmlConfig::default();
+        let open =
+            |enricher, raw_html| RenderCache::open(temp_dir.path(), enricher, raw_html).unwrap();
+        let cache = open("offline", &raw_html);
+        let entry = |fingerprint| cache.bodies.entry(key("tech/note", fingerprint));
+
+        assert_eq!(entry("body").get(), None);
+        entry("body").put(&body("<p>Body</p>"));
+        assert_eq!(entry("body").get(), Some(body("<p>Body</p>")));
+        assert_eq!(entry("edited").get(), None);
+        assert_eq!(cache.bodies.take_counts(), (1, 2));
+        assert_eq!(cache.bodies.take_counts(), (0, 0));
+
+        let stored = |cache: RenderCache| cache.bodies.entry(key("tech/note", "body")).get();
+        assert_eq!(
+            stored(open("offline", &raw_html)),
+            Some(body("<p>Body</p>"))
+        );
+        assert_eq!(stored(open("rich", &raw_html)), None);
+        let mut narrower = RawHtmlConfig::default();
+        narrower.tags.remove("img");
+        assert_eq!(stored(open("offline", &narrower)), None);
+    }
+
+    #[test]
+    fn test_memory_only_cache_stores_and_counts_nothing() {
+        let cache = RenderCache::default();
+        let entry = cache.bodies.entry(key("note", "body"));
+
+        entry.put(&body("<p>Body</p>"));
+        assert_eq!(entry.get(), None);
+        assert_eq!(cache.bodies.take_counts(), (0, 0));
+    }
+
+    #[test]
+    fn test_prune_deletes_bodies_of_notes_no_longer_published() {
+        let temp_dir = TempDir::new().unwrap();
+        let mut cache =
+            RenderCache::open(temp_dir.path(), "offline", &RawHtmlConfig::default()).unwrap();
+        for source_key in ["index", "removed"] {
+            cache
+                .bodies
+                .entry(key(source_key, "body"))
+                .put(&body("<p>Body</p>"));
+        }
+        cache.home.insert(
+            key("index", "body"),
+            HomeFragmentArtifactDocument {
+                title: "Home".to_string(),
+                description: None,
+                html: "<p>Body</p>".to_string(),
+                updated_at: "2025-01-01T00:00:00+09:00".to_string(),
+            },
+        );
+        fs::write(temp_dir.path().join("notes.json"), "{}").unwrap();
+
+        cache.prune().unwrap();
+
+        assert!(cache.bodies.entry(key("index", "body")).get().is_some());
+        assert!(cache.bodies.entry(key("removed", "body")).get().is_none());
+        assert_eq!(fs::read_dir(temp_dir.path()).unwrap().count(), 2);
+    }
+}
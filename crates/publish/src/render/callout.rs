@@ -0,0 +1,248 @@
+use html_escape::encode_double_quoted_attribute;
+use pulldown_cmark::{CowStr, Event, Tag, TagEnd};
+use regex::Regex;
+use std::sync::LazyLock;
+
+static CALLOUT_MARKER_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^\[!([A-Za-z0-9_-]+)\]([+-])?(?:[ \t]+|$)")
+        .expect("Invalid callout marker regex pattern")
+});
+
+/// Obsidian callout parsed from the first line of a blockquote.
+struct Callout<'a> {
+    kind: String,
+    fold: Fold,
+    title: CowStr<'a>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Fold {
+    None,
+    Open,
+    Closed,
+}
+
+/// Renders Obsidian `> [!type]` blockquotes as admonition blocks.
+pub(super) fn events<'a>(events: Vec<Event<'a>>) -> Vec<Event<'a>> {
+    let mut result = Vec::with_capacity(events.len());
+    let mut closing_markup: Vec<Option<&'static str>> = Vec::new();
+    let mut events = events.into_iter().peekable();
+
+    while let Some(event) = events.next() {
+        match event {
+            Event::Start(Tag::BlockQuote(_)) => {
+                if !matches!(events.peek(), Some(Event::Start(Tag::Paragraph))) {
+                    closing_markup.push(None);
+                    result.push(event);
+                    continue;
+                }
+                let paragraph_start = events.next().expect("peeked paragraph start");
+
+                // pulldown-cmark splits `[!note]` into several text events.
+                let mut first_text = String::new();
+                let mut text_events = Vec::new();
+                while let Some(Event::Text(text)) = events.peek() {
+                    first_text.push_str(text);
+                    text_events.push(events.next().expect("peeked text event"));
+                }
+
+                let Some(callout) = parse_marker(&first_text) else {
+                    closing_markup.push(None);
+                    result.push(event);
+                    result.push(paragraph_start);
+                    result.extend(text_events);
+                    continue;
+                };
+
+                closing_markup.push(Some(callout.fold.closing_markup()));
+                push_callout_start(callout, &mut events, &mut result);
+            }
+            Event::End(TagEnd::BlockQuote(_)) => match closing_markup.pop().flatten() {
+                Some(markup) => result.push(Event::Html(markup.into())),
+                None => result.push(event),
+            },
+            event => result.push(event),
+        }
+    }
+
+    result
+}
+
+fn parse_marker(first_text: &str) -> Option<Callout<'static>> {
+    let captures = CALLOUT_MARKER_RE.captures(first_text)?;
+    let fold = match captures.get(2).map(|fold| fold.as_str()) {
+        Some("+") => Fold::Open,
+        Some(_) => Fold::Closed,
+        None => Fold::None,
+    };
+
+    Some(Callout {
+        kind: captures[1].to_ascii_lowercase(),
+        fold,
+        title: first_text[captures.get(0)?.end()..].to_string().into(),
+    })
+}
+
+// Emits the callout wrapper and title; the rest of the first paragraph becomes the content.
+fn push_callout_start<'a>(
+    callout: Callout<'a>,
+    events: &mut impl Iterator<Item = Event<'a>>,
+    result: &mut Vec<Event<'a>>,
+) {
+    let class = encode_double_quoted_attribute(&callout.kind).into_owned();
+    let (open_tag, title_tag) = match callout.fold {
+        Fold::None => (format!(r#"<aside class="callout callout-{class}">"#), "p"),
+        Fold::Open => (
+            format!(r#"<details class="callout callout-{class}" open>"#),
+            "summary",
+        ),
+        Fold::Closed => (
+            format!(r#"<details class="callout callout-{class}">"#),
+            "summary",
+        ),
+    };
+    result.push(Event::Html(
+        format!("{open_tag}\n<{title_tag} class=\"callout-title\">").into(),
+    ));
+
+    let mut title_events = Vec::new();
+    if !callout.title.trim().is_empty() {
+        title_events.push(Event::Text(callout.title));
+    }
+    let mut paragraph_continues = false;
+    for event in events.by_ref() {
+        match event {
+            Event::SoftBreak | Event::HardBreak => {
+                paragraph_continues = true;
+                break;
+            }
+            Event::End(TagEnd::Paragraph) => break,
+            event => title_events.push(event),
+        }
+    }
+    if title_events.is_empty() {
+        title_events.push(Event::Text(default_title(&callout.kind).into()));
+    }
+    result.extend(title_events);
+
+    result.push(Event::Html(
+        format!("</{title_tag}>\n<div class=\"callout-content\">\n").into(),
+    ));
+    if paragraph_continues {
+        result.push(Event::Start(Tag::Paragraph));
+    }
+}
+
+impl Fold {
+    fn closing_markup(self) -> &'static str {
+        match self {
+            Self::None => "</div>\n</aside>\n",
+            Self::Open | Self::Closed => "</div>\n</details>\n",
+        }
+    }
+}
+
+fn default_title(kind: &str) -> String {
+    let mut chars = kind.chars();
+    match chars.next() {
+        Some(first) => first
+            .to_uppercase()
+            .chain(chars)
+            .collect::<String>()
+            .replace(['-', '_'], " "),
+        None => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render::html::markdown_options;
+    use indoc::indoc;
+    use pulldown_cmark::{Parser, html};
+    use rstest::rstest;
+
+    fn render(markdown: &str) -> String {
+        let parser = Parser::new_ext(markdown, markdown_options());
+        let mut output = String::new();
+        html::push_html(&mut output, events(parser.collect()).into_iter());
+        output
+    }
+
+    #[test]
+    fn test_callout_with_default_title() {
+        let html = render("> [!note]\n> Body text.");
+
+        assert_eq!(
+            html,
+            indoc! {r#"
+                <aside class="callout callout-note">
+                <p class="callout-title">Note</p>
+                <div class="callout-content">
+                <p>Body text.</p>
+                </div>
+                </aside>
+            "#}
+        );
+    }
+
+    #[test]
+    fn test_callout_with_inline_title_and_blocks() {
+        let html = render("> [!TIP] Use **this**\n> First line\n>\n> - item");
+
+        assert_eq!(
+            html,
+            indoc! {r#"
+                <aside class="callout callout-tip">
+                <p class="callout-title">Use <strong>this</strong></p>
+                <div class="callout-content">
+                <p>First line</p>
+                <ul>
+                <li>item</li>
+                </ul>
+                </div>
+                </aside>
+            "#}
+        );
+    }
+
+    #[rstest]
+    #[case::collapsed(
+        "> [!warning]- Careful\n> Hidden",
+        r#"<details class="callout callout-warning">"#
+    )]
+    #[case::expanded(
+        "> [!faq]+ Question\n> Shown",
+        r#"<details class="callout callout-faq" open>"#
+    )]
+    fn test_foldable_callouts_use_details(#[case] markdown: &str, #[case] opening: &str) {
+        let html = render(markdown);
+
+        assert!(html.starts_with(opening), "unexpected html:\n{html}");
+        assert!(html.contains(r#"<summary class="callout-title">"#));
+        assert!(
+            html.ends_with("</div>\n</details>\n"),
+            "unexpected html:\n{html}"
+        );
+    }
+
+    #[test]
+    fn test_nested_callouts_and_plain_blockquotes() {
+        let html = render("> [!my-custom] Outer\n> > [!info]\n> > Inner\n\n> Plain quote");
+
+        assert!(html.contains(r#"<aside class="callout callout-my-custom">"#));
+        assert!(html.contains(r#"<aside class="callout callout-info">"#));
+        assert!(html.contains("<p>Inner</p>\n</div>\n</aside>\n</div>\n</aside>\n"));
+        assert!(html.contains("<blockquote>\n<p>Plain quote</p>\n</blockquote>"));
+    }
+
+    #[rstest]
+    #[case::not_a_marker("> [not] a callout")]
+    #[case::marker_without_separator("> [!note]text")]
+    fn test_non_callout_blockquotes_are_unchanged(#[case] markdown: &str) {
+        let html = render(markdown);
+
+        assert!(html.starts_with("<blockquote>"), "unexpected html:\n{html}");
+        assert!(!html.contains(r#"class="callout"#));
+    }
+}
@@ -0,0 +1,132 @@
+use super::html::markdown_options;
+use pulldown_cmark::{Event, Parser, Tag};
+use std::{borrow::Cow, ops::Range};
+
+/// Removes Obsidian `%% comments %%` so private notes never reach published HTML.
+pub(crate) fn strip_comments(markdown: &str) -> Cow<'_, str> {
+    let comments = comment_ranges(markdown);
+    if comments.is_empty() {
+        return Cow::Borrowed(markdown);
+    }
+
+    let mut stripped = String::with_capacity(markdown.len());
+    let mut cursor = 0;
+    for comment in comments {
+        stripped.push_str(&markdown[cursor..comment.start]);
+        cursor = comment.end;
+    }
+    stripped.push_str(&markdown[cursor..]);
+
+    Cow::Owned(stripped)
+}
+
+/// Blanks out comments but keeps every byte offset and line break, for positioned diagnostics.
+pub(crate) fn mask_comments(markdown: &str) -> Cow<'_, str> {
+    let comments = comment_ranges(markdown);
+    if comments.is_empty() {
+        return Cow::Borrowed(markdown);
+    }
+
+    let mut masked = markdown.to_string().into_bytes();
+    for comment in comments {
+        for byte in &mut masked[comment] {
+            if *byte != b'\n' {
+                *byte = b' ';
+            }
+        }
+    }
+
+    Cow::Owned(String::from_utf8(masked).expect("masking with ASCII spaces keeps UTF-8 valid"))
+}
+
+fn comment_ranges(markdown: &str) -> Vec<Range<usize>> {
+    if !markdown.contains("%%") {
+        return Vec::new();
+    }
+
+    let literal_ranges = literal_ranges(markdown);
+    let mut comments = Vec::new();
+    let mut comment_start = None;
+
+    for (position, delimiter) in markdown.match_indices("%%") {
+        if literal_ranges.iter().any(|range| range.contains(&position)) {
+            continue;
+        }
+        match comment_start.take() {
+            Some(start) => comments.push(start..position + delimiter.len()),
+            None => comment_start = Some(position),
+        }
+    }
+
+    // Like Obsidian, an unclosed comment hides the rest of the note.
+    if let Some(start) = comment_start {
+        comments.push(start..markdown.len());
+    }
+
+    comments
+}
+
+// Code and math keep `%%` literally, e.g. LaTeX line comments.
+fn literal_ranges(markdown: &str) -> Vec<Range<usize>> {
+    Parser::new_ext(markdown, markdown_options())
+        .into_offset_iter()
+        .filter_map(|(event, range)| match event {
+            Event::Code(_)
+            | Event::InlineMath(_)
+            | Event::DisplayMath(_)
+            | Event::Start(Tag::CodeBlock(_)) => Some(range),
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case::inline("Visible %%private%% text", "Visible  text")]
+    #[case::block(
+        indoc! {"
+            Before
+
+            %%
+            private
+
+            - draft
+            %%
+
+            After
+        "},
+        "Before\n\n\n\nAfter\n"
+    )]
+    #[case::unclosed("Visible\n\n%% private to the end", "Visible\n\n")]
+    #[case::empty("Visible%%%%", "Visible")]
+    fn test_comments_are_removed(#[case] markdown: &str, #[case] expected: &str) {
+        assert_eq!(strip_comments(markdown), expected);
+    }
+
+    #[rstest]
+    #[case::inline_code("Use `%%x%%` literally")]
+    #[case::fenced_code("```tex\n%% comment\n```")]
+    #[case::math("$a %% b$ and $c %% d$")]
+    #[case::no_comment("100% sure")]
+    fn test_literal_percent_signs_are_kept(#[case] markdown: &str) {
+        assert_eq!(strip_comments(markdown), markdown);
+    }
+
+    #[test]
+    fn test_masked_comments_keep_offsets_and_line_breaks() {
+        let markdown = "前 %%秘密\nです%% [[link]] `%%x%%`";
+
+        let masked = mask_comments(markdown);
+
+        assert_eq!(masked.len(), markdown.len());
+        assert_eq!(masked.find("[[link]]"), markdown.find("[[link]]"));
+        assert!(!masked.contains("秘密"));
+        assert_eq!(masked.lines().count(), 2);
+        assert!(masked.ends_with("`%%x%%`"));
+    }
+}
@@ -1,42 +1,103 @@
-use super::{body, bookmark::BookmarkEnricher};
+use super::{
+    body,
+    bookmark::BookmarkEnricher,
+    cache::{BodyEntry, RenderedBody},
+    tag::collect_inline_tags,
+    text,
+};
 use crate::{
     classify::{ParsedArticleFile, ParsedCategoryFile, ParsedHomeFile, ParsedPageFile},
+    config::RawHtmlConfig,
     error::Result,
     links,
 };
 use domain::{
     ArticleBody, ArticleMeta, CategoryLandingBody, CategoryLandingMeta,
     HomeFragmentArtifactDocument, PageArtifactDocument, PublishableArticle,
-    PublishableCategoryLanding, Timestamp, Title,
+    PublishableCategoryLanding, TagSynonyms, Timestamp, Title,
 };
 
+#[tracing::instrument(skip_all, fields(source_key = %parsed_file.source_key))]
 pub(crate) async fn render_article(
     parsed_file: ParsedArticleFile,
     link_index: &links::Index,
+    raw_html: &RawHtmlConfig,
+    tag_synonyms: &TagSynonyms,
     enrich: BookmarkEnricher,
+    body_entry: BodyEntry<'_>,
 ) -> Result<PublishableArticle> {
-    let html = body::render(&parsed_file.markdown_body, link_index, &enrich).await;
+    let RenderedBody { html, toc } = body::render_cached(
+        &parsed_file.source_key,
+        &parsed_file.markdown_body,
+        true,
+        link_index,
+        raw_html,
+        &enrich,
+        body_entry,
+    )
+    .await;
+    // Front matter tags keep their order; inline `#tags` are appended once each.
+    let tags = tag_synonyms.canonical_tags(
+        parsed_file
+            .front_matter
+            .tags
+            .unwrap_or_default()
+            .into_iter()
+            .chain(collect_inline_tags(&parsed_file.markdown_body)),
+    )?;
+    let links = link_index.linked_articles(&parsed_file.source_key);
+    let reading_stats = text::reading_stats(&html);
+    // Only articles without a `summary` need the body to stand in for one.
+    let excerpt = parsed_file
+        .front_matter
+        .summary
+        .as_deref()
+        .is_none_or(|summary| summary.trim().is_empty())
+        .then(|| text::excerpt(&html))
+        .flatten();
     let meta = ArticleMeta {
         slug: parsed_file.slug,
         title: Title::new(parsed_file.front_matter.title)?,
         category: parsed_file.category,
         section_path: parsed_file.section_path,
         description: parsed_file.front_matter.summary,
-        tags: parsed_file.front_matter.tags.unwrap_or_default(),
+        tags,
         priority: parsed_file.front_matter.priority,
         created_at: Timestamp::new(parsed_file.front_matter.created)?,
         updated_at: Timestamp::new(parsed_file.front_matter.updated)?,
+        toc,
+        series: parsed_file.series,
+        publish_at: parsed_file.publish_at,
+        links,
+        // Ranked and collected once every article is rendered.
+        related: Vec::new(),
+        backlinks: Vec::new(),
+        reading_stats,
+        excerpt,
     };
     let body = ArticleBody::new(html)?;
     Ok(PublishableArticle::new(meta, body))
 }
 
+#[tracing::instrument(skip_all, fields(source_key = %parsed_file.source_key))]
 pub(crate) async fn render_category(
     parsed_file: ParsedCategoryFile,
     link_index: &links::Index,
+    raw_html: &RawHtmlConfig,
     enrich: BookmarkEnricher,
+    body_entry: BodyEntry<'_>,
 ) -> Result<PublishableCategoryLanding> {
-    let html = body::render(&parsed_file.markdown_body, link_index, &enrich).await;
+    let html = body::render_cached(
+        &parsed_file.source_key,
+        &parsed_file.markdown_body,
+        false,
+        link_index,
+        raw_html,
+        &enrich,
+        body_entry,
+    )
+    .await
+    .html;
     let meta = CategoryLandingMeta {
         category: parsed_file.category,
         title: Title::new(parsed_file.front_matter.title)?,
@@ -47,12 +108,25 @@ pub(crate) async fn render_category(
     Ok(PublishableCategoryLanding::new(meta, body))
 }
 
+#[tracing::instrument(skip_all, fields(source_key = %parsed_file.source_key))]
 pub(crate) async fn render_home(
     parsed_file: ParsedHomeFile,
     link_index: &links::Index,
+    raw_html: &RawHtmlConfig,
     enrich: BookmarkEnricher,
+    body_entry: BodyEntry<'_>,
 ) -> HomeFragmentArtifactDocument {
-    let html = body::render(&parsed_file.markdown_body, link_index, &enrich).await;
+    let html = body::render_cached(
+        &parsed_file.source_key,
+        &parsed_file.markdown_body,
+        false,
+        link_index,
+        raw_html,
+        &enrich,
+        body_entry,
+    )
+    .await
+    .html;
     HomeFragmentArtifactDocument {
         title: parsed_file.front_matter.title,
         description: parsed_file.front_matter.summary,
@@ -61,12 +135,25 @@ pub(crate) async fn render_home(
     }
 }
 
+#[tracing::instrument(skip_all, fields(source_key = %parsed_file.source_key))]
 pub(crate) async fn render_page(
     parsed_file: ParsedPageFile,
     link_index: &links::Index,
+    raw_html: &RawHtmlConfig,
     enrich: BookmarkEnricher,
+    body_entry: BodyEntry<'_>,
 ) -> PageArtifactDocument {
-    let html = body::render(&parsed_file.markdown_body, link_index, &enrich).await;
+    let html = body::render_cached(
+        &parsed_file.source_key,
+        &parsed_file.markdown_body,
+        false,
+        link_index,
+        raw_html,
+        &enrich,
+        body_entry,
+    )
+    .await
+    .html;
     PageArtifactDocument {
         page: parsed_file.page,
         title: parsed_file.front_matter.title,
@@ -0,0 +1,505 @@
+use super::comment::{mask_comments, strip_comments};
+use super::html::{markdown_options, render_markdown};
+use crate::classify::ClassifiedFiles;
+use crate::config::RawHtmlConfig;
+use crate::diagnostics::{Diagnostic, DiagnosticCode};
+use crate::error::PublishError;
+use crate::links::{self, EmbeddedNote, Index};
+use html_escape::{encode_double_quoted_attribute, encode_text};
+use indoc::formatdoc;
+use pulldown_cmark::{Event, HeadingLevel, LinkType, Parser, Tag, TagEnd};
+use std::{collections::HashSet, fmt, ops::Range};
+
+/// Note embed resolved from `![[note]]` or `![[note#Heading]]`.
+struct NoteEmbed<'a> {
+    note: EmbeddedNote<'a>,
+    heading: Option<String>,
+}
+
+/// Replaces note embeds with transclusions of the embedded note body or heading section.
+pub(super) fn expand_note_embeds<'a>(
+    events: Vec<Event<'a>>,
+    index: &Index,
+    raw_html: &RawHtmlConfig,
+    embed_stack: &mut Vec<String>,
+) -> Vec<Event<'a>> {
+    let mut result = Vec::with_capacity(events.len());
+    let mut events = events.into_iter().peekable();
+
+    while let Some(event) = events.next() {
+        let Some(embed) = note_embed(&event, index) else {
+            result.push(event);
+            continue;
+        };
+        let label = take_embed_label(&mut events);
+        let html = transclude(&embed, &label, index, raw_html, embed_stack);
+
+        // A standalone embed replaces its paragraph so the block is not nested inside `<p>`.
+        if matches!(result.last(), Some(Event::Start(Tag::Paragraph)))
+            && matches!(events.peek(), Some(Event::End(TagEnd::Paragraph)))
+        {
+            result.pop();
+            events.next();
+        }
+        result.push(Event::Html(html.into()));
+    }
+
+    result
+}
+
+/// Rejects notes whose embedded sections transclude themselves directly or through other notes.
+///
+/// A heading embed only depends on the embeds inside that heading's section, so two notes may
+/// embed sections of each other as long as neither section reaches back.
+pub(crate) fn ensure_acyclic_embeds(index: &Index) -> std::result::Result<(), EmbedCycle> {
+    let mut source_keys: Vec<_> = index.embedded_notes().map(|note| note.source_key).collect();
+    source_keys.sort_unstable();
+
+    let mut finished = HashSet::new();
+    for source_key in source_keys {
+        let section = Section {
+            source_key: source_key.to_string(),
+            heading: None,
+        };
+        visit_embeds(section, index, &mut finished, &mut Vec::new())?;
+    }
+
+    Ok(())
+}
+
+/// Sections that embed one another in a loop, starting and ending with the same section.
+#[derive(Debug)]
+pub(crate) struct EmbedCycle {
+    sections: Vec<Section>,
+}
+
+impl EmbedCycle {
+    /// One diagnostic per embed in the cycle, at the `![[…]]` in the note that embeds the next
+    /// section.
+    pub(crate) fn diagnostics(
+        &self,
+        index: &Index,
+        classified_files: &ClassifiedFiles,
+    ) -> Vec<Diagnostic> {
+        let message = PublishError::EmbedCycle {
+            cycle: self.to_string(),
+        }
+        .to_string();
+        self.sections
+            .windows(2)
+            .filter_map(|pair| {
+                let (markdown, location) = classified_files.note(&pair[0].source_key)?;
+                let diagnostic = Diagnostic::error(DiagnosticCode::EmbedCycle, message.clone())
+                    .in_file(&location.path);
+                Some(match pair[0].embed_offset(markdown, &pair[1], index) {
+                    Some(offset) => diagnostic.at_offset(markdown, location.body_line, offset),
+                    None => diagnostic,
+                })
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for EmbedCycle {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sections: Vec<_> = self.sections.iter().map(Section::to_string).collect();
+        formatter.write_str(&sections.join(" -> "))
+    }
+}
+
+impl From<EmbedCycle> for PublishError {
+    fn from(cycle: EmbedCycle) -> Self {
+        PublishError::EmbedCycle {
+            cycle: cycle.to_string(),
+        }
+    }
+}
+
+/// Whole note or heading section that an embed transcludes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Section {
+    source_key: String,
+    heading: Option<String>,
+}
+
+impl Section {
+    /// Sections embedded by this one, read from its comment-free Markdown.
+    fn embeds(&self, index: &Index) -> Vec<Section> {
+        let Some(note) = index.embedded_note(&self.source_key) else {
+            return Vec::new();
+        };
+        // A missing heading is published as a plain link, so it embeds nothing.
+        let Some(markdown) = (match self.heading.as_deref() {
+            Some(heading) => heading_section(note.markdown_body, heading),
+            None => Some(note.markdown_body),
+        }) else {
+            return Vec::new();
+        };
+
+        let markdown = strip_comments(markdown);
+        Parser::new_ext(&markdown, markdown_options())
+            .filter_map(|event| note_embed(&event, index))
+            .map(|embed| embed.section())
+            .collect()
+    }
+
+    /// Byte offset in this section's source `markdown` of the first embed of `target`.
+    fn embed_offset(&self, markdown: &str, target: &Section, index: &Index) -> Option<usize> {
+        let masked = mask_comments(markdown);
+        let range = match self.heading.as_deref() {
+            Some(heading) => heading_range(&masked, heading)?,
+            None => 0..masked.len(),
+        };
+        Parser::new_ext(&masked[range.clone()], markdown_options())
+            .into_offset_iter()
+            .find(|(event, _)| {
+                note_embed(event, index).is_some_and(|embed| embed.section() == *target)
+            })
+            .map(|(_, embed_range)| range.start + embed_range.start)
+    }
+}
+
+impl fmt::Display for Section {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.heading {
+            Some(heading) => write!(formatter, "{}#{heading}", self.source_key),
+            None => formatter.write_str(&self.source_key),
+        }
+    }
+}
+
+fn visit_embeds(
+    section: Section,
+    index: &Index,
+    finished: &mut HashSet<Section>,
+    path: &mut Vec<Section>,
+) -> std::result::Result<(), EmbedCycle> {
+    if let Some(position) = path.iter().position(|visited| *visited == section) {
+        let mut sections = path.split_off(position);
+        sections.push(section);
+        return Err(EmbedCycle { sections });
+    }
+    if finished.contains(&section) {
+        return Ok(());
+    }
+
+    let targets = section.embeds(index);
+    path.push(section);
+    for target in targets {
+        visit_embeds(target, index, finished, path)?;
+    }
+    let section = path.pop().expect("the visited section is on the path");
+    finished.insert(section);
+
+    Ok(())
+}
+
+fn note_embed<'a>(event: &Event<'_>, index: &'a Index) -> Option<NoteEmbed<'a>> {
+    let Event::Start(Tag::Image {
+        link_type: LinkType::WikiLink { has_pothole },
+        dest_url,
+        ..
+    }) = event
+    else {
+        return None;
+    };
+
+    let target = links::wikilink_target(dest_url, *has_pothole);
+    let (note_target, heading) = match target.split_once('#') {
+        Some((note_target, heading)) => (note_target.trim(), Some(heading.trim())),
+        None => (target, None),
+    };
+
+    Some(NoteEmbed {
+        note: index.embedded_note(note_target)?,
+        heading: heading.map(str::to_string),
+    })
+}
+
+impl NoteEmbed<'_> {
+    fn section(&self) -> Section {
+        Section {
+            source_key: self.note.source_key.to_string(),
+            heading: self.heading.clone(),
+        }
+    }
+}
+
+// Consumes the embed alt text so it is not rendered next to the transclusion.
+fn take_embed_label<'a>(events: &mut impl Iterator<Item = Event<'a>>) -> String {
+    let mut label = String::new();
+    let mut depth = 0;
+
+    for event in events {
+        match event {
+            Event::Start(_) => depth += 1,
+            Event::End(TagEnd::Image) if depth == 0 => break,
+            Event::End(_) => depth -= 1,
+            Event::Text(text) | Event::Code(text) => label.push_str(&text),
+            _ => {}
+        }
+    }
+
+    label
+}
+
+fn transclude(
+    embed: &NoteEmbed<'_>,
+    label: &str,
+    index: &Index,
+    raw_html: &RawHtmlConfig,
+    embed_stack: &mut Vec<String>,
+) -> String {
+    let note = &embed.note;
+    let section = embed.section().to_string();
+    // Cycles are rejected before rendering; this only keeps recursion bounded.
+    if embed_stack.contains(&section) {
+        return embed_link(note.href, label);
+    }
+
+    let (markdown, title) = match embed.heading.as_deref() {
+        Some(heading) => match heading_section(note.markdown_body, heading) {
+            Some(section) => (section, format!("{} › {heading}", note.title)),
+            None => {
+                tracing::debug!(
+                    source_key = note.source_key,
+                    %heading,
+                    "embedded heading was not found"
+                );
+                return embed_link(note.href, label);
+            }
+        },
+        None => (note.markdown_body, note.title.to_string()),
+    };
+
+    embed_stack.push(section);
+    // Embedded headings stay out of the host note's outline.
+    let (body, _) = render_markdown(markdown, None, index, raw_html, embed_stack);
+    embed_stack.pop();
+
+    formatdoc! {r#"
+        <div class="transclusion">
+        <div class="transclusion-body">
+        {body}</div>
+        <a class="transclusion-source" href="{href}">{title}</a>
+        </div>
+        "#,
+        href = encode_double_quoted_attribute(note.href),
+        title = encode_text(&title),
+    }
+}
+
+fn embed_link(href: &str, label: &str) -> String {
+    format!(
+        r#"<a href="{}">{}</a>"#,
+        encode_double_quoted_attribute(href),
+        encode_text(label)
+    )
+}
+
+/// Markdown from a heading up to the next heading of the same or a higher level.
+fn heading_section<'a>(markdown: &'a str, heading: &str) -> Option<&'a str> {
+    heading_range(markdown, heading).map(|range| &markdown[range])
+}
+
+/// Byte range of [`heading_section`] in `markdown`.
+fn heading_range(markdown: &str, heading: &str) -> Option<Range<usize>> {
+    let mut current_heading: Option<(HeadingLevel, usize, String)> = None;
+    let mut section: Option<(HeadingLevel, usize)> = None;
+
+    for (event, range) in Parser::new_ext(markdown, markdown_options()).into_offset_iter() {
+        match event {
+            Event::Start(Tag::Heading { level, .. }) => {
+                if let Some((section_level, start)) = section
+                    && level <= section_level
+                {
+                    return Some(start..range.start);
+                }
+                current_heading = Some((level, range.start, String::new()));
+            }
+            Event::Text(text) | Event::Code(text) => {
+                if let Some((_, _, heading_text)) = &mut current_heading {
+                    heading_text.push_str(&text);
+                }
+            }
+            Event::End(TagEnd::Heading(_)) => {
+                if let Some((level, start, heading_text)) = current_heading.take()
+                    && section.is_none()
+                    && heading_text.trim() == heading
+                {
+                    section = Some((level, start));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    section.map(|(_, start)| start..markdown.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::classify::{ClassifiedFiles, ParsedArticleFile, SourceLocation};
+    use crate::vault::{ContentKind, ObsidianFrontMatter};
+    use domain::{Category, SectionPath, Slug};
+    use std::path::PathBuf;
+
+    fn index(notes: &[(&str, &str, &str)]) -> Index {
+        let files = ClassifiedFiles {
+            articles: notes
+                .iter()
+                .map(|(source_key, slug, markdown_body)| article(source_key, slug, markdown_body))
+                .collect(),
+            ..Default::default()
+        };
+        Index::from_classified_files(&files)
+    }
+
+    fn article(source_key: &str, slug: &str, markdown_body: &str) -> ParsedArticleFile {
+        ParsedArticleFile {
+            category: Category::new("tech".to_string()).unwrap(),
+            slug: Slug::new(slug.to_string()).unwrap(),
+            source_key: source_key.to_string(),
+            section_path: SectionPath::default(),
+            aliases: vec![],
+            series: None,
+            publish_at: None,
+            markdown_body: markdown_body.to_string(),
+            front_matter: ObsidianFrontMatter {
+                title: source_key.to_string(),
+                category: Some("tech".to_string()),
+                ..ObsidianFrontMatter::for_test(ContentKind::Article)
+            },
+            location: SourceLocation::default(),
+        }
+    }
+
+    #[test]
+    fn test_nested_note_embeds_are_expanded() {
+        let index = index(&[
+            ("outer", "outer", "![[middle]]"),
+            ("middle", "middle", "Middle ![[inner]]"),
+            ("inner", "inner", "Inner text"),
+        ]);
+
+        ensure_acyclic_embeds(&index).unwrap();
+        let (html, _) = render_markdown(
+            "![[outer]]",
+            None,
+            &index,
+            &RawHtmlConfig::default(),
+            &mut Vec::new(),
+        );
+
+        assert_eq!(html.matches(r#"<div class="transclusion">"#).count(), 3);
+        assert!(
+            html.contains("<p>Inner text</p>"),
+            "unexpected html:\n{html}"
+        );
+    }
+
+    #[test]
+    fn test_recursive_note_embeds_are_rejected() {
+        let index = index(&[
+            ("first", "first", "![[second]]"),
+            ("second", "second", "![[third#Heading]]"),
+            ("third", "third", "# Heading\n\n![[first]]"),
+        ]);
+
+        let cycle = ensure_acyclic_embeds(&index).unwrap_err();
+
+        assert_eq!(
+            cycle.to_string(),
+            "first -> second -> third#Heading -> first"
+        );
+    }
+
+    #[test]
+    fn test_embed_cycle_is_reported_at_each_embed() {
+        let mut files = ClassifiedFiles::default();
+        for (source_key, markdown_body) in [
+            ("first", "Intro\n\nText %% ![[second]] %% then ![[second]]"),
+            ("second", "# Heading\n\n![[first]]"),
+        ] {
+            let mut file = article(source_key, source_key, markdown_body);
+            file.location = SourceLocation {
+                path: PathBuf::from(format!("vault/tech/{source_key}.md")),
+                body_line: 8,
+                ..SourceLocation::default()
+            };
+            files.articles.push(file);
+        }
+        let index = Index::from_classified_files(&files);
+
+        let diagnostics = ensure_acyclic_embeds(&index)
+            .unwrap_err()
+            .diagnostics(&index, &files);
+
+        assert_eq!(
+            diagnostics
+                .iter()
+                .map(|diagnostic| (
+                    diagnostic.file.as_deref(),
+                    diagnostic.line,
+                    diagnostic.column
+                ))
+                .collect::<Vec<_>>(),
+            [
+                (Some("vault/tech/first.md"), Some(10), Some(29)),
+                (Some("vault/tech/second.md"), Some(10), Some(1)),
+            ]
+        );
+        assert!(diagnostics.iter().all(
+            |diagnostic| diagnostic.message == "recursive note embed: first -> second -> first"
+        ));
+    }
+
+    #[test]
+    fn test_mutual_section_embeds_are_not_cycles() {
+        let index = index(&[
+            ("a", "a", "# X\n\n![[b#Y]]\n\n# Z\n\nA own text."),
+            ("b", "b", "# Y\n\nB own text.\n\n# W\n\n![[a#Z]]"),
+        ]);
+
+        ensure_acyclic_embeds(&index).unwrap();
+        let (html, _) = render_markdown(
+            "![[a]]",
+            None,
+            &index,
+            &RawHtmlConfig::default(),
+            &mut Vec::new(),
+        );
+
+        assert!(html.contains("B own text."), "unexpected html:\n{html}");
+    }
+
+    #[test]
+    fn test_embeds_inside_comments_are_not_cycles() {
+        let index = index(&[
+            ("first", "first", "![[second]]"),
+            ("second", "second", "Second %% ![[first]] %%"),
+        ]);
+
+        ensure_acyclic_embeds(&index).unwrap();
+    }
+
+    #[test]
+    fn test_self_embed_is_rejected() {
+        let index = index(&[("note", "note", "![[note]]")]);
+
+        assert!(ensure_acyclic_embeds(&index).is_err());
+    }
+
+    #[test]
+    fn test_heading_section_stops_at_same_level_heading() {
+        let markdown = "# A\n\n## B\n\nb\n\n### C\n\nc\n\n## D\n\nd\n";
+
+        assert_eq!(
+            heading_section(markdown, "B"),
+            Some("## B\n\nb\n\n### C\n\nc\n\n")
+        );
+        assert_eq!(heading_section(markdown, "D"), Some("## D\n\nd\n"));
+        assert_eq!(heading_section(markdown, "Missing"), None);
+    }
+}
@@ -0,0 +1,252 @@
+use html_escape::{encode_double_quoted_attribute, encode_text};
+use pulldown_cmark::{CodeBlockKind, Event, Tag, TagEnd};
+use std::sync::LazyLock;
+#[cfg(test)]
+use syntect::highlighting::{Color, FontStyle, ScopeSelectors, StyleModifier, Theme, ThemeItem};
+use syntect::{
+    html::{ClassStyle, ClassedHTMLGenerator},
+    parsing::{SyntaxReference, SyntaxSet},
+    util::LinesWithEndings,
+};
+
+/// Grammar scopes become `hl-` classes, so they never collide with the site's own classes.
+const CLASS_STYLE: ClassStyle = ClassStyle::SpacedPrefixed { prefix: "hl-" };
+
+/// Sublime Text grammars bundled with syntect, plus TOML, TypeScript and others from bat.
+static SYNTAXES: LazyLock<SyntaxSet> = LazyLock::new(two_face::syntax::extra_newlines);
+
+/// Colours for each group of TextMate scopes; `crates/site/web/style/highlight.css` is generated
+/// from this by the `regenerate_highlight_css` test.
+#[cfg(test)]
+const THEME: &[(&str, u32, FontStyle)] = &[
+    ("comment", 0x8c7b6e, FontStyle::ITALIC),
+    ("keyword, storage", 0xe0a86f, FontStyle::empty()),
+    (
+        "constant.language, constant.numeric, constant.character",
+        0xd3869b,
+        FontStyle::empty(),
+    ),
+    ("string", 0xa9c08a, FontStyle::empty()),
+    (
+        "entity.name.type, entity.name.class, support.type, support.class",
+        0xe8c97a,
+        FontStyle::empty(),
+    ),
+    (
+        "entity.name.function, support.function",
+        0x8fc1c9,
+        FontStyle::empty(),
+    ),
+    ("support.macro", 0xc5a3d9, FontStyle::empty()),
+    (
+        "variable, entity.name.tag, meta.mapping.key, support.type.property-name",
+        0x9fb4d8,
+        FontStyle::empty(),
+    ),
+];
+
+/// Highlights fenced code blocks in languages the bundled grammars know at build time.
+pub(super) fn events<'a>(events: Vec<Event<'a>>) -> Vec<Event<'a>> {
+    let mut result = Vec::with_capacity(events.len());
+    let mut events = events.into_iter();
+
+    while let Some(event) = events.next() {
+        let Event::Start(Tag::CodeBlock(CodeBlockKind::Fenced(info))) = &event else {
+            result.push(event);
+            continue;
+        };
+        let name = info.split_whitespace().next().unwrap_or_default();
+        let Some(syntax) = find_syntax(name) else {
+            result.push(event);
+            continue;
+        };
+        let name = name.to_string();
+
+        let mut code = String::new();
+        for event in events.by_ref() {
+            match event {
+                Event::Text(text) => code.push_str(&text),
+                Event::End(TagEnd::CodeBlock) => break,
+                _ => {}
+            }
+        }
+
+        let html = highlight(&code, syntax).unwrap_or_else(|error| {
+            tracing::warn!(%error, language = %name, "failed to highlight code block");
+            encode_text(&code).into_owned()
+        });
+        result.push(Event::Html(
+            format!(
+                "<pre><code class=\"language-{}\">{html}</code></pre>\n",
+                encode_double_quoted_attribute(&name),
+            )
+            .into(),
+        ));
+    }
+
+    result
+}
+
+/// Stylesheet for the classes emitted by build-time syntax highlighting.
+#[cfg(test)]
+pub(crate) fn highlight_theme_css() -> String {
+    let css = syntect::html::css_for_theme_with_class_style(&theme(), CLASS_STYLE)
+        .expect("the highlight theme should only use plain scope selectors");
+    format!("/* Generated from publish::render::highlight::THEME. Do not edit by hand. */\n{css}")
+}
+
+#[cfg(test)]
+fn theme() -> Theme {
+    let scopes = THEME
+        .iter()
+        .map(|&(scopes, rgb, font_style)| {
+            let [_, r, g, b] = rgb.to_be_bytes();
+            ThemeItem {
+                scope: scopes
+                    .parse::<ScopeSelectors>()
+                    .expect("the highlight theme scopes should parse"),
+                style: StyleModifier {
+                    foreground: Some(Color { r, g, b, a: 0xff }),
+                    background: None,
+                    font_style: Some(font_style),
+                },
+            }
+        })
+        .collect();
+    Theme {
+        name: Some("okawak".to_string()),
+        scopes,
+        ..Theme::default()
+    }
+}
+
+fn find_syntax(name: &str) -> Option<&'static SyntaxReference> {
+    if name.is_empty() {
+        return None;
+    }
+    SYNTAXES.find_syntax_by_token(name)
+}
+
+fn highlight(code: &str, syntax: &SyntaxReference) -> Result<String, syntect::Error> {
+    let mut generator = ClassedHTMLGenerator::new_with_class_style(syntax, &SYNTAXES, CLASS_STYLE);
+    for line in LinesWithEndings::from(code) {
+        generator.parse_html_for_line_which_includes_newline(line)?;
+    }
+    Ok(generator.finalize())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render::html::markdown_options;
+    use indoc::indoc;
+    use pulldown_cmark::{Parser, html};
+    use rstest::rstest;
+    use std::{fs, path::Path};
+
+    fn render(markdown: &str) -> String {
+        let parser = Parser::new_ext(markdown, markdown_options());
+        let mut output = String::new();
+        html::push_html(&mut output, events(parser.collect()).into_iter());
+        output
+    }
+
+    fn stylesheet_path() -> std::path::PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("../site/web/style/highlight.css")
+    }
+
+    #[test]
+    fn test_rust_code_block_is_highlighted() {
+        let html = render("```rust\nfn main() {\n    println!(\"<Hi>\");\n}\n```");
+
+        assert!(
+            html.starts_with(
+                r#"<pre><code class="language-rust"><span class="hl-source hl-rust">"#
+            ),
+            "unexpected html:\n{html}"
+        );
+        assert!(html.contains(r#"<span class="hl-storage hl-type hl-function hl-rust">fn</span>"#));
+        assert!(
+            html.contains(r#"<span class="hl-entity hl-name hl-function hl-rust">main</span>"#)
+        );
+        assert!(html.contains(r#"<span class="hl-support hl-macro hl-rust">println!</span>"#));
+        assert!(html.contains("&lt;Hi&gt;"), "unexpected html:\n{html}");
+        assert!(html.ends_with("</span></code></pre>\n"));
+    }
+
+    #[rstest]
+    #[case::unknown_language(
+        "```mermaid\ngraph TD\n```",
+        "<pre><code class=\"language-mermaid\">graph TD\n</code></pre>\n"
+    )]
+    #[case::no_language("```\nfn main() {}\n```", "<pre><code>fn main() {}\n</code></pre>\n")]
+    #[case::indented("    fn main() {}", "<pre><code>fn main() {}</code></pre>\n")]
+    fn test_unsupported_code_blocks_are_unchanged(#[case] markdown: &str, #[case] expected: &str) {
+        assert_eq!(render(markdown), expected);
+    }
+
+    #[rstest]
+    #[case::rust("rs", "hl-source hl-rust")]
+    #[case::python("py", "hl-source hl-python")]
+    #[case::typescript("ts", "hl-source hl-ts")]
+    #[case::bash("bash", "hl-source hl-shell hl-bash")]
+    #[case::toml("toml", "hl-source hl-toml")]
+    #[case::yaml("yaml", "hl-source hl-yaml")]
+    #[case::json("json", "hl-source hl-json")]
+    #[case::go("Go", "hl-source hl-go")]
+    fn test_language_names_and_extensions_are_recognized(
+        #[case] language: &str,
+        #[case] root_class: &str,
+    ) {
+        let html = render(&format!("```{language}\nx\n```"));
+
+        assert!(
+            html.contains(&format!(r#"<span class="{root_class}">"#)),
+            "unexpected html:\n{html}"
+        );
+    }
+
+    #[test]
+    fn test_long_lines_are_highlighted() {
+        let line = "let x = 1; ".repeat(2_000);
+
+        let html = render(&format!("```rust\n{line}\n```"));
+
+        assert_eq!(html.matches(">let</span>").count(), 2_000);
+    }
+
+    #[test]
+    fn test_theme_covers_code_block_markup() {
+        let html = render(indoc! {r#"
+            ```python
+            def f():
+                return None  # done
+            ```
+        "#});
+        let css = highlight_theme_css();
+
+        assert!(html.contains(
+            r#"<span class="hl-keyword hl-declaration hl-function hl-python">def</span>"#
+        ));
+        assert!(html.contains(r#"<span class="hl-constant hl-language hl-python">None</span>"#));
+        assert!(html.contains(r#"<span class="hl-comment hl-line hl-number-sign hl-python">"#));
+        for class in [".hl-keyword", ".hl-constant.hl-language", ".hl-comment"] {
+            assert!(css.contains(class), "{class} is not styled:\n{css}");
+        }
+    }
+
+    #[test]
+    fn test_web_stylesheet_matches_generated_theme() {
+        assert_eq!(
+            fs::read_to_string(stylesheet_path()).unwrap(),
+            highlight_theme_css(),
+            "regenerate with `cargo test -p publish -- --ignored regenerate_highlight_css`"
+        );
+    }
+
+    #[test]
+    #[ignore = "writes crates/site/web/style/highlight.css"]
+    fn regenerate_highlight_css() {
+        fs::write(stylesheet_path(), highlight_theme_css()).unwrap();
+    }
+}
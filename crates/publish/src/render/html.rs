@@ -1,9 +1,80 @@
-use super::sanitize;
+use super::{
+    anchor, callout, comment::strip_comments, embed, highlight, mark, math, sanitize, tag, toc,
+};
+use crate::config::RawHtmlConfig;
 use crate::links::{self, Index};
+use domain::TocEntry;
 use pulldown_cmark::{Options, Parser, html};
 
-/// Converts Markdown to sanitized HTML.
-pub(crate) fn convert_markdown_to_html(markdown_content: &str, link_index: &Index) -> String {
+/// Converts the Markdown body of the note `source_key` to sanitized HTML.
+pub(crate) fn convert_markdown_to_html(
+    source_key: &str,
+    markdown_content: &str,
+    link_index: &Index,
+    raw_html: &RawHtmlConfig,
+) -> String {
+    render_markdown(
+        markdown_content,
+        Some(source_key),
+        link_index,
+        raw_html,
+        &mut Vec::new(),
+    )
+    .0
+}
+
+/// Converts the Markdown body of the note `source_key` to sanitized HTML along with its heading
+/// outline.
+pub(super) fn convert_markdown_with_toc(
+    source_key: &str,
+    markdown_content: &str,
+    link_index: &Index,
+    raw_html: &RawHtmlConfig,
+) -> (String, Vec<TocEntry>) {
+    render_markdown(
+        markdown_content,
+        Some(source_key),
+        link_index,
+        raw_html,
+        &mut Vec::new(),
+    )
+}
+
+/// Converts Markdown while tracking the notes and heading sections currently being embedded.
+///
+/// Note links are recorded for `source_key`, which is `None` for transcluded bodies.
+pub(super) fn render_markdown(
+    markdown_content: &str,
+    source_key: Option<&str>,
+    link_index: &Index,
+    raw_html: &RawHtmlConfig,
+    embed_stack: &mut Vec<String>,
+) -> (String, Vec<TocEntry>) {
+    let markdown_content = strip_comments(markdown_content);
+    let parser = Parser::new_ext(&markdown_content, markdown_options());
+    // Generated markup is inserted after raw HTML is escaped so it is emitted as-is.
+    // Rejections are reported by `lint::rejected_raw_html`, which also runs on cache hits.
+    let (events, _) = sanitize::raw_html(parser.into_offset_iter(), raw_html);
+    let events = embed::expand_note_embeds(events, link_index, raw_html, embed_stack);
+    let events = callout::events(events);
+    let events = mark::events(events);
+    let (events, _) = tag::events(events);
+    let events = highlight::events(events);
+    let events = match source_key {
+        Some(_) => anchor::assign_anchors(events).0,
+        // A transcluded body is rendered inside the host note, which owns every anchor id.
+        None => anchor::strip_anchors(events),
+    };
+    let toc = toc::collect(&events);
+    let events = math::events(events);
+    let events = links::resolve_wikilinks(events.into_iter(), link_index, source_key);
+    let mut html_output = String::with_capacity(markdown_content.len() * 2);
+    html::push_html(&mut html_output, events.map(sanitize::destination));
+
+    (html_output, toc)
+}
+
+pub(crate) fn markdown_options() -> Options {
     let mut options = Options::empty();
     options.insert(Options::ENABLE_TABLES);
     options.insert(Options::ENABLE_FOOTNOTES);
@@ -12,13 +83,7 @@ pub(crate) fn convert_markdown_to_html(markdown_content: &str, link_index: &Inde
     options.insert(Options::ENABLE_SMART_PUNCTUATION);
     options.insert(Options::ENABLE_MATH);
     options.insert(Options::ENABLE_WIKILINKS);
-
-    let parser = Parser::new_ext(markdown_content, options);
-    let parser = links::resolve_wikilinks(parser, link_index);
-    let mut html_output = String::with_capacity(markdown_content.len() * 2);
-    html::push_html(&mut html_output, sanitize::events(parser).into_iter());
-
-    html_output
+    options
 }
 
 #[cfg(test)]
@@ -28,21 +93,26 @@ mod tests {
     use rstest::*;
 
     fn convert_markdown_to_html(markdown: &str) -> String {
-        super::convert_markdown_to_html(markdown, &Index::default())
+        super::convert_markdown_to_html(
+            "note",
+            markdown,
+            &Index::default(),
+            &RawHtmlConfig::default(),
+        )
     }
 
     #[rstest]
     #[case::basic_markdown(
         "# Hello World\n\nThis is a **bold** text and *italic* text.",
-        "<h1>Hello World</h1>\n<p>This is a <strong>bold</strong> text and <em>italic</em> text.</p>\n"
+        "<h1 id=\"hello-world\">Hello World</h1>\n<p>This is a <strong>bold</strong> text and <em>italic</em> text.</p>\n"
     )]
     #[case::list_items(
         "- Item 1\n- Item 2\n- Item 3",
         "<ul>\n<li>Item 1</li>\n<li>Item 2</li>\n<li>Item 3</li>\n</ul>\n"
     )]
     #[case::code_block(
-        "```rust\nfn main() {\n    println!(\"Hello!\");\n}\n```",
-        "<pre><code class=\"language-rust\">fn main() {\n    println!(\"Hello!\");\n}\n</code></pre>\n"
+        "```\nfn main() {\n    println!(\"Hello!\");\n}\n```",
+        "<pre><code>fn main() {\n    println!(\"Hello!\");\n}\n</code></pre>\n"
     )]
     #[case::table_support(
         "| Col1 | Col2 |\n|------|------|\n| A    | B    |",
@@ -50,45 +120,60 @@ mod tests {
     )]
     #[case::japanese_content(
         "# 日本語のタイトル\n\n**太字**のテキストです。",
-        "<h1>日本語のタイトル</h1>\n<p><strong>太字</strong>のテキストです。</p>\n"
+        "<h1 id=\"日本語のタイトル\">日本語のタイトル</h1>\n<p><strong>太字</strong>のテキストです。</p>\n"
     )]
     fn test_markdown_to_html_conversion(#[case] markdown: &str, #[case] expected_html: &str) {
         let result = convert_markdown_to_html(markdown);
         assert_eq!(result, expected_html);
     }
 
+    // Math spans as (class, TeX source) pairs, read back from the MathML annotations.
+    fn math_sources(html: &str) -> Vec<(String, String)> {
+        let math_re = regex::Regex::new(
+            r#"<span class="(math [^"]+)"><math[^>]*>.*?<annotation encoding="application/x-tex">(.*?)</annotation>"#,
+        )
+        .unwrap();
+        math_re
+            .captures_iter(html)
+            .map(|captures| (captures[1].to_string(), captures[2].to_string()))
+            .collect()
+    }
+
+    fn inline_math(tex: &str) -> (String, String) {
+        ("math math-inline".to_string(), tex.to_string())
+    }
+
+    fn display_math(tex: &str) -> (String, String) {
+        ("math math-display".to_string(), tex.to_string())
+    }
+
     #[rstest]
     #[case::inline_math(
         "Here is some inline math: $x^2 + y^2 = z^2$ and more text.",
-        "<p>Here is some inline math: <span class=\"math math-inline\">x^2 + y^2 = z^2</span> and more text.</p>\n"
+        vec![inline_math("x^2 + y^2 = z^2")]
     )]
     #[case::display_math(
         "Here is display math:\n$$\\int_0^1 x^2 dx = \\frac{1}{3}$$\nEnd of math.",
-        "<p>Here is display math:\n<span class=\"math math-display\">\\int_0^1 x^2 dx = \\frac{1}{3}</span>\nEnd of math.</p>\n"
+        vec![display_math("\\int_0^1 x^2 dx = \\frac{1}{3}")]
     )]
     #[case::mixed_math(
         "Inline $a+b$ and display $$c+d$$ math.",
-        "<p>Inline <span class=\"math math-inline\">a+b</span> and display <span class=\"math math-display\">c+d</span> math.</p>\n"
-    )]
-    #[case::pipe_in_inline_math(
-        r"Inline $a\|b$ math.",
-        "<p>Inline <span class=\"math math-inline\">a\\|b</span> math.</p>\n"
+        vec![inline_math("a+b"), display_math("c+d")]
     )]
-    #[case::unescaped_pipe_outside_table(
-        "Inline $a|b$ math.",
-        "<p>Inline <span class=\"math math-inline\">a|b</span> math.</p>\n"
-    )]
-    fn test_math_processing(#[case] input: &str, #[case] expected: &str) {
+    #[case::pipe_in_inline_math(r"Inline $a\|b$ math.", vec![inline_math("a\\|b")])]
+    #[case::unescaped_pipe_outside_table("Inline $a|b$ math.", vec![inline_math("a|b")])]
+    fn test_math_processing(#[case] input: &str, #[case] expected: Vec<(String, String)>) {
         let result = convert_markdown_to_html(input);
-        assert_eq!(result, expected);
+        assert_eq!(math_sources(&result), expected);
     }
 
     #[test]
     fn test_math_markup_uses_semantic_classes() {
         let result = convert_markdown_to_html("Inline $a+b$ and display $$c+d$$.");
 
-        assert!(result.contains(r#"class="math math-inline">a+b</span>"#));
-        assert!(result.contains(r#"class="math math-display">c+d</span>"#));
+        assert!(result.contains(r#"<span class="math math-inline"><math display="inline">"#));
+        assert!(result.contains(r#"<span class="math math-display"><math display="block">"#));
+        assert!(!result.contains("$"));
     }
 
     #[test]
@@ -98,7 +183,8 @@ mod tests {
         let result = convert_markdown_to_html(markdown);
 
         assert!(
-            result.contains(r#"<td><span class="math math-inline">a|b</span></td>"#),
+            result.contains(r#"<td><span class="math math-inline"><math display="inline">"#)
+                && math_sources(&result) == vec![inline_math("a|b")],
             "unexpected html:\n{result}"
         );
     }
@@ -110,7 +196,8 @@ mod tests {
         let result = convert_markdown_to_html(markdown);
 
         assert!(
-            result.contains(r#"<th><span class="math math-inline">a|b</span></th>"#),
+            result.contains(r#"<th><span class="math math-inline"><math display="inline">"#)
+                && math_sources(&result) == vec![inline_math("a|b")],
             "unexpected html:\n{result}"
         );
         assert!(result.starts_with("<table>"), "unexpected html:\n{result}");
@@ -127,12 +214,19 @@ mod tests {
 
     #[test]
     fn test_math_content_is_html_escaped() {
-        let result = convert_markdown_to_html(r#"$x < y & "quoted"$"#);
+        let result = convert_markdown_to_html(r#"$x < y > z \text{<b>"&"</b>}$"#);
 
         assert_eq!(
-            result,
-            "<p><span class=\"math math-inline\">x &lt; y &amp; &quot;quoted&quot;</span></p>\n"
+            math_sources(&result),
+            vec![inline_math(
+                "x &lt; y &gt; z \\text{&lt;b&gt;\"&amp;\"&lt;/b&gt;}"
+            )]
         );
+        assert!(
+            result.contains("<mo>&lt;</mo>") && result.contains("<mo>&gt;</mo>"),
+            "unexpected html:\n{result}"
+        );
+        assert!(!result.contains("<b>"), "unexpected html:\n{result}");
     }
 
     #[test]
@@ -145,11 +239,8 @@ mod tests {
             result.contains("<strong>「サンプリング」</strong> と <strong>「モデル化」</strong>"),
             "unexpected html:\n{result}"
         );
-        assert!(
-            result.contains(
-                "<strong><span class=\"math math-inline\">x = (x_1, x_2)</span></strong>"
-            )
-        );
+        assert!(result.contains("<strong><span class=\"math math-inline\">"));
+        assert_eq!(math_sources(&result), vec![inline_math("x = (x_1, x_2)")]);
         assert!(!result.contains("**"));
     }
 
@@ -170,8 +261,7 @@ mod tests {
         let result = convert_markdown_to_html(markdown);
 
         assert!(result.contains("<code>code with `$x$` inside</code>"));
-        assert!(result.contains(r#"<span class="math math-inline">y</span>"#));
-        assert!(!result.contains(r#"<span class="math math-inline">x</span>"#));
+        assert_eq!(math_sources(&result), vec![inline_math("y")]);
     }
 
     #[test]
@@ -184,8 +274,7 @@ mod tests {
             result
                 .contains("<pre><code class=\"language-text\">literal ``` and $x$\n</code></pre>")
         );
-        assert!(result.contains(r#"<span class="math math-inline">y</span>"#));
-        assert!(!result.contains(r#"<span class="math math-inline">x</span>"#));
+        assert_eq!(math_sources(&result), vec![inline_math("y")]);
     }
 
     #[test]
@@ -204,8 +293,7 @@ mod tests {
 
         let result = convert_markdown_to_html(markdown);
 
-        assert!(result.contains("&lt;img"));
-        assert!(result.contains("$x$.png"));
+        assert!(result.contains(r#"<img src="https://example.com/$x$.png" alt="img">"#));
         assert!(!result.contains("math math-inline"));
     }
 
@@ -215,7 +303,7 @@ mod tests {
 
         let result = convert_markdown_to_html(markdown);
 
-        assert!(result.contains(r#"<span class="math math-inline">z</span>"#));
+        assert_eq!(math_sources(&result), vec![inline_math("z")]);
     }
 
     #[test]
@@ -282,7 +370,7 @@ mod tests {
         let result = convert_markdown_to_html(markdown);
 
         assert!(
-            result.contains(r#"<span class="math math-inline">a+b</span>"#),
+            math_sources(&result) == vec![inline_math("a+b")],
             "math outside code should be converted to math span; got:\n{result}"
         );
         assert!(
@@ -307,6 +395,10 @@ mod tests {
 
         assert!(result.contains("math math-inline"));
         assert!(result.contains("$not_math$"));
-        assert!(!result.contains("<span class=\"math math-inline\">not_math</span>"));
+        assert!(
+            math_sources(&result)
+                .iter()
+                .all(|(_, tex)| tex != "not_math")
+        );
     }
 }
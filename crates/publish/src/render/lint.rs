@@ -0,0 +1,263 @@
+use super::{comment::mask_comments, html::markdown_options, math, sanitize};
+use crate::classify::SourceLocation;
+use crate::config::{BrokenLinkPolicy, RawHtmlConfig};
+use crate::diagnostics::{Diagnostic, DiagnosticCode};
+use crate::links::{self, Index};
+use pulldown_cmark::{Event, LinkType, Parser, Tag};
+
+/// Reports WikiLinks and embeds that resolve to nothing per `policy`, and warns about TeX math
+/// that does not convert and raw HTML the allowlist rejects. Rendering recovers from all of them;
+/// comments are never checked.
+pub(crate) fn lint_markdown(
+    markdown: &str,
+    source_key: &str,
+    location: &SourceLocation,
+    index: &Index,
+    policy: BrokenLinkPolicy,
+    raw_html: &RawHtmlConfig,
+) -> Vec<Diagnostic> {
+    let mut diagnostics = broken_links(markdown, source_key, location, index, policy);
+    diagnostics.extend(invalid_math(markdown, location));
+    diagnostics.extend(rejected_raw_html(markdown, location, raw_html));
+    diagnostics.sort_by_key(|diagnostic| (diagnostic.line, diagnostic.column));
+    diagnostics
+}
+
+/// TeX math that does not convert to MathML and is published as its source instead.
+pub(crate) fn invalid_math(markdown: &str, location: &SourceLocation) -> Vec<Diagnostic> {
+    positioned(markdown, location, |event| match event {
+        Event::InlineMath(tex) => math::tex_error(&tex, false).map(invalid_math_warning),
+        Event::DisplayMath(tex) => math::tex_error(&tex, true).map(invalid_math_warning),
+        _ => None,
+    })
+}
+
+/// Raw HTML that rendering escapes, strips or rewrites under the `raw_html` allowlist.
+pub(crate) fn rejected_raw_html(
+    markdown: &str,
+    location: &SourceLocation,
+    raw_html: &RawHtmlConfig,
+) -> Vec<Diagnostic> {
+    let masked = mask_comments(markdown);
+    let parser = Parser::new_ext(&masked, markdown_options()).into_offset_iter();
+    let (_, rejections) = sanitize::raw_html(parser, raw_html);
+    rejections
+        .into_iter()
+        .map(|rejection| {
+            Diagnostic::warning(DiagnosticCode::RejectedRawHtml, rejection.message)
+                .in_file(&location.path)
+                .at_offset(markdown, location.body_line, rejection.offset)
+        })
+        .collect()
+}
+
+/// WikiLinks, embeds, and heading fragments that resolve to nothing, at the severity of `policy`.
+///
+/// Same-note fragments such as `[[#Heading]]` are checked against the note at `source_key`.
+pub(crate) fn broken_links(
+    markdown: &str,
+    source_key: &str,
+    location: &SourceLocation,
+    index: &Index,
+    policy: BrokenLinkPolicy,
+) -> Vec<Diagnostic> {
+    let Some(severity) = policy.severity() else {
+        return Vec::new();
+    };
+    positioned(markdown, location, |event| match event {
+        Event::Start(
+            Tag::Link {
+                link_type: LinkType::WikiLink { has_pothole },
+                dest_url,
+                ..
+            }
+            | Tag::Image {
+                link_type: LinkType::WikiLink { has_pothole },
+                dest_url,
+                ..
+            },
+        ) => {
+            let target = links::wikilink_target(&dest_url, has_pothole);
+            (!index.resolves_wikilink(target, source_key)).then(|| {
+                Diagnostic::new(
+                    severity,
+                    DiagnosticCode::UnresolvedLink,
+                    format!("internal link target was not found: {target}"),
+                )
+            })
+        }
+        _ => None,
+    })
+}
+
+/// Diagnoses each Markdown event outside comments and positions it in the source file.
+fn positioned(
+    markdown: &str,
+    location: &SourceLocation,
+    diagnose: impl Fn(Event<'_>) -> Option<Diagnostic>,
+) -> Vec<Diagnostic> {
+    let masked = mask_comments(markdown);
+    Parser::new_ext(&masked, markdown_options())
+        .into_offset_iter()
+        .filter_map(|(event, range)| {
+            Some(diagnose(event)?.in_file(&location.path).at_offset(
+                markdown,
+                location.body_line,
+                range.start,
+            ))
+        })
+        .collect()
+}
+
+fn invalid_math_warning(error: String) -> Diagnostic {
+    Diagnostic::warning(
+        DiagnosticCode::InvalidMath,
+        format!("invalid TeX math: {error}"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::classify::{ClassifiedFiles, ParsedHomeFile};
+    use crate::vault::{ContentKind, ObsidianFrontMatter};
+    use std::path::PathBuf;
+
+    /// Index of a single note published from `source_key`.
+    fn index(source_key: &str, markdown_body: &str) -> Index {
+        let files = ClassifiedFiles {
+            home: Some(ParsedHomeFile {
+                source_key: source_key.to_string(),
+                markdown_body: markdown_body.to_string(),
+                front_matter: ObsidianFrontMatter {
+                    title: "Home".to_string(),
+                    ..ObsidianFrontMatter::for_test(ContentKind::Home)
+                },
+                location: SourceLocation::default(),
+            }),
+            ..Default::default()
+        };
+        Index::from_classified_files(&files)
+    }
+
+    #[test]
+    fn test_lint_positions_unresolved_links_and_invalid_math_in_the_file() {
+        let location = SourceLocation {
+            path: PathBuf::from("vault/tech/note.md"),
+            body_line: 7,
+            ..SourceLocation::default()
+        };
+        let markdown =
+            "[[#Local]] and [[missing]]\n%% [[private]] %%\n本文 $\\foo$ `[[code]]`\n\n## Local\n";
+
+        let diagnostics = lint_markdown(
+            markdown,
+            "tech/note",
+            &location,
+            &index("tech/note", markdown),
+            BrokenLinkPolicy::Warn,
+            &RawHtmlConfig::default(),
+        );
+
+        assert_eq!(
+            diagnostics
+                .iter()
+                .map(|diagnostic| (diagnostic.code, diagnostic.line, diagnostic.column))
+                .collect::<Vec<_>>(),
+            [
+                (DiagnosticCode::UnresolvedLink, Some(7), Some(16)),
+                (DiagnosticCode::InvalidMath, Some(9), Some(4)),
+            ]
+        );
+        assert_eq!(
+            diagnostics[0].message,
+            "internal link target was not found: missing"
+        );
+        assert_eq!(diagnostics[0].file.as_deref(), Some("vault/tech/note.md"));
+    }
+
+    #[test]
+    fn test_broken_links_follow_the_policy_severity() {
+        let location = SourceLocation::default();
+        let markdown = "[[missing#Heading]] ![[missing.png]]";
+        let index = Index::default();
+
+        let errors = broken_links(markdown, "note", &location, &index, BrokenLinkPolicy::Error);
+        assert_eq!(errors.len(), 2);
+        assert!(errors.iter().all(Diagnostic::is_error));
+        assert!(
+            broken_links(
+                markdown,
+                "note",
+                &location,
+                &index,
+                BrokenLinkPolicy::Ignore
+            )
+            .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_same_note_fragments_are_checked_against_the_note() {
+        let markdown = "# Setup\n\nText ^done\n\n[[#Setup]] [[#^done]] [[#Missing]] [[#^gone]]\n";
+        let index = index("tech/note", markdown);
+
+        let diagnostics = broken_links(
+            markdown,
+            "tech/note",
+            &SourceLocation::default(),
+            &index,
+            BrokenLinkPolicy::Warn,
+        );
+
+        assert_eq!(
+            diagnostics
+                .iter()
+                .map(|diagnostic| diagnostic.message.as_str())
+                .collect::<Vec<_>>(),
+            [
+                "internal link target was not found: #Missing",
+                "internal link target was not found: #^gone",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rejected_raw_html_is_a_positioned_warning() {
+        let location = SourceLocation {
+            path: PathBuf::from("vault/tech/note.md"),
+            body_line: 5,
+            ..SourceLocation::default()
+        };
+        let markdown = "本文\n\n<div onclick=\"x()\">\n\n%% <script> %%\nA <a href=\"javascript:x\">link</a>\n";
+
+        let diagnostics = rejected_raw_html(markdown, &location, &RawHtmlConfig::default());
+
+        assert_eq!(
+            diagnostics
+                .iter()
+                .map(|diagnostic| (
+                    diagnostic.message.as_str(),
+                    diagnostic.line,
+                    diagnostic.column
+                ))
+                .collect::<Vec<_>>(),
+            [
+                (
+                    "removed disallowed raw HTML attribute onclick from <div>",
+                    Some(7),
+                    Some(1)
+                ),
+                (
+                    "neutralized unsafe raw HTML URL in href of <a>",
+                    Some(10),
+                    Some(3)
+                ),
+                ("closed unclosed raw HTML tag <div>", Some(7), Some(1)),
+            ]
+        );
+        assert!(diagnostics.iter().all(|diagnostic| !diagnostic.is_error()
+            && diagnostic.code == DiagnosticCode::RejectedRawHtml
+            && diagnostic.file.as_deref() == Some("vault/tech/note.md")));
+    }
+}
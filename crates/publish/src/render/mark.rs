@@ -0,0 +1,132 @@
+use pulldown_cmark::{Event, Tag, TagEnd};
+
+/// Renders Obsidian `==highlight==` spans as `<mark>` elements.
+pub(super) fn events<'a>(events: Vec<Event<'a>>) -> Vec<Event<'a>> {
+    let mut result = Vec::with_capacity(events.len());
+    let mut open_mark = None;
+    let mut in_code_block = false;
+
+    for event in events {
+        match &event {
+            Event::Start(Tag::CodeBlock(_)) => in_code_block = true,
+            Event::End(TagEnd::CodeBlock) => in_code_block = false,
+            _ => {}
+        }
+        match event {
+            Event::Text(text) if !in_code_block && text.contains("==") => {
+                push_marked_text(&text, &mut open_mark, &mut result);
+            }
+            Event::Start(ref tag) if !is_inline_tag(tag) => {
+                close_unmatched_mark(&mut open_mark, &mut result);
+                result.push(event);
+            }
+            Event::End(ref tag) if !is_inline_tag_end(tag) => {
+                close_unmatched_mark(&mut open_mark, &mut result);
+                result.push(event);
+            }
+            Event::Html(_) => {
+                close_unmatched_mark(&mut open_mark, &mut result);
+                result.push(event);
+            }
+            event => result.push(event),
+        }
+    }
+    close_unmatched_mark(&mut open_mark, &mut result);
+
+    result
+}
+
+// Delimiters must hug the highlighted text, so `a == b` stays literal.
+fn push_marked_text(text: &str, open_mark: &mut Option<usize>, result: &mut Vec<Event<'_>>) {
+    let mut cursor = 0;
+    for (position, delimiter) in text.match_indices("==") {
+        let before = text[..position].chars().next_back();
+        let after = text[position + delimiter.len()..].chars().next();
+        let is_delimiter = match open_mark {
+            Some(_) => before.is_none_or(|c| !c.is_whitespace()),
+            None => after.is_none_or(|c| !c.is_whitespace()),
+        };
+        if !is_delimiter {
+            continue;
+        }
+
+        if cursor < position {
+            result.push(Event::Text(text[cursor..position].to_string().into()));
+        }
+        match open_mark.take() {
+            Some(_) => result.push(Event::Html("</mark>".into())),
+            None => {
+                *open_mark = Some(result.len());
+                result.push(Event::Html("<mark>".into()));
+            }
+        }
+        cursor = position + delimiter.len();
+    }
+
+    if cursor < text.len() {
+        result.push(Event::Text(text[cursor..].to_string().into()));
+    }
+}
+
+// An opening `==` without a partner in the same block is literal text.
+fn close_unmatched_mark(open_mark: &mut Option<usize>, result: &mut [Event<'_>]) {
+    if let Some(index) = open_mark.take() {
+        result[index] = Event::Text("==".into());
+    }
+}
+
+fn is_inline_tag(tag: &Tag<'_>) -> bool {
+    matches!(
+        tag,
+        Tag::Emphasis
+            | Tag::Strong
+            | Tag::Strikethrough
+            | Tag::Superscript
+            | Tag::Subscript
+            | Tag::Link { .. }
+            | Tag::Image { .. }
+    )
+}
+
+fn is_inline_tag_end(tag: &TagEnd) -> bool {
+    matches!(
+        tag,
+        TagEnd::Emphasis
+            | TagEnd::Strong
+            | TagEnd::Strikethrough
+            | TagEnd::Superscript
+            | TagEnd::Subscript
+            | TagEnd::Link
+            | TagEnd::Image
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render::html::markdown_options;
+    use pulldown_cmark::{Parser, html};
+    use rstest::rstest;
+
+    fn render(markdown: &str) -> String {
+        let parser = Parser::new_ext(markdown, markdown_options());
+        let mut output = String::new();
+        html::push_html(&mut output, events(parser.collect()).into_iter());
+        output
+    }
+
+    #[rstest]
+    #[case::simple("A ==key idea== here", "<p>A <mark>key idea</mark> here</p>\n")]
+    #[case::with_markup(
+        "==**bold** text==",
+        "<p><mark><strong>bold</strong> text</mark></p>\n"
+    )]
+    #[case::comparison("if a == b", "<p>if a == b</p>\n")]
+    #[case::unclosed("==open only", "<p>==open only</p>\n")]
+    #[case::unclosed_across_blocks("==one\n\ntwo==", "<p>==one</p>\n<p>two==</p>\n")]
+    #[case::code("`a==b==c`", "<p><code>a==b==c</code></p>\n")]
+    #[case::fenced_code("```\na ==b==\n```", "<pre><code>a ==b==\n</code></pre>\n")]
+    fn test_highlights(#[case] markdown: &str, #[case] expected: &str) {
+        assert_eq!(render(markdown), expected);
+    }
+}
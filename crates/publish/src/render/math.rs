@@ -0,0 +1,144 @@
+use html_escape::{encode_double_quoted_attribute, encode_text};
+use pulldown_cmark::Event;
+use pulldown_latex::{Parser, RenderConfig, Storage, config::DisplayMode, push_mathml};
+
+/// Converts `$...$` and `$$...$$` to MathML so math renders without client-side scripts.
+pub(super) fn events(events: Vec<Event<'_>>) -> Vec<Event<'_>> {
+    events
+        .into_iter()
+        .map(|event| match event {
+            Event::InlineMath(tex) => Event::Html(math_html(&tex, false).into()),
+            Event::DisplayMath(tex) => Event::Html(math_html(&tex, true).into()),
+            event => event,
+        })
+        .collect()
+}
+
+/// Why `tex` cannot be converted to MathML, if it cannot.
+pub(super) fn tex_error(tex: &str, display: bool) -> Option<String> {
+    to_mathml(tex, display).err()
+}
+
+// Invalid math is reported with its file and line before rendering, so it only shows its source.
+fn math_html(tex: &str, display: bool) -> String {
+    let class = if display {
+        "math math-display"
+    } else {
+        "math math-inline"
+    };
+
+    match to_mathml(tex, display) {
+        Ok(mathml) => format!(r#"<span class="{class}">{mathml}</span>"#),
+        Err(error) => format!(
+            r#"<span class="{class} math-error" title="{}">{}</span>"#,
+            encode_double_quoted_attribute(&error),
+            encode_text(tex)
+        ),
+    }
+}
+
+/// MathML for `tex`, annotated with its TeX source so it can be copied back out.
+fn to_mathml(tex: &str, display: bool) -> Result<String, String> {
+    let storage = Storage::new();
+    let events = Parser::new(tex, &storage)
+        .collect::<Result<Vec<_>, _>>()
+        // The first line names the problem; the rest is a drawing of where it is in `tex`.
+        .map_err(|error| {
+            let message = error.to_string();
+            let message = message.lines().next().unwrap_or_default();
+            message
+                .strip_prefix("parsing error: ")
+                .unwrap_or(message)
+                .to_string()
+        })?;
+
+    let config = RenderConfig {
+        display_mode: if display {
+            DisplayMode::Block
+        } else {
+            DisplayMode::Inline
+        },
+        ..RenderConfig::default()
+    };
+    let mut mathml = String::new();
+    push_mathml(
+        &mut mathml,
+        events.into_iter().map(Ok::<_, std::io::Error>),
+        config,
+    )
+    .map_err(|error| error.to_string())?;
+
+    // pulldown-latex copies annotations verbatim, so the escaped source is added here instead.
+    let mathml = escape_operators(&mathml);
+    let body_start = mathml.find('>').map_or(0, |index| index + 1);
+    let (math_tag, body) = mathml.split_at(body_start);
+    let body = body.strip_suffix("</math>").unwrap_or(body);
+    Ok(format!(
+        r#"{math_tag}<semantics>{body}<annotation encoding="application/x-tex">{}</annotation></semantics></math>"#,
+        encode_text(tex)
+    ))
+}
+
+/// Escapes the `<` and `>` operators pulldown-latex writes as raw characters.
+///
+/// Every tag it writes starts with a letter or `/`, and an operator is the whole content of its
+/// element, so a `<` before anything else or a `>` right after a tag is an operator.
+fn escape_operators(mathml: &str) -> String {
+    let mut escaped = String::with_capacity(mathml.len());
+    let mut chars = mathml.chars().peekable();
+    let mut previous = None;
+    while let Some(c) = chars.next() {
+        match c {
+            '<' if !chars
+                .peek()
+                .is_some_and(|next| next.is_ascii_alphabetic() || *next == '/') =>
+            {
+                escaped.push_str("&lt;");
+            }
+            '>' if previous == Some('>') => escaped.push_str("&gt;"),
+            c => escaped.push(c),
+        }
+        previous = Some(c);
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render::html::markdown_options;
+    use pulldown_cmark::{Parser, html};
+
+    fn render(markdown: &str) -> String {
+        let parser = Parser::new_ext(markdown, markdown_options());
+        let mut output = String::new();
+        html::push_html(&mut output, events(parser.collect()).into_iter());
+        output
+    }
+
+    #[test]
+    fn test_inline_and_display_math_become_mathml() {
+        let html = render("Inline $x^2$ and display $$\\frac{a}{b}$$ math.");
+
+        assert_eq!(
+            html,
+            concat!(
+                r#"<p>Inline <span class="math math-inline"><math display="inline"><semantics><msup><mi>x</mi><mn>2</mn></msup>"#,
+                r#"<annotation encoding="application/x-tex">x^2</annotation></semantics></math></span>"#,
+                r#" and display <span class="math math-display"><math display="block"><semantics><mfrac><mrow><mi>a</mi></mrow><mrow><mi>b</mi></mrow></mfrac>"#,
+                r#"<annotation encoding="application/x-tex">\frac{a}{b}</annotation></semantics></math></span>"#,
+                " math.</p>\n"
+            )
+        );
+    }
+
+    #[test]
+    fn test_invalid_tex_keeps_source_with_diagnostic() {
+        let html = render("Broken $\\foo x$ math.");
+
+        assert_eq!(
+            html,
+            "<p>Broken <span class=\"math math-inline math-error\" title=\"unknown primitive command found\">\\foo x</span> math.</p>\n"
+        );
+    }
+}
@@ -1,39 +1,88 @@
 use super::bookmark;
+use crate::config::RawHtmlConfig;
+use html_escape::{decode_html_entities, encode_double_quoted_attribute, encode_text};
 use pulldown_cmark::{CowStr, Event, Tag};
+use std::ops::Range;
+
+/// Attributes whose values are checked with [`is_safe_destination`]; each candidate of a
+/// `srcset` is checked the same way.
+const URL_ATTRIBUTES: &[&str] = &[
+    "href",
+    "src",
+    "cite",
+    "poster",
+    "action",
+    "formaction",
+    "data",
+    "xlink:href",
+    "background",
+];
+
+const VOID_TAGS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track",
+    "wbr",
+];
+
+/// Raw HTML that was escaped, removed or rewritten, at the byte offset of its fragment in the
+/// Markdown source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct Rejection {
+    pub(super) offset: usize,
+    pub(super) message: String,
+}
 
-/// Sanitizes link destinations and raw HTML before HTML generation.
-pub(super) fn events<'a>(parser: impl Iterator<Item = Event<'a>>) -> Vec<Event<'a>> {
+/// Sanitizes raw HTML from the Markdown source against the configured tag allowlist,
+/// preserving valid simple bookmark markup, and returns what it rejected.
+pub(super) fn raw_html<'a>(
+    parser: impl Iterator<Item = (Event<'a>, Range<usize>)>,
+    config: &RawHtmlConfig,
+) -> (Vec<Event<'a>>, Vec<Rejection>) {
     let mut result = Vec::new();
     let mut bookmark_buffer = String::new();
+    let mut allowlist = Allowlist::new(config);
 
-    for event in parser {
+    for (event, range) in parser {
         match event {
-            Event::Html(html) | Event::InlineHtml(html) => {
-                sanitize_raw_html(html, &mut bookmark_buffer, &mut result)
-            }
+            Event::Html(html) | Event::InlineHtml(html) => sanitize_raw_html(
+                html,
+                range.start,
+                &mut bookmark_buffer,
+                &mut allowlist,
+                &mut result,
+            ),
             other => {
-                flush_bookmark_buffer(&mut bookmark_buffer, &mut result);
-                result.push(sanitize_destination_event(other));
+                flush_bookmark_buffer(&mut bookmark_buffer, &mut allowlist, &mut result);
+                result.push(other);
             }
         }
     }
 
-    flush_bookmark_buffer(&mut bookmark_buffer, &mut result);
+    flush_bookmark_buffer(&mut bookmark_buffer, &mut allowlist, &mut result);
+    if let Some(closing_tags) = allowlist.close_open_tags() {
+        result.push(Event::Html(closing_tags.into()));
+    }
 
-    result
+    (result, allowlist.rejections)
 }
 
-// Escapes raw HTML while preserving valid simple bookmark markup.
+// Sanitizes raw HTML while preserving valid simple bookmark markup.
 fn sanitize_raw_html<'a>(
     html: CowStr<'a>,
+    offset: usize,
     bookmark_buffer: &mut String,
+    allowlist: &mut Allowlist<'_>,
     result: &mut Vec<Event<'a>>,
 ) {
     if bookmark_buffer.is_empty() && !bookmark::is_simple_bookmark_start(&html) {
-        result.push(Event::Text(html));
+        allowlist.offset = offset;
+        result.push(Event::Html(allowlist.sanitize(&html).into()));
         return;
     }
 
+    // A bookmark spanning several fragments is reported where it starts.
+    if bookmark_buffer.is_empty() {
+        allowlist.offset = offset;
+    }
     bookmark_buffer.push_str(&html);
     let Some(end) = bookmark::simple_bookmark_end(bookmark_buffer) else {
         return;
@@ -46,26 +95,296 @@ fn sanitize_raw_html<'a>(
             let href = if is_safe_destination(bookmark.href()) {
                 bookmark.href()
             } else {
+                allowlist.reject("neutralized unsafe bookmark URL".to_string());
                 "#"
             };
             result.push(Event::Html(bookmark.with_href(href).into()));
         }
-        None => result.push(Event::Text(bookmark_html.into())),
+        None => {
+            allowlist.reject("escaped malformed bookmark markup".to_string());
+            result.push(Event::Text(bookmark_html.into()));
+        }
     }
 
     if !rest.is_empty() {
-        result.push(Event::Text(rest.into()));
+        result.push(Event::Html(allowlist.sanitize(&rest).into()));
     }
 }
 
 // Emits an incomplete bookmark buffer as escaped text.
-fn flush_bookmark_buffer<'a>(bookmark_buffer: &mut String, result: &mut Vec<Event<'a>>) {
+fn flush_bookmark_buffer<'a>(
+    bookmark_buffer: &mut String,
+    allowlist: &mut Allowlist<'_>,
+    result: &mut Vec<Event<'a>>,
+) {
     if !bookmark_buffer.is_empty() {
+        allowlist.reject("escaped unterminated bookmark markup".to_string());
         result.push(Event::Text(std::mem::take(bookmark_buffer).into()));
     }
 }
 
-fn sanitize_destination_event(event: Event<'_>) -> Event<'_> {
+/// Rebuilds allowed tags across the raw HTML fragments of one document.
+///
+/// Open elements are tracked so stray closing tags cannot close the page's own markup.
+struct Allowlist<'c> {
+    config: &'c RawHtmlConfig,
+    /// Open elements with the offset of the fragment that opened each.
+    open: Vec<(&'c str, usize)>,
+    /// Offset of the fragment being sanitized.
+    offset: usize,
+    rejections: Vec<Rejection>,
+}
+
+impl<'c> Allowlist<'c> {
+    fn new(config: &'c RawHtmlConfig) -> Self {
+        Self {
+            config,
+            open: Vec::new(),
+            offset: 0,
+            rejections: Vec::new(),
+        }
+    }
+
+    fn reject(&mut self, message: String) {
+        self.rejections.push(Rejection {
+            offset: self.offset,
+            message,
+        });
+    }
+
+    fn sanitize(&mut self, html: &str) -> String {
+        let mut output = String::with_capacity(html.len());
+        let mut rest = html;
+
+        while let Some(start) = rest.find('<') {
+            output.push_str(&rest[..start]);
+            rest = &rest[start..];
+
+            if let Some(comment) = rest.strip_prefix("<!--") {
+                // Comments never reach the page; an unterminated one swallows the fragment.
+                rest = comment
+                    .find("-->")
+                    .map_or("", |end| &comment[end + "-->".len()..]);
+                continue;
+            }
+
+            match RawTag::parse(rest) {
+                Some((tag, length)) => {
+                    output.push_str(&self.tag(&tag, &rest[..length]));
+                    rest = &rest[length..];
+                }
+                None => {
+                    output.push_str("&lt;");
+                    rest = &rest[1..];
+                }
+            }
+        }
+
+        output.push_str(rest);
+        output
+    }
+
+    fn tag(&mut self, tag: &RawTag, source: &str) -> String {
+        let Some((name, attributes)) = self.config.tag(&tag.name) else {
+            self.reject(format!("escaped disallowed raw HTML tag <{}>", tag.name));
+            return encode_text(source).into_owned();
+        };
+
+        if tag.closing {
+            return self.close(name, source);
+        }
+
+        let mut output = format!("<{name}");
+        for (attribute, value) in &tag.attributes {
+            let attribute = attribute.to_ascii_lowercase();
+            if !self.config.allows_attribute(attributes, &attribute) {
+                self.reject(format!(
+                    "removed disallowed raw HTML attribute {attribute} from <{name}>"
+                ));
+                continue;
+            }
+
+            match value {
+                Some(value) => {
+                    let value = decode_html_entities(value);
+                    let safe_value = if URL_ATTRIBUTES.contains(&attribute.as_str()) {
+                        (!is_safe_destination(&value)).then(|| "#".to_string())
+                    } else if attribute == "srcset" {
+                        safe_srcset(&value)
+                    } else {
+                        None
+                    };
+                    let value = match safe_value {
+                        Some(safe_value) => {
+                            self.reject(format!(
+                                "neutralized unsafe raw HTML URL in {attribute} of <{name}>"
+                            ));
+                            safe_value.into()
+                        }
+                        None => value,
+                    };
+                    output.push_str(&format!(
+                        r#" {attribute}="{}""#,
+                        encode_double_quoted_attribute(&value)
+                    ));
+                }
+                None => output.push_str(&format!(" {attribute}")),
+            }
+        }
+        output.push('>');
+
+        if !VOID_TAGS.contains(&name) {
+            self.open.push((name, self.offset));
+        }
+        output
+    }
+
+    // Closes `name` and anything still open inside it; unmatched closing tags are escaped.
+    fn close(&mut self, name: &'c str, source: &str) -> String {
+        if VOID_TAGS.contains(&name) {
+            return String::new();
+        }
+
+        let Some(index) = self.open.iter().rposition(|(open, _)| *open == name) else {
+            self.reject(format!("escaped unmatched raw HTML closing tag </{name}>"));
+            return encode_text(source).into_owned();
+        };
+
+        self.open
+            .split_off(index)
+            .iter()
+            .rev()
+            .map(|(open, _)| format!("</{open}>"))
+            .collect()
+    }
+
+    fn close_open_tags(&mut self) -> Option<String> {
+        if self.open.is_empty() {
+            return None;
+        }
+
+        let mut closing_tags = String::new();
+        for (open, offset) in self.open.drain(..).rev().collect::<Vec<_>>() {
+            self.rejections.push(Rejection {
+                offset,
+                message: format!("closed unclosed raw HTML tag <{open}>"),
+            });
+            closing_tags.push_str(&format!("</{open}>"));
+        }
+        closing_tags.push('\n');
+        Some(closing_tags)
+    }
+}
+
+/// Replaces each unsafe candidate URL in a `srcset`, or returns `None` when all are safe.
+fn safe_srcset(srcset: &str) -> Option<String> {
+    let candidates: Vec<_> = srcset.split(',').map(str::trim).collect();
+    let is_safe = |candidate: &str| {
+        candidate
+            .split_whitespace()
+            .next()
+            .is_none_or(is_safe_destination)
+    };
+    if candidates.iter().all(|candidate| is_safe(candidate)) {
+        return None;
+    }
+    let safe_candidates: Vec<_> = candidates
+        .into_iter()
+        .map(|candidate| {
+            if is_safe(candidate) {
+                candidate.to_string()
+            } else {
+                // Keep the width or density descriptor after the URL.
+                let descriptor = candidate
+                    .split_once(char::is_whitespace)
+                    .map_or("", |(_, descriptor)| descriptor);
+                format!("# {descriptor}").trim_end().to_string()
+            }
+        })
+        .collect();
+    Some(safe_candidates.join(", "))
+}
+
+/// Start or end tag read from a raw HTML fragment.
+struct RawTag {
+    name: String,
+    closing: bool,
+    attributes: Vec<(String, Option<String>)>,
+}
+
+impl RawTag {
+    /// Parses the tag at the start of `html`, returning it with its length in bytes.
+    fn parse(html: &str) -> Option<(Self, usize)> {
+        let mut rest = html.strip_prefix('<')?;
+        let closing = rest.starts_with('/');
+        if closing {
+            rest = &rest[1..];
+        }
+
+        if !rest.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            return None;
+        }
+        let name_length = rest
+            .find(|c: char| !c.is_ascii_alphanumeric() && c != '-')
+            .unwrap_or(rest.len());
+        let name = rest[..name_length].to_string();
+        rest = &rest[name_length..];
+
+        let mut attributes = Vec::new();
+        loop {
+            rest = rest.trim_start();
+            // A `/` that does not end the tag carries no meaning between attributes.
+            if rest.starts_with('/') && !rest.starts_with("/>") {
+                rest = &rest[1..];
+                continue;
+            }
+            if let Some(end) = rest.strip_prefix("/>").or_else(|| rest.strip_prefix('>')) {
+                let tag = Self {
+                    name,
+                    closing,
+                    attributes,
+                };
+                return Some((tag, html.len() - end.len()));
+            }
+
+            let attribute_length = rest
+                .find(|c: char| c.is_whitespace() || matches!(c, '=' | '>' | '/'))
+                .filter(|length| *length > 0)?;
+            let attribute = rest[..attribute_length].to_string();
+            rest = rest[attribute_length..].trim_start();
+
+            let value = match rest.strip_prefix('=') {
+                Some(value) => {
+                    let (value, remaining) = attribute_value(value.trim_start())?;
+                    rest = remaining;
+                    Some(value.to_string())
+                }
+                None => None,
+            };
+            attributes.push((attribute, value));
+        }
+    }
+}
+
+// Splits a quoted or unquoted attribute value from the rest of the tag.
+fn attribute_value(html: &str) -> Option<(&str, &str)> {
+    match html.chars().next()? {
+        quote @ ('"' | '\'') => {
+            let value = &html[1..];
+            let end = value.find(quote)?;
+            Some((&value[..end], &value[end + 1..]))
+        }
+        _ => {
+            let end = html
+                .find(|c: char| c.is_whitespace() || c == '>')
+                .unwrap_or(html.len());
+            Some((&html[..end], &html[end..]))
+        }
+    }
+}
+
+/// Neutralizes unsafe link and image destinations once internal links are resolved.
+pub(super) fn destination(event: Event<'_>) -> Event<'_> {
     match event {
         Event::Start(Tag::Link {
             link_type,
@@ -136,7 +455,13 @@ mod tests {
     fn sanitize(markdown: &str) -> String {
         let parser = Parser::new_ext(markdown, Options::empty());
         let mut output = String::new();
-        html::push_html(&mut output, events(parser).into_iter());
+        html::push_html(
+            &mut output,
+            raw_html(parser.into_offset_iter(), &RawHtmlConfig::default())
+                .0
+                .into_iter()
+                .map(destination),
+        );
         output
     }
 
@@ -154,12 +479,144 @@ mod tests {
 
     #[rstest]
     #[case::script("<script>alert('xss')</script>")]
-    #[case::custom_div(r#"<div class="custom"><span>hello</span></div>"#)]
-    #[case::inline_span("<span>inline</span>")]
-    fn test_raw_html_is_escaped(#[case] markdown: &str) {
+    #[case::style("<style>body { display: none; }</style>")]
+    #[case::iframe(r#"<iframe src="https://example.com"></iframe>"#)]
+    #[case::inline_form("Text <form action=\"/login\">")]
+    fn test_disallowed_raw_html_is_escaped(#[case] markdown: &str) {
         let result = sanitize(markdown);
 
         assert!(result.contains("&lt;"), "unexpected html:\n{result}");
+        assert!(!result.contains("<script") && !result.contains("<iframe"));
+    }
+
+    #[rstest]
+    #[case::details(
+        "<details open>\n<summary>More</summary>\n\nHidden text.\n\n</details>",
+        "<details open>\n<summary>More</summary>\n<p>Hidden text.</p>\n</details>"
+    )]
+    #[case::inline_tags(
+        "Press <kbd>Ctrl</kbd>+<kbd>C</kbd>, x<sup>2</sup><br>next",
+        "<p>Press <kbd>Ctrl</kbd>+<kbd>C</kbd>, x<sup>2</sup><br>next</p>\n"
+    )]
+    #[case::ruby(
+        "<ruby>漢字<rp>(</rp><rt>かんじ</rt><rp>)</rp></ruby>",
+        "<p><ruby>漢字<rp>(</rp><rt>かんじ</rt><rp>)</rp></ruby></p>\n"
+    )]
+    #[case::self_closing("Line<br/>break", "<p>Line<br>break</p>\n")]
+    #[case::comment("Visible<!-- hidden --> text", "<p>Visible text</p>\n")]
+    fn test_allowed_raw_html_is_preserved(#[case] markdown: &str, #[case] expected: &str) {
+        assert_eq!(sanitize(markdown), expected);
+    }
+
+    #[rstest]
+    #[case::event_handler(
+        r#"<span onclick="alert(1)" title="tip">x</span>"#,
+        r#"<span title="tip">x</span>"#
+    )]
+    #[case::style_and_class(
+        r#"<div style="position:fixed" class="bookmark">x</div>"#,
+        "<div>x</div>"
+    )]
+    #[case::unsafe_href(r#"<a href="javascript:alert(1)">x</a>"#, r##"<a href="#">x</a>"##)]
+    #[case::entity_encoded_href(
+        r#"<a href="javascript&#58;alert(1)">x</a>"#,
+        r##"<a href="#">x</a>"##
+    )]
+    #[case::safe_image(
+        r#"<img src="https://example.com/a.png?x=1&amp;y=2" alt='A "quoted" alt' loading=lazy>"#,
+        r#"<img src="https://example.com/a.png?x=1&amp;y=2" alt="A &quot;quoted&quot; alt">"#
+    )]
+    fn test_raw_html_attributes_are_filtered(#[case] markdown: &str, #[case] expected: &str) {
+        let result = sanitize(markdown);
+
+        assert!(result.contains(expected), "unexpected html:\n{result}");
+    }
+
+    #[rstest]
+    #[case::srcset(
+        r#"<img srcset="/a.png 1x, javascript:alert(1) 2x">"#,
+        r#"<img srcset="/a.png 1x, # 2x">"#
+    )]
+    #[case::poster(
+        r#"<video poster="javascript:alert(1)"></video>"#,
+        r##"<video poster="#">"##
+    )]
+    #[case::form_action(
+        r#"<button formaction="javascript:alert(1)">x</button>"#,
+        r##"<button formaction="#">"##
+    )]
+    #[case::data(r#"<video data="data:text/html,x"></video>"#, r##"<video data="#">"##)]
+    #[case::background(
+        r#"<table background="javascript:alert(1)"></table>"#,
+        r##"<table background="#">"##
+    )]
+    fn test_configured_url_attributes_are_checked(#[case] markdown: &str, #[case] expected: &str) {
+        let mut config = RawHtmlConfig::default();
+        for (tag, attribute) in [
+            ("img", "srcset"),
+            ("video", "poster"),
+            ("video", "data"),
+            ("button", "formaction"),
+            ("table", "background"),
+        ] {
+            config
+                .tags
+                .entry(tag.to_string())
+                .or_default()
+                .push(attribute.to_string());
+        }
+        let parser = Parser::new_ext(markdown, Options::empty());
+        let mut result = String::new();
+        html::push_html(
+            &mut result,
+            raw_html(parser.into_offset_iter(), &config).0.into_iter(),
+        );
+
+        assert!(result.contains(expected), "unexpected html:\n{result}");
+        assert!(!result.contains("javascript:") && !result.contains("data:"));
+    }
+
+    #[test]
+    fn test_configured_allowlist_replaces_the_default() {
+        let mut config = RawHtmlConfig::default();
+        config.tags.remove("img");
+        config
+            .tags
+            .insert("section".to_string(), vec!["id".to_string()]);
+        let parser = Parser::new_ext(
+            r#"<section id="intro" class="x"><img src="/a.png"></section>"#,
+            Options::empty(),
+        );
+        let mut result = String::new();
+        html::push_html(
+            &mut result,
+            raw_html(parser.into_offset_iter(), &config).0.into_iter(),
+        );
+
+        assert_eq!(
+            result,
+            r#"<section id="intro">&lt;img src="/a.png"&gt;</section>"#
+        );
+    }
+
+    #[test]
+    fn test_unmatched_closing_tag_is_escaped() {
+        let result = sanitize("</div>\n\ntext");
+
+        assert!(
+            result.starts_with("&lt;/div&gt;"),
+            "unexpected html:\n{result}"
+        );
+    }
+
+    #[test]
+    fn test_unclosed_tags_are_closed_at_document_end() {
+        let result = sanitize("<details>\n<summary>Open</summary>\n\nBody");
+
+        assert!(
+            result.ends_with("<p>Body</p>\n</details>\n"),
+            "unexpected html:\n{result}"
+        );
     }
 
     #[rstest]
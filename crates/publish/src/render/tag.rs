@@ -0,0 +1,104 @@
+use super::{comment::strip_comments, html::markdown_options};
+use pulldown_cmark::{Event, Parser, Tag, TagEnd};
+use regex::Regex;
+use std::sync::LazyLock;
+
+// Obsidian tags need at least one non-numeric character and may be nested with `/`.
+static INLINE_TAG_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?:^|\s)#([\w/-]*[^\W\d][\w/-]*)").expect("Invalid inline tag regex pattern")
+});
+
+/// Marks up inline `#tags`, returning the tags in document order.
+pub(super) fn events(events: Vec<Event<'_>>) -> (Vec<Event<'_>>, Vec<String>) {
+    let mut result = Vec::with_capacity(events.len());
+    let mut tags = Vec::new();
+
+    let mut in_code_block = false;
+
+    for event in events {
+        match &event {
+            Event::Start(Tag::CodeBlock(_)) => in_code_block = true,
+            Event::End(TagEnd::CodeBlock) => in_code_block = false,
+            _ => {}
+        }
+        let Event::Text(text) = &event else {
+            result.push(event);
+            continue;
+        };
+        if in_code_block || !INLINE_TAG_RE.is_match(text) {
+            result.push(event);
+            continue;
+        }
+
+        let mut cursor = 0;
+        for captures in INLINE_TAG_RE.captures_iter(text) {
+            let tag = captures.get(1).expect("inline tag capture");
+            // Include the `#` that precedes the captured name.
+            let start = tag.start() - 1;
+            if cursor < start {
+                result.push(Event::Text(text[cursor..start].to_string().into()));
+            }
+            result.push(Event::Html(r#"<span class="tag">"#.into()));
+            result.push(Event::Text(text[start..tag.end()].to_string().into()));
+            result.push(Event::Html("</span>".into()));
+            tags.push(tag.as_str().to_string());
+            cursor = tag.end();
+        }
+        if cursor < text.len() {
+            result.push(Event::Text(text[cursor..].to_string().into()));
+        }
+    }
+
+    (result, tags)
+}
+
+/// Unique inline tags written in a note body, excluding code and comments.
+pub(crate) fn collect_inline_tags(markdown: &str) -> Vec<String> {
+    let markdown = strip_comments(markdown);
+    let (_, inline_tags) = events(Parser::new_ext(&markdown, markdown_options()).collect());
+
+    let mut tags = Vec::new();
+    for tag in inline_tags {
+        if !tags.contains(&tag) {
+            tags.push(tag);
+        }
+    }
+    tags
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pulldown_cmark::html;
+    use rstest::rstest;
+
+    fn render(markdown: &str) -> String {
+        let parser = Parser::new_ext(markdown, markdown_options());
+        let mut output = String::new();
+        html::push_html(&mut output, events(parser.collect()).0.into_iter());
+        output
+    }
+
+    #[rstest]
+    #[case::ascii(
+        "Notes on #rust today",
+        "<p>Notes on <span class=\"tag\">#rust</span> today</p>\n"
+    )]
+    #[case::nested_and_japanese(
+        "#physics/量子 and #統計",
+        "<p><span class=\"tag\">#physics/量子</span> and <span class=\"tag\">#統計</span></p>\n"
+    )]
+    #[case::numeric_only("Issue #123", "<p>Issue #123</p>\n")]
+    #[case::inside_word("C# and a#b", "<p>C# and a#b</p>\n")]
+    fn test_inline_tags_are_marked_up(#[case] markdown: &str, #[case] expected: &str) {
+        assert_eq!(render(markdown), expected);
+    }
+
+    #[test]
+    fn test_collect_inline_tags_skips_code_and_comments() {
+        let markdown =
+            "#rust and #wasm\n\n`#code`\n\n```\n#fenced\n```\n\n%% #private %%\n\n#rust again";
+
+        assert_eq!(collect_inline_tags(markdown), vec!["rust", "wasm"]);
+    }
+}
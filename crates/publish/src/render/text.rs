@@ -0,0 +1,218 @@
+use domain::ReadingStats;
+use scraper::{ElementRef, Html, Selector, node::Node};
+
+/// Blocks whose text is not read as prose: rendered math, bookmark cards, and transclusions,
+/// which belong to the note they embed. Code blocks are skipped by tag.
+const SKIPPED_CLASSES: &[&str] = &["math", "bookmark", "transclusion"];
+/// Longest excerpt in characters before it is cut back to a sentence boundary.
+const EXCERPT_MAX_CHARS: usize = 120;
+
+/// Measures the prose of a rendered body, leaving out code blocks, math, bookmark cards, and
+/// transcluded notes.
+pub(super) fn reading_stats(html: &str) -> ReadingStats {
+    let fragment = Html::parse_fragment(html);
+    let mut text = String::new();
+    push_prose(fragment.root_element(), &mut text);
+    ReadingStats::measure(&text)
+}
+
+/// Plain-text opening of the first paragraphs, cut at the last sentence end that fits.
+///
+/// Headings, code blocks, math, bookmark cards, and transcluded notes with their source links are
+/// never part of the excerpt.
+pub(super) fn excerpt(html: &str) -> Option<String> {
+    let fragment = Html::parse_fragment(html);
+    let paragraph = Selector::parse("p").expect("paragraph selector must be valid");
+    let mut excerpt = String::new();
+    for element in fragment.select(&paragraph) {
+        if element
+            .ancestors()
+            .filter_map(ElementRef::wrap)
+            .any(is_skipped)
+        {
+            continue;
+        }
+        let mut text = String::new();
+        push_prose(element, &mut text);
+        let text = collapse_whitespace(&text);
+        if text.is_empty() {
+            continue;
+        }
+        if !excerpt.is_empty() && !excerpt.ends_with(is_japanese_sentence_end) {
+            excerpt.push(' ');
+        }
+        excerpt.push_str(&text);
+        if excerpt.chars().count() >= EXCERPT_MAX_CHARS {
+            break;
+        }
+    }
+
+    (!excerpt.is_empty()).then(|| truncate_at_sentence(&excerpt))
+}
+
+fn push_prose(root: ElementRef<'_>, text: &mut String) {
+    for node in root.descendants() {
+        if let Node::Text(node_text) = node.value()
+            && !node
+                .ancestors()
+                .filter_map(ElementRef::wrap)
+                .any(is_skipped)
+        {
+            text.push_str(node_text);
+        }
+    }
+}
+
+fn is_skipped(element: ElementRef<'_>) -> bool {
+    let element = element.value();
+    matches!(element.name(), "pre" | "math")
+        || element
+            .classes()
+            .any(|class| SKIPPED_CLASSES.contains(&class))
+}
+
+/// Collapses whitespace runs to one space, dropping soft line breaks inside Japanese text.
+fn collapse_whitespace(text: &str) -> String {
+    let mut collapsed = String::with_capacity(text.len());
+    let mut pending = None;
+    for ch in text.chars() {
+        if ch.is_whitespace() {
+            pending = Some(pending.unwrap_or(false) || ch == '\n');
+            continue;
+        }
+        if let Some(has_newline) = pending.take() {
+            let joins_japanese = collapsed
+                .chars()
+                .next_back()
+                .is_some_and(|prev| !prev.is_ascii())
+                || !ch.is_ascii();
+            if !collapsed.is_empty() && !(has_newline && joins_japanese) {
+                collapsed.push(' ');
+            }
+        }
+        collapsed.push(ch);
+    }
+    collapsed
+}
+
+fn truncate_at_sentence(text: &str) -> String {
+    if text.chars().count() <= EXCERPT_MAX_CHARS {
+        return text.to_string();
+    }
+
+    let mut end = None;
+    let mut after_sentence_end = false;
+    for (index, ch) in text.char_indices().take(EXCERPT_MAX_CHARS) {
+        let next = text[index + ch.len_utf8()..].chars().next();
+        let closes_sentence = is_japanese_sentence_end(ch)
+            || (matches!(ch, '.' | '!' | '?') && next.is_none_or(char::is_whitespace))
+            // Closing brackets stay with the sentence they quote, as in 「そうです。」.
+            || (after_sentence_end && matches!(ch, '」' | '』' | '）' | ')'));
+        if closes_sentence {
+            end = Some(index + ch.len_utf8());
+        }
+        after_sentence_end = closes_sentence;
+    }
+
+    match end {
+        Some(end) => text[..end].to_string(),
+        None => {
+            let head: String = text.chars().take(EXCERPT_MAX_CHARS - 1).collect();
+            format!("{}…", head.trim_end())
+        }
+    }
+}
+
+fn is_japanese_sentence_end(ch: char) -> bool {
+    matches!(ch, '。' | '！' | '？')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BODY_WITH_SKIPPED_BLOCKS: &str = concat!(
+        "<h2 id=\"intro\">はじめに</h2>\n",
+        "<p>本文と<code>inline</code>です。",
+        "<span class=\"math math-inline\"><math><annotation encoding=\"application/x-tex\">x^2</annotation></math></span></p>\n",
+        "<pre><code class=\"language-rust\">fn main() {}</code></pre>\n",
+        "<div class=\"bookmark\"><p><a href=\"https://example.com\">Example</a></p></div>\n",
+        "<p>次の段落\nです。</p>\n",
+    );
+
+    #[test]
+    fn reading_stats_skip_code_blocks_math_and_bookmarks() {
+        assert_eq!(
+            reading_stats(BODY_WITH_SKIPPED_BLOCKS),
+            ReadingStats {
+                char_count: 23,
+                reading_minutes: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn reading_stats_skip_transcluded_notes() {
+        let html = concat!(
+            "<p>本文です。</p>\n",
+            "<div class=\"transclusion\">\n<div class=\"transclusion-body\">\n",
+            "<p>埋め込んだノートの本文です。</p>\n</div>\n",
+            "<a class=\"transclusion-source\" href=\"/tech/other\">Other</a>\n</div>\n",
+        );
+
+        assert_eq!(reading_stats(html), reading_stats("<p>本文です。</p>"));
+    }
+
+    #[test]
+    fn excerpt_joins_paragraphs_without_headings_code_math_or_bookmarks() {
+        assert_eq!(
+            excerpt(BODY_WITH_SKIPPED_BLOCKS).as_deref(),
+            Some("本文とinlineです。次の段落です。")
+        );
+        assert_eq!(
+            excerpt("<p>First line\nsecond line.</p><p>Next.</p>").as_deref(),
+            Some("First line second line. Next.")
+        );
+        assert_eq!(excerpt("<h1>Title</h1><pre><code>code</code></pre>"), None);
+    }
+
+    #[test]
+    fn excerpt_skips_transcluded_notes() {
+        let html = concat!(
+            "<div class=\"transclusion\">\n<div class=\"transclusion-body\">\n",
+            "<p>埋め込んだノートの本文です。</p>\n</div>\n",
+            "<p><a class=\"transclusion-source\" href=\"/tech/other\">Other › Setup</a></p>\n</div>\n",
+            "<p>本文です。</p>\n",
+        );
+
+        assert_eq!(excerpt(html).as_deref(), Some("本文です。"));
+    }
+
+    #[test]
+    fn excerpt_is_cut_at_the_last_sentence_end_that_fits() {
+        let sentence = "吾輩は猫である。";
+        let html = format!("<p>{}</p>", sentence.repeat(20));
+        assert_eq!(excerpt(&html), Some(sentence.repeat(EXCERPT_MAX_CHARS / 8)));
+
+        let html = format!("<p>{}「そうです。」と答えた。</p>", "あ".repeat(110));
+        assert_eq!(
+            excerpt(&html),
+            Some(format!("{}「そうです。」", "あ".repeat(110)))
+        );
+    }
+
+    #[test]
+    fn excerpt_without_sentence_end_is_cut_with_ellipsis() {
+        let html = format!("<p>{}</p>", "あ".repeat(200));
+        let excerpt = excerpt(&html).unwrap();
+
+        assert_eq!(excerpt.chars().count(), EXCERPT_MAX_CHARS);
+        assert!(excerpt.ends_with("あ…"));
+    }
+
+    #[test]
+    fn excerpt_does_not_cut_inside_version_numbers() {
+        let html = format!("<p>Version 1.2 is out. {}</p>", "word ".repeat(40));
+        assert_eq!(excerpt(&html).as_deref(), Some("Version 1.2 is out."));
+    }
+}
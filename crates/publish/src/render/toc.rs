@@ -0,0 +1,66 @@
+use super::anchor::heading_text;
+use domain::TocEntry;
+use pulldown_cmark::{Event, Tag, TagEnd};
+
+/// Collects the heading outline once anchors have assigned heading IDs.
+pub(super) fn collect(events: &[Event<'_>]) -> Vec<TocEntry> {
+    let mut toc = Vec::new();
+    let mut heading = None;
+
+    for (index, event) in events.iter().enumerate() {
+        match event {
+            Event::Start(Tag::Heading {
+                level,
+                id: Some(id),
+                ..
+            }) => heading = Some((index, *level as u8, id)),
+            Event::End(TagEnd::Heading(_)) => {
+                if let Some((start, level, id)) = heading.take() {
+                    toc.push(TocEntry {
+                        level,
+                        text: heading_text(&events[start + 1..index]).trim().to_string(),
+                        id: id.to_string(),
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    toc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render::{anchor::assign_anchors, html::markdown_options};
+    use pulldown_cmark::Parser;
+
+    fn toc(markdown: &str) -> Vec<(u8, String, String)> {
+        let events = Parser::new_ext(markdown, markdown_options()).collect();
+        collect(&assign_anchors(events).0)
+            .into_iter()
+            .map(|entry| (entry.level, entry.text, entry.id))
+            .collect()
+    }
+
+    #[test]
+    fn test_collect_follows_heading_ids() {
+        let toc = toc("# Intro\n\n## `Setup` step\n\ntext\n\n## Setup step\n\n### 数式 $x$");
+
+        assert_eq!(
+            toc,
+            vec![
+                (1, "Intro".to_string(), "intro".to_string()),
+                (2, "Setup step".to_string(), "setup-step".to_string()),
+                (2, "Setup step".to_string(), "setup-step-1".to_string()),
+                (3, "数式 x".to_string(), "数式-x".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_collect_ignores_code_block_headings() {
+        assert!(toc("```\n# not a heading\n```").is_empty());
+    }
+}
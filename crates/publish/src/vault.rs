@@ -1,7 +1,9 @@
+mod cache;
 mod parser;
 mod scanner;
+mod strict;
 
-pub(crate) use parser::{
-    ContentKind, ObsidianFrontMatter, ParsedObsidianFile, parse_obsidian_file,
-};
-pub(crate) use scanner::{scan_markdown_files, validate_obsidian_dir};
+pub(crate) use cache::SourceCache;
+pub(crate) use parser::{ContentKind, ObsidianFrontMatter, ParsedObsidianFile};
+pub(crate) use scanner::{scan_attachment_files, scan_markdown_files, validate_obsidian_dir};
+pub(crate) use strict::StrictFrontmatter;
@@ -0,0 +1,110 @@
+use super::parser::{ParsedObsidianFile, parse_obsidian_file};
+use crate::assets::Attachment;
+use crate::error::Result;
+use std::{
+    collections::{BTreeSet, HashMap},
+    path::{Path, PathBuf},
+};
+
+/// Parsed notes and hashed attachments kept between watch rebuilds, so a rebuild reads only the
+/// vault files that changed.
+#[derive(Default)]
+pub(crate) struct SourceCache {
+    notes: HashMap<PathBuf, Option<ParsedObsidianFile>>,
+    attachments: HashMap<PathBuf, Attachment>,
+}
+
+impl SourceCache {
+    /// Forgets every file at or under a changed path, so the next rebuild reads it again.
+    ///
+    /// A changed directory covers the files in it, since a renamed or removed directory is
+    /// reported without them.
+    pub(crate) fn forget(&mut self, changed: &BTreeSet<PathBuf>) {
+        let is_unchanged = |path: &Path| !changed.iter().any(|changed| path.starts_with(changed));
+        self.notes.retain(|path, _| is_unchanged(path));
+        self.attachments.retain(|path, _| is_unchanged(path));
+    }
+
+    /// Parses a note unless it is cached; files that fail to parse are read again every time.
+    pub(crate) fn parse(&mut self, path: &Path) -> Result<Option<ParsedObsidianFile>> {
+        if let Some(parsed_file) = self.notes.get(path) {
+            return Ok(parsed_file.clone());
+        }
+        let parsed_file = parse_obsidian_file(path)?;
+        self.notes.insert(path.to_path_buf(), parsed_file.clone());
+        Ok(parsed_file)
+    }
+
+    /// Splits attachments into cached hashes and paths still to hash.
+    pub(crate) fn split_attachments(&self, paths: Vec<PathBuf>) -> (Vec<Attachment>, Vec<PathBuf>) {
+        let mut hashed = Vec::new();
+        let mut unhashed = Vec::new();
+        for path in paths {
+            match self.attachments.get(&path) {
+                Some(attachment) => hashed.push(attachment.clone()),
+                None => unhashed.push(path),
+            }
+        }
+        (hashed, unhashed)
+    }
+
+    /// Keeps fresh hashes of attachments returned by [`SourceCache::split_attachments`].
+    pub(crate) fn insert_attachments(&mut self, attachments: &[Attachment]) {
+        self.attachments.extend(
+            attachments
+                .iter()
+                .map(|attachment| (attachment.source_path.clone(), attachment.clone())),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assets::hash_attachments;
+    use indoc::formatdoc;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn note(title: &str) -> String {
+        formatdoc! {r#"
+            ---
+            title: {title}
+            created: "2025-01-01T00:00:00+09:00"
+            updated: "2025-01-01T00:00:00+09:00"
+            is_completed: true
+            ---
+            Body
+        "#}
+    }
+
+    #[test]
+    fn test_files_are_read_again_only_once_forgotten() {
+        let temp_dir = TempDir::new().unwrap();
+        let note_path = temp_dir.path().join("tech/note.md");
+        let image_path = temp_dir.path().join("tech/image.png");
+        fs::create_dir_all(temp_dir.path().join("tech")).unwrap();
+        fs::write(&note_path, note("Before")).unwrap();
+        fs::write(&image_path, "before").unwrap();
+        let title =
+            |cache: &mut SourceCache| cache.parse(&note_path).unwrap().unwrap().front_matter.title;
+        let mut cache = SourceCache::default();
+
+        assert_eq!(title(&mut cache), "Before");
+        let (hashed, unhashed) = cache.split_attachments(vec![image_path.clone()]);
+        assert!(hashed.is_empty());
+        let before = hash_attachments(unhashed, temp_dir.path()).unwrap();
+        cache.insert_attachments(&before);
+
+        fs::write(&note_path, note("After")).unwrap();
+        fs::write(&image_path, "after").unwrap();
+        assert_eq!(title(&mut cache), "Before");
+        let (hashed, unhashed) = cache.split_attachments(vec![image_path.clone()]);
+        assert_eq!((hashed, unhashed.len()), (before, 0));
+
+        cache.forget(&BTreeSet::from([temp_dir.path().join("tech")]));
+        assert_eq!(title(&mut cache), "After");
+        let (hashed, unhashed) = cache.split_attachments(vec![image_path.clone()]);
+        assert_eq!((hashed.len(), unhashed), (0, vec![image_path]));
+    }
+}
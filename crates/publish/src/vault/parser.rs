@@ -1,8 +1,27 @@
 use crate::error::{PublishError, Result};
 use serde::Deserialize;
-use std::{fs, path::Path};
-
-#[derive(Debug, Deserialize, PartialEq, Eq, Clone)]
+use std::{collections::HashMap, fs, path::Path};
+
+/// Keys `ObsidianFrontMatter` reads; strict mode reports any other key as a likely typo.
+pub(crate) const FRONT_MATTER_FIELDS: &[&str] = &[
+    "title",
+    "kind",
+    "tags",
+    "summary",
+    "is_completed",
+    "priority",
+    "created",
+    "updated",
+    "category",
+    "page",
+    "slug",
+    "aliases",
+    "series",
+    "series_order",
+    "publish_at",
+];
+
+#[derive(Debug, Deserialize, PartialEq, Eq, Hash, Clone)]
 pub(crate) struct ObsidianFrontMatter {
     pub(crate) title: String,
     #[serde(default)]
@@ -18,15 +37,54 @@ pub(crate) struct ObsidianFrontMatter {
     pub(crate) category: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub(crate) page: Option<String>,
+    /// Explicit article slug that replaces the generated hash.
+    pub(crate) slug: Option<String>,
+    /// Former article slugs or `/category/slug` paths that redirect to this article.
+    #[serde(default)]
+    pub(crate) aliases: Vec<String>,
+    /// Key of the multi-part series this article belongs to.
+    pub(crate) series: Option<String>,
+    /// One-based part number within `series`.
+    pub(crate) series_order: Option<u32>,
+    /// Release time before which the content is not published.
+    pub(crate) publish_at: Option<String>,
+}
+
+#[cfg(test)]
+impl ObsidianFrontMatter {
+    /// Completed front matter of `kind` titled "Test", with equal timestamps and no optional fields.
+    pub(crate) fn for_test(kind: ContentKind) -> Self {
+        Self {
+            title: "Test".to_string(),
+            kind,
+            tags: None,
+            summary: None,
+            is_completed: true,
+            priority: None,
+            created: "2025-01-01T00:00:00+09:00".to_string(),
+            updated: "2025-01-01T00:00:00+09:00".to_string(),
+            category: None,
+            page: None,
+            slug: None,
+            aliases: vec![],
+            series: None,
+            series_order: None,
+            publish_at: None,
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub(crate) struct ParsedObsidianFile {
     pub(crate) front_matter: ObsidianFrontMatter,
     pub(crate) markdown_body: String,
+    /// One-based line of each top-level frontmatter key, for diagnostics.
+    pub(crate) field_lines: HashMap<String, usize>,
+    /// One-based line where `markdown_body` starts in the file.
+    pub(crate) body_line: usize,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Default)]
 #[serde(rename_all = "lowercase")]
 pub(crate) enum ContentKind {
     #[default]
@@ -47,16 +105,39 @@ pub(crate) fn parse_obsidian_file(path: impl AsRef<Path>) -> Result<Option<Parse
     let content = fs::read_to_string(&path)?;
     match split_frontmatter(&content)? {
         FrontmatterSplit::Complete { yaml, body } => {
-            let front_matter = serde_yaml::from_str::<ObsidianFrontMatter>(yaml)?;
+            let body_offset = content.len() - body.len();
+            let body_line = line_at(&content, body_offset);
+            let yaml_line = line_at(&content, body_offset - "\n---\n".len() - yaml.len());
+            // Leading newlines make YAML error locations count lines from the top of the file.
+            let padded_yaml = format!("{}{yaml}", "\n".repeat(yaml_line - 1));
+            let front_matter = serde_yaml::from_str::<ObsidianFrontMatter>(&padded_yaml)?;
             Ok(Some(ParsedObsidianFile {
                 front_matter,
                 markdown_body: body.to_owned(),
+                field_lines: field_lines(yaml, yaml_line),
+                body_line,
             }))
         }
         FrontmatterSplit::NoFrontmatter => Ok(None),
     }
 }
 
+fn line_at(content: &str, offset: usize) -> usize {
+    content[..offset].matches('\n').count() + 1
+}
+
+/// Lines of unindented `key:` entries, which are the frontmatter fields.
+fn field_lines(yaml: &str, first_line: usize) -> HashMap<String, usize> {
+    yaml.lines()
+        .zip(first_line..)
+        .filter(|(line, _)| !line.starts_with([' ', '\t', '#', '-']))
+        .filter_map(|(line, number)| {
+            let (key, _) = line.split_once(':')?;
+            Some((key.trim().trim_matches(['"', '\'']).to_string(), number))
+        })
+        .collect()
+}
+
 fn split_frontmatter(content: &str) -> Result<FrontmatterSplit<'_>> {
     let trimmed = content.trim_start();
     let Some(rest) = trimmed.strip_prefix("---\n") else {
@@ -139,6 +220,40 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_obsidian_frontmatter_reads_slug_and_aliases() {
+        let frontmatter: ObsidianFrontMatter = serde_yaml::from_str(indoc! {r#"
+            title: "Stable Article"
+            is_completed: true
+            created: "2025-01-01T00:00:00+09:00"
+            updated: "2025-01-01T00:00:00+09:00"
+            category: "tech"
+            slug: "stable-article"
+            aliases: ["0123456789ab", "/daily/old-article"]
+        "#})
+        .unwrap();
+
+        assert_eq!(frontmatter.slug.as_deref(), Some("stable-article"));
+        assert_eq!(frontmatter.aliases, ["0123456789ab", "/daily/old-article"]);
+    }
+
+    #[test]
+    fn test_obsidian_frontmatter_reads_series() {
+        let frontmatter: ObsidianFrontMatter = serde_yaml::from_str(indoc! {r#"
+            title: "Async Basics"
+            is_completed: true
+            created: "2025-01-01T00:00:00+09:00"
+            updated: "2025-01-01T00:00:00+09:00"
+            category: "tech"
+            series: "rust-async"
+            series_order: 2
+        "#})
+        .unwrap();
+
+        assert_eq!(frontmatter.series.as_deref(), Some("rust-async"));
+        assert_eq!(frontmatter.series_order, Some(2));
+    }
+
     #[rstest]
     #[case::valid_frontmatter(
         indoc! {r#"
@@ -491,4 +606,68 @@ mod tests {
         assert_eq!(parsed_file.markdown_body, expected_body);
         Ok(())
     }
+
+    #[test]
+    fn test_parse_obsidian_file_records_field_and_body_lines() -> Result<()> {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("lines.md");
+        fs::write(
+            &file_path,
+            indoc! {r#"
+
+                ---
+                title: Test
+                tags:
+                  - rust
+                is_completed: true
+                created: "2025-01-01T00:00:00+09:00"
+                updated: "2025-01-01T00:00:00+09:00"
+                ---
+                # Content
+            "#},
+        )?;
+
+        let parsed_file = parse_obsidian_file(&file_path)?.unwrap();
+
+        assert_eq!(parsed_file.field_lines["title"], 3);
+        assert_eq!(parsed_file.field_lines["is_completed"], 6);
+        assert!(!parsed_file.field_lines.contains_key("- rust"));
+        assert_eq!(parsed_file.body_line, 10);
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_obsidian_file_reports_yaml_errors_at_file_lines() {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("invalid.md");
+        fs::write(
+            &file_path,
+            "---\ntitle: Test\nis_completed: maybe\ncreated: x\nupdated: x\n---\nBody\n",
+        )
+        .unwrap();
+
+        let Err(PublishError::Yaml(error)) = parse_obsidian_file(&file_path) else {
+            panic!("invalid frontmatter must fail with a YAML error");
+        };
+
+        assert_eq!(error.location().map(|location| location.line()), Some(3));
+    }
+
+    #[test]
+    fn test_front_matter_fields_are_all_read() {
+        let base = "title: Test\nis_completed: true\ncreated: x\nupdated: x\n";
+        assert!(serde_yaml::from_str::<ObsidianFrontMatter>(base).is_ok());
+
+        // A mapping is invalid for every field, so only keys the struct reads fail to parse.
+        for field in FRONT_MATTER_FIELDS {
+            let yaml = format!(
+                "{}{field}: {{ nested: true }}\n",
+                base.replace(&format!("{field}: "), "_: ")
+            );
+            assert!(
+                serde_yaml::from_str::<ObsidianFrontMatter>(&yaml).is_err(),
+                "{field} is not read by ObsidianFrontMatter"
+            );
+        }
+    }
 }
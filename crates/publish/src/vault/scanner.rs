@@ -1,10 +1,20 @@
 use crate::error::{PublishError, Result};
+use domain::AssetName;
 use ignore::WalkBuilder;
 use std::path::{Path, PathBuf};
 
 /// Scans the specified directory for Markdown files (.md) and returns their paths.
 pub(crate) fn scan_markdown_files(vault_dir: impl AsRef<Path>) -> Result<Vec<PathBuf>> {
-    let mut md_files: Vec<PathBuf> = WalkBuilder::new(vault_dir.as_ref())
+    scan_files(vault_dir.as_ref(), |ext| ext.eq_ignore_ascii_case("md"))
+}
+
+/// Scans the specified directory for publishable attachments such as images, PDFs, and audio.
+pub(crate) fn scan_attachment_files(vault_dir: impl AsRef<Path>) -> Result<Vec<PathBuf>> {
+    scan_files(vault_dir.as_ref(), AssetName::supports_extension)
+}
+
+fn scan_files(vault_dir: &Path, accepts_extension: impl Fn(&str) -> bool) -> Result<Vec<PathBuf>> {
+    let mut files: Vec<PathBuf> = WalkBuilder::new(vault_dir)
         .hidden(true)
         .git_ignore(false)
         .build()
@@ -15,13 +25,13 @@ pub(crate) fn scan_markdown_files(vault_dir: impl AsRef<Path>) -> Result<Vec<Pat
                 && path
                     .extension()
                     .and_then(|s| s.to_str())
-                    .is_some_and(|ext| ext.eq_ignore_ascii_case("md")))
+                    .is_some_and(&accepts_extension))
             .then(|| entry.into_path())
         })
         .collect();
 
-    md_files.sort_unstable();
-    Ok(md_files)
+    files.sort_unstable();
+    Ok(files)
 }
 
 pub(crate) fn validate_obsidian_dir(obsidian_dir: &Path) -> Result<()> {
@@ -100,4 +110,38 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_scan_attachment_files() -> Result<()> {
+        let temp_dir = TempDir::new().unwrap();
+        let base_path = temp_dir.path();
+        fs::create_dir_all(base_path.join("attachments"))?;
+
+        for file_path in [
+            "attachments/diagram.png",
+            "attachments/Paper.PDF",
+            "talk.mp3",
+            "note.md",
+            "config.json",
+        ] {
+            fs::write(base_path.join(file_path), "content")?;
+        }
+
+        let files = scan_attachment_files(base_path)?;
+        let names: Vec<_> = files
+            .iter()
+            .map(|path| path.strip_prefix(base_path).unwrap().to_path_buf())
+            .collect();
+
+        assert_eq!(
+            names,
+            vec![
+                PathBuf::from("attachments/Paper.PDF"),
+                PathBuf::from("attachments/diagram.png"),
+                PathBuf::from("talk.mp3"),
+            ]
+        );
+
+        Ok(())
+    }
 }
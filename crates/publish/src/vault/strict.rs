@@ -0,0 +1,335 @@
+use super::parser::{ContentKind, FRONT_MATTER_FIELDS, ObsidianFrontMatter};
+use crate::diagnostics::{Diagnostic, DiagnosticCode};
+use chrono::{DateTime, FixedOffset};
+use domain::{Tag, TagSynonyms, Timestamp};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Largest edit distance at which an unknown key is taken for a typo of a known one.
+const MAX_TYPO_DISTANCE: usize = 2;
+
+/// Opt-in frontmatter checks for mistakes that publishing alone would silently accept.
+///
+/// Files are checked one by one as they are classified; offsets are compared across the vault
+/// once every file has been seen.
+pub(crate) struct StrictFrontmatter<'a> {
+    tag_synonyms: &'a TagSynonyms,
+    findings: Vec<Diagnostic>,
+    timestamps: Vec<TimestampField>,
+}
+
+/// A frontmatter timestamp recorded for the vault-wide offset check.
+struct TimestampField {
+    diagnostic: Diagnostic,
+    field: &'static str,
+    offset: FixedOffset,
+}
+
+impl<'a> StrictFrontmatter<'a> {
+    pub(crate) fn new(tag_synonyms: &'a TagSynonyms) -> Self {
+        Self {
+            tag_synonyms,
+            findings: Vec::new(),
+            timestamps: Vec::new(),
+        }
+    }
+
+    pub(crate) fn check_file(
+        &mut self,
+        file_path: &Path,
+        front_matter: &ObsidianFrontMatter,
+        field_lines: &HashMap<String, usize>,
+    ) {
+        let finding = |code, field: &str, message: String| {
+            let diagnostic = Diagnostic::error(code, message).in_file(file_path);
+            match field_lines.get(field) {
+                Some(line) => diagnostic.at(*line, 1),
+                None => diagnostic,
+            }
+        };
+
+        let mut unknown_fields: Vec<_> = field_lines
+            .keys()
+            .filter(|key| !FRONT_MATTER_FIELDS.contains(&key.as_str()))
+            .collect();
+        unknown_fields.sort_unstable();
+        for key in unknown_fields {
+            let message = match closest_field(key) {
+                Some(known) => {
+                    format!("unknown frontmatter field `{key}`; did you mean `{known}`?")
+                }
+                None => format!("unknown frontmatter field `{key}`"),
+            };
+            self.findings
+                .push(finding(DiagnosticCode::UnknownField, key, message));
+        }
+
+        if let (Ok(created), Ok(updated)) = (
+            Timestamp::new(front_matter.created.clone()),
+            Timestamp::new(front_matter.updated.clone()),
+        ) && updated.to_utc() < created.to_utc()
+        {
+            self.findings.push(finding(
+                DiagnosticCode::TimestampOrder,
+                "updated",
+                format!("`updated` {updated} is earlier than `created` {created}"),
+            ));
+        }
+
+        let mut seen: Vec<(Tag, &str)> = Vec::new();
+        for raw_tag in front_matter.tags.iter().flatten() {
+            if raw_tag.trim().is_empty() {
+                self.findings.push(finding(
+                    DiagnosticCode::InvalidTags,
+                    "tags",
+                    "empty tag".to_string(),
+                ));
+                continue;
+            }
+            let Ok(tag) = Tag::new(raw_tag.clone()) else {
+                continue;
+            };
+            let tag = self.tag_synonyms.canonicalize(tag);
+            match seen.iter().find(|(seen_tag, _)| *seen_tag == tag) {
+                Some((_, first)) => self.findings.push(finding(
+                    DiagnosticCode::InvalidTags,
+                    "tags",
+                    format!("duplicate tag `{raw_tag}` is the same tag as `{first}`"),
+                )),
+                None => seen.push((tag, raw_tag)),
+            }
+        }
+
+        if front_matter.page.is_some() && front_matter.kind != ContentKind::Page {
+            self.findings.push(finding(
+                DiagnosticCode::MisplacedField,
+                "page",
+                "`page` is only used with `kind: page`".to_string(),
+            ));
+        }
+        if front_matter.category.is_some()
+            && matches!(front_matter.kind, ContentKind::Page | ContentKind::Home)
+        {
+            self.findings.push(finding(
+                DiagnosticCode::MisplacedField,
+                "category",
+                "`category` is only used by articles and categories".to_string(),
+            ));
+        }
+
+        let timestamps = [
+            ("created", Some(&front_matter.created)),
+            ("updated", Some(&front_matter.updated)),
+            ("publish_at", front_matter.publish_at.as_ref()),
+        ];
+        for (field, value) in timestamps {
+            if let Some(Ok(timestamp)) =
+                value.map(|value| DateTime::parse_from_rfc3339(value.trim()))
+            {
+                self.timestamps.push(TimestampField {
+                    diagnostic: finding(DiagnosticCode::InconsistentOffset, field, String::new()),
+                    field,
+                    offset: *timestamp.offset(),
+                });
+            }
+        }
+    }
+
+    /// Every finding, including timestamps whose offset differs from the one most of the vault uses.
+    pub(crate) fn finish(mut self) -> Vec<Diagnostic> {
+        let mut counts: Vec<(FixedOffset, usize)> = Vec::new();
+        for timestamp in &self.timestamps {
+            match counts
+                .iter_mut()
+                .find(|(offset, _)| *offset == timestamp.offset)
+            {
+                Some((_, count)) => *count += 1,
+                None => counts.push((timestamp.offset, 1)),
+            }
+        }
+        // Ties go to the offset seen first, so the result does not depend on hashing.
+        let Some((vault_offset, _)) = counts.iter().rev().max_by_key(|(_, count)| *count).copied()
+        else {
+            return self.findings;
+        };
+
+        for timestamp in self.timestamps {
+            if timestamp.offset != vault_offset {
+                let mut diagnostic = timestamp.diagnostic;
+                diagnostic.message = format!(
+                    "`{}` uses offset {} while the vault uses {vault_offset}",
+                    timestamp.field, timestamp.offset
+                );
+                self.findings.push(diagnostic);
+            }
+        }
+        self.findings
+    }
+}
+
+fn closest_field(key: &str) -> Option<&'static str> {
+    FRONT_MATTER_FIELDS
+        .iter()
+        .map(|field| (edit_distance(key, field), *field))
+        .filter(|(distance, _)| *distance <= MAX_TYPO_DISTANCE)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, field)| field)
+}
+
+/// Levenshtein distance in characters.
+fn edit_distance(left: &str, right: &str) -> usize {
+    let right: Vec<char> = right.chars().collect();
+    let mut previous: Vec<usize> = (0..=right.len()).collect();
+    for (i, left_char) in left.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, right_char) in right.iter().enumerate() {
+            let substitution = previous[j] + usize::from(left_char != *right_char);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[right.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field_lines(fields: &[&str]) -> HashMap<String, usize> {
+        fields
+            .iter()
+            .zip(2..)
+            .map(|(field, line)| ((*field).to_string(), line))
+            .collect()
+    }
+
+    fn findings(
+        front_matter: &ObsidianFrontMatter,
+        fields: &[&str],
+    ) -> Vec<(DiagnosticCode, usize)> {
+        let synonyms = TagSynonyms::default();
+        let mut strict = StrictFrontmatter::new(&synonyms);
+        strict.check_file(Path::new("note.md"), front_matter, &field_lines(fields));
+        strict
+            .finish()
+            .into_iter()
+            .map(|diagnostic| (diagnostic.code, diagnostic.line.unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn test_unknown_fields_suggest_the_closest_known_key() {
+        let synonyms = TagSynonyms::default();
+        let mut strict = StrictFrontmatter::new(&synonyms);
+        strict.check_file(
+            Path::new("note.md"),
+            &ObsidianFrontMatter::for_test(ContentKind::Article),
+            &field_lines(&["title", "sumary", "weather"]),
+        );
+
+        let messages: Vec<_> = strict
+            .finish()
+            .into_iter()
+            .map(|diagnostic| (diagnostic.line, diagnostic.message))
+            .collect();
+        assert_eq!(
+            messages,
+            [
+                (
+                    Some(3),
+                    "unknown frontmatter field `sumary`; did you mean `summary`?".to_string()
+                ),
+                (Some(4), "unknown frontmatter field `weather`".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_updated_must_not_precede_created() {
+        let mut file = ObsidianFrontMatter::for_test(ContentKind::Article);
+        // Same instant in another offset is not earlier.
+        file.updated = "2024-12-31T15:00:00Z".to_string();
+        assert!(
+            !findings(&file, &["created", "updated"])
+                .iter()
+                .any(|(code, _)| *code == DiagnosticCode::TimestampOrder)
+        );
+
+        file.updated = "2024-12-31T23:00:00+09:00".to_string();
+        assert_eq!(
+            findings(&file, &["created", "updated"]),
+            [(DiagnosticCode::TimestampOrder, 3)]
+        );
+    }
+
+    #[test]
+    fn test_tags_must_not_be_empty_or_duplicated() {
+        let mut file = ObsidianFrontMatter::for_test(ContentKind::Article);
+        file.tags = Some(vec![
+            "Rust".to_string(),
+            " ".to_string(),
+            "#rust".to_string(),
+            "async".to_string(),
+        ]);
+
+        assert_eq!(
+            findings(&file, &["tags"]),
+            [
+                (DiagnosticCode::InvalidTags, 2),
+                (DiagnosticCode::InvalidTags, 2)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_page_and_category_belong_to_their_content_kinds() {
+        let mut page = ObsidianFrontMatter::for_test(ContentKind::Page);
+        page.page = Some("about".to_string());
+        page.category = Some("tech".to_string());
+        assert_eq!(
+            findings(&page, &["page", "category"]),
+            [(DiagnosticCode::MisplacedField, 3)]
+        );
+
+        let mut article = ObsidianFrontMatter::for_test(ContentKind::Article);
+        article.page = Some("about".to_string());
+        article.category = Some("tech".to_string());
+        assert_eq!(
+            findings(&article, &["page", "category"]),
+            [(DiagnosticCode::MisplacedField, 2)]
+        );
+    }
+
+    #[test]
+    fn test_timestamp_offsets_follow_the_vault_majority() {
+        let synonyms = TagSynonyms::default();
+        let mut strict = StrictFrontmatter::new(&synonyms);
+        let lines = field_lines(&["created", "updated"]);
+        strict.check_file(
+            Path::new("a.md"),
+            &ObsidianFrontMatter::for_test(ContentKind::Article),
+            &lines,
+        );
+        let mut utc = ObsidianFrontMatter::for_test(ContentKind::Article);
+        utc.updated = "2025-01-02T00:00:00Z".to_string();
+        strict.check_file(Path::new("b.md"), &utc, &lines);
+
+        let findings = strict.finish();
+
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].file.as_deref(), Some("b.md"));
+        assert_eq!(findings[0].line, Some(3));
+        assert_eq!(
+            findings[0].message,
+            "`updated` uses offset +00:00 while the vault uses +09:00"
+        );
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("categry", "category"), 1);
+        assert_eq!(edit_distance("sumary", "summary"), 1);
+        assert_eq!(edit_distance("kind", "kind"), 0);
+        assert_eq!(edit_distance("", "page"), 4);
+    }
+}
@@ -0,0 +1,389 @@
+use crate::artifacts::remove_stale_artifacts;
+use crate::error::Result;
+use crate::pipeline::PublishOptions;
+use crate::render::RenderCache;
+use crate::vault::SourceCache;
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use std::{
+    collections::BTreeSet,
+    future::Future,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+use tokio::sync::mpsc;
+use tracing::{debug, error, info, warn};
+
+/// Quiet period that folds the burst of events from one save into a single rebuild.
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// Rebuilds the site whenever the vault changes, until `shutdown` completes.
+pub(crate) async fn watch(
+    options: &PublishOptions,
+    shutdown: impl Future<Output = ()>,
+) -> Result<()> {
+    // Events carry absolute paths, so compare them against the canonical vault path.
+    let vault_dir = options.obsidian_dir().canonicalize()?;
+    let (sender, mut receiver) = mpsc::unbounded_channel();
+    let mut watcher = notify::recommended_watcher(move |event| {
+        // Sending only fails once the watch loop is gone.
+        let _ = sender.send(event);
+    })?;
+    watcher.watch(&vault_dir, RecursiveMode::Recursive)?;
+
+    let mut session = Session {
+        cache: options.render_cache()?,
+        ..Session::default()
+    };
+    session.rebuild(options, &BTreeSet::new()).await;
+    info!(input_dir = %vault_dir.display(), "watching vault for changes");
+
+    tokio::pin!(shutdown);
+    loop {
+        let changed = tokio::select! {
+            changed = next_changes(&mut receiver, &vault_dir) => changed?,
+            () = &mut shutdown => break,
+        };
+        for path in &changed {
+            debug!(path = %path.display(), "vault file changed");
+        }
+        info!(changed_count = changed.len(), "vault changed; rebuilding");
+        // The scan names files under the vault path as given, not the canonical one.
+        let changed = changed
+            .iter()
+            .filter_map(|path| path.strip_prefix(&vault_dir).ok())
+            .map(|relative| options.obsidian_dir().join(relative))
+            .collect();
+        session.rebuild(options, &changed).await;
+    }
+
+    info!("watch stopped");
+    Ok(())
+}
+
+/// What one rebuild hands to the next.
+#[derive(Default)]
+struct Session {
+    cache: RenderCache,
+    sources: SourceCache,
+    /// Artifacts the last successful rebuild wrote, removed once a rebuild stops producing them.
+    artifacts: BTreeSet<PathBuf>,
+}
+
+impl Session {
+    // Nothing is written until the whole site is built, so a rebuild that fails on a note leaves
+    // the last good site in place; only an I/O error while writing can leave it half updated.
+    async fn rebuild(&mut self, options: &PublishOptions, changed: &BTreeSet<PathBuf>) {
+        let start_time = Instant::now();
+        self.sources.forget(changed);
+        let artifacts = match options.rebuild(&mut self.cache, &mut self.sources).await {
+            Ok(artifacts) => artifacts,
+            Err(error) => {
+                error!(%error, "rebuild failed; keeping the last written site");
+                return;
+            }
+        };
+        if let Err(error) = remove_stale_artifacts(&self.artifacts, &artifacts) {
+            warn!(%error, "failed to remove stale artifacts");
+        }
+        self.artifacts = artifacts;
+        info!(
+            processing_time_ms = start_time.elapsed().as_millis(),
+            "rebuild completed"
+        );
+    }
+}
+
+/// Waits for a change to the vault and collects every path changed until the burst settles.
+async fn next_changes(
+    receiver: &mut mpsc::UnboundedReceiver<notify::Result<Event>>,
+    vault_dir: &Path,
+) -> Result<BTreeSet<PathBuf>> {
+    let mut changed = BTreeSet::new();
+    while changed.is_empty() {
+        let event = receiver
+            .recv()
+            .await
+            .expect("the watcher should outlive the watch loop");
+        collect_vault_changes(event?, vault_dir, &mut changed);
+    }
+    while let Ok(Some(event)) = tokio::time::timeout(DEBOUNCE, receiver.recv()).await {
+        collect_vault_changes(event?, vault_dir, &mut changed);
+    }
+    Ok(changed)
+}
+
+fn collect_vault_changes(event: Event, vault_dir: &Path, changed: &mut BTreeSet<PathBuf>) {
+    if matches!(
+        event.kind,
+        EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+    ) {
+        changed.extend(
+            event
+                .paths
+                .into_iter()
+                .filter(|path| is_vault_source(vault_dir, path)),
+        );
+    }
+}
+
+/// Whether a path is one the vault scan reads; hidden entries such as `.obsidian/` are skipped,
+/// so Obsidian saving its workspace state never triggers a rebuild.
+fn is_vault_source(vault_dir: &Path, path: &Path) -> bool {
+    path.strip_prefix(vault_dir).is_ok_and(|relative| {
+        relative
+            .components()
+            .all(|component| !component.as_os_str().to_string_lossy().starts_with('.'))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::SiteConfig;
+    use crate::render::BookmarkEnricher;
+    use indoc::{formatdoc, indoc};
+    use std::fs;
+    use std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    };
+    use tempfile::TempDir;
+
+    fn write_note(vault_dir: &Path, key: &str, front_matter: &str, body: &str) {
+        let path = vault_dir.join(format!("{key}.md"));
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        let note = formatdoc! {r#"
+            ---
+            {front_matter}
+            created: "2025-01-01T00:00:00+09:00"
+            updated: "2025-01-01T00:00:00+09:00"
+            is_completed: true
+            ---
+
+            {body}
+        "#};
+        fs::write(path, note).unwrap();
+    }
+
+    fn write_article(vault_dir: &Path, key: &str, title: &str, body: &str) {
+        let front_matter = format!("title: \"{title}\"\ncategory: tech");
+        write_note(vault_dir, key, &front_matter, body);
+    }
+
+    /// Vault with three articles, of which only `first` links to another.
+    fn vault() -> TempDir {
+        let temp_dir = TempDir::new().unwrap();
+        let vault_dir = temp_dir.path().join("vault");
+        write_note(
+            &vault_dir,
+            "about",
+            "title: About\nkind: page\npage: about",
+            "About.",
+        );
+        write_note(
+            &vault_dir,
+            "tech/category",
+            "title: Tech\nkind: category\ncategory: tech",
+            "# Tech",
+        );
+        write_article(&vault_dir, "tech/first", "First", "See [[second]].");
+        write_article(&vault_dir, "tech/second", "Second", "Second body.");
+        write_article(&vault_dir, "tech/third", "Third", "Third body.");
+        temp_dir
+    }
+
+    /// Offline options whose enricher counts rendered note bodies.
+    fn counting_options(temp_dir: &TempDir) -> (PublishOptions, Arc<AtomicUsize>) {
+        let config = SiteConfig::from_yaml(indoc! {"
+            categories:
+              - key: tech
+                display_name: Tech
+        "})
+        .unwrap();
+        let render_count = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&render_count);
+        let enrich: BookmarkEnricher = Arc::new(move |html: String| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            Box::pin(async move { html })
+        });
+        let options = PublishOptions::new(
+            config,
+            temp_dir.path().join("vault"),
+            temp_dir.path().join("dist"),
+        )
+        .with_bookmark_enricher("counting", enrich);
+        (options, render_count)
+    }
+
+    fn article_titles(temp_dir: &TempDir) -> Vec<String> {
+        let index = fs::read_to_string(temp_dir.path().join("dist/site/articles/index.json"))
+            .unwrap_or_default();
+        let index: serde_json::Value = serde_json::from_str(&index).unwrap_or_default();
+        let mut titles: Vec<_> = index["articles"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .map(|article| article["title"].as_str().unwrap().to_string())
+            .collect();
+        titles.sort();
+        titles
+    }
+
+    /// Vault paths of the notes with the given keys, as the watcher reports them.
+    fn changed(vault_dir: &Path, keys: &[&str]) -> BTreeSet<PathBuf> {
+        keys.iter()
+            .map(|key| vault_dir.join(format!("{key}.md")))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_rebuild_renders_changed_notes_and_their_linkers_only() {
+        let temp_dir = vault();
+        let vault_dir = temp_dir.path().join("vault");
+        let (options, render_count) = counting_options(&temp_dir);
+        let mut session = Session::default();
+
+        session.rebuild(&options, &BTreeSet::new()).await;
+        assert_eq!(render_count.swap(0, Ordering::SeqCst), 5);
+
+        session.rebuild(&options, &BTreeSet::new()).await;
+        assert_eq!(render_count.swap(0, Ordering::SeqCst), 0);
+
+        // A new title moves `second` to a new slug, so the article linking to it follows.
+        write_article(&vault_dir, "tech/second", "Second Edition", "Second body.");
+        session
+            .rebuild(&options, &changed(&vault_dir, &["tech/second"]))
+            .await;
+        assert_eq!(render_count.swap(0, Ordering::SeqCst), 2);
+
+        fs::remove_file(vault_dir.join("tech/third.md")).unwrap();
+        session
+            .rebuild(&options, &changed(&vault_dir, &["tech/third"]))
+            .await;
+        assert_eq!(render_count.swap(0, Ordering::SeqCst), 0);
+        assert_eq!(article_titles(&temp_dir), ["First", "Second Edition"]);
+        let article_pages = session
+            .artifacts
+            .iter()
+            .filter(|path| {
+                path.extension()
+                    .is_some_and(|extension| extension == "html")
+            })
+            .count();
+        assert_eq!(article_pages, 2);
+        assert!(
+            fs::read_dir(temp_dir.path().join("dist/site/articles/tech"))
+                .unwrap()
+                .all(|entry| session.artifacts.contains(&entry.unwrap().path()))
+        );
+
+        write_article(&vault_dir, "tech/broken", "", "Untitled.");
+        session
+            .rebuild(&options, &changed(&vault_dir, &["tech/broken"]))
+            .await;
+        assert_eq!(article_titles(&temp_dir), ["First", "Second Edition"]);
+    }
+
+    #[tokio::test]
+    async fn test_rebuild_reads_only_changed_files() {
+        let temp_dir = vault();
+        let vault_dir = temp_dir.path().join("vault");
+        let (options, _) = counting_options(&temp_dir);
+        let mut session = Session::default();
+        session.rebuild(&options, &BTreeSet::new()).await;
+
+        write_article(&vault_dir, "tech/second", "Unreported", "Second body.");
+        session.rebuild(&options, &BTreeSet::new()).await;
+        assert_eq!(article_titles(&temp_dir), ["First", "Second", "Third"]);
+
+        // A changed directory stands for every file in it.
+        session
+            .rebuild(&options, &BTreeSet::from([vault_dir.join("tech")]))
+            .await;
+        assert_eq!(article_titles(&temp_dir), ["First", "Third", "Unreported"]);
+    }
+
+    #[tokio::test]
+    async fn test_failed_rebuild_writes_nothing_it_rendered() {
+        let temp_dir = vault();
+        let vault_dir = temp_dir.path().join("vault");
+        let (options, render_count) = counting_options(&temp_dir);
+        let mut session = Session::default();
+        session.rebuild(&options, &BTreeSet::new()).await;
+        render_count.store(0, Ordering::SeqCst);
+
+        // The article renders, but a series without its first part fails the site build.
+        let gap_page = temp_dir.path().join("dist/site/articles/tech/gap.html");
+        let gap = |series_order: u32| {
+            let front_matter = format!(
+                "title: Gap\ncategory: tech\nslug: gap\nseries: guide\nseries_order: {series_order}"
+            );
+            write_note(&vault_dir, "tech/gap", &front_matter, "Gap body.");
+        };
+        gap(2);
+        session
+            .rebuild(&options, &changed(&vault_dir, &["tech/gap"]))
+            .await;
+        assert_eq!(render_count.load(Ordering::SeqCst), 1);
+        assert!(!gap_page.exists());
+        assert_eq!(article_titles(&temp_dir), ["First", "Second", "Third"]);
+
+        gap(1);
+        session
+            .rebuild(&options, &changed(&vault_dir, &["tech/gap"]))
+            .await;
+        assert!(gap_page.exists());
+        assert_eq!(
+            article_titles(&temp_dir),
+            ["First", "Gap", "Second", "Third"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_watch_republishes_saved_notes_until_shutdown() {
+        let temp_dir = vault();
+        let vault_dir = temp_dir.path().join("vault");
+        let (options, _) = counting_options(&temp_dir);
+        let (stop, stopped) = tokio::sync::oneshot::channel::<()>();
+        let watching = tokio::spawn(async move {
+            watch(&options, async {
+                let _ = stopped.await;
+            })
+            .await
+        });
+
+        let wait_for_titles = async |expected: &[&str]| {
+            for _ in 0..200 {
+                if article_titles(&temp_dir) == expected {
+                    return;
+                }
+                tokio::time::sleep(Duration::from_millis(50)).await;
+            }
+            panic!("articles never became {expected:?}");
+        };
+        wait_for_titles(&["First", "Second", "Third"]).await;
+        // Obsidian's own state is not part of the vault.
+        fs::create_dir_all(vault_dir.join(".obsidian")).unwrap();
+        fs::write(vault_dir.join(".obsidian/workspace.json"), "{}").unwrap();
+        write_article(&vault_dir, "tech/fourth", "Fourth", "Fourth body.");
+        wait_for_titles(&["First", "Fourth", "Second", "Third"]).await;
+
+        stop.send(()).unwrap();
+        watching.await.unwrap().unwrap();
+    }
+
+    #[test]
+    fn test_hidden_entries_are_not_vault_sources() {
+        let vault_dir = Path::new("/vault");
+        assert!(is_vault_source(vault_dir, Path::new("/vault/tech/note.md")));
+        assert!(!is_vault_source(
+            vault_dir,
+            Path::new("/vault/.obsidian/workspace.json")
+        ));
+        assert!(!is_vault_source(
+            vault_dir,
+            Path::new("/vault/tech/.note.md.swp")
+        ));
+        assert!(!is_vault_source(vault_dir, Path::new("/elsewhere/note.md")));
+    }
+}
@@ -1,12 +1,12 @@
 mod test_fixtures;
 
-use domain::ArticleIndexDocument;
+use domain::{ArticleIndexDocument, ArticleOutlineDocument};
 use indoc::indoc;
-use publish::publish;
+use publish::{ErrorKind, publish};
 use std::fs;
 use tempfile::TempDir;
 use test_fixtures::collect_html_files;
-use test_fixtures::{write_about_page, write_tech_category_landing};
+use test_fixtures::{site_config, write_about_page, write_tech_category_landing};
 
 /// End-to-end test that simulates a realistic Obsidian vault.
 #[tokio::test]
@@ -192,7 +192,7 @@ async fn test_end_to_end_obsidian_processing() {
     fs::write(&memory_file, memory_practices).unwrap();
 
     // Run the main processing flow.
-    let result = publish(&obsidian_dir, &output_dir).await;
+    let result = publish(&site_config(), &obsidian_dir, &output_dir).await;
     assert!(result.is_ok(), "publish should succeed");
 
     // Validate the output directory.
@@ -259,7 +259,7 @@ async fn test_end_to_end_obsidian_processing() {
     );
     assert!(!draft_file_found, "Draft blog HTML should not be generated");
 
-    // Verify semantic math markup for client-side KaTeX rendering.
+    // Verify math is converted to MathML at publish time.
     let mut math_processing_verified = false;
 
     for path in collect_html_files(&articles_dir) {
@@ -299,6 +299,34 @@ async fn test_end_to_end_obsidian_processing() {
             .all(|article| article.title != "開発日記: ブログシステムを作ってみた"),
         "article index should not contain the draft article"
     );
+    let tech_summary = article_index
+        .articles
+        .iter()
+        .find(|article| article.title == "Rustでのパフォーマンス最適化")
+        .unwrap();
+    let tech_outline: ArticleOutlineDocument = serde_json::from_str(
+        &fs::read_to_string(
+            articles_dir
+                .join(&tech_summary.category)
+                .join(format!("{}.outline.json", tech_summary.slug)),
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert!(
+        tech_outline
+            .toc
+            .iter()
+            .any(|entry| entry.level == 2 && entry.id == "ベンチマークの重要性"),
+        "article outline should carry the heading outline: {:?}",
+        tech_outline.toc
+    );
+    assert!(
+        !fs::read_to_string(site_root.join("articles").join("index.json"))
+            .unwrap()
+            .contains("\"toc\""),
+        "article index should not carry heading outlines"
+    );
     for article in &article_index.articles {
         assert!(
             articles_dir
@@ -387,7 +415,7 @@ async fn test_large_volume_processing() {
 
     // Measure processing time.
     let start = std::time::Instant::now();
-    let result = publish(&obsidian_dir, &output_dir).await;
+    let result = publish(&site_config(), &obsidian_dir, &output_dir).await;
     let duration = start.elapsed();
 
     assert!(result.is_ok(), "Large volume processing should succeed");
@@ -474,8 +502,10 @@ async fn test_publish_rejects_content_errors_without_writing_artifacts() {
     fs::write(tech_dir.join("invalid.md"), invalid_yaml).unwrap();
     fs::write(tech_dir.join("incomplete.md"), incomplete_file).unwrap();
 
-    let result = publish(&obsidian_dir, &output_dir).await;
-    assert!(result.is_err(), "Publishing must reject content errors");
+    let error = publish(&site_config(), &obsidian_dir, &output_dir)
+        .await
+        .expect_err("Publishing must reject content errors");
+    assert_eq!(error.kind(), ErrorKind::Content);
     assert!(
         !output_dir.exists(),
         "Publishing must fail before writing an incomplete artifact set"
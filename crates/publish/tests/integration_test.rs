@@ -1,11 +1,26 @@
 mod test_fixtures;
 
-use indoc::indoc;
-use publish::{BookmarkEnricher, PublishError, publish, publish_with_bookmark_enricher};
+use chrono::DateTime;
+use indoc::{formatdoc, indoc};
+use publish::{
+    BookmarkEnricher, BrokenLinkPolicy, DiagnosticCode, ErrorKind, PublishError, PublishOptions,
+    Severity, check, publish, publish_with_bookmark_enricher,
+};
 use rstest::rstest;
-use std::{fs, path::Path, sync::Arc};
+use std::{
+    collections::BTreeMap,
+    fs,
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+};
 use tempfile::TempDir;
-use test_fixtures::{collect_html_files, write_about_page, write_tech_category_landing};
+use test_fixtures::{
+    collect_html_files, site_config, write_about_page, write_tech_category_landing,
+};
 
 fn offline_bookmark_enricher() -> BookmarkEnricher {
     Arc::new(|html: String| {
@@ -63,7 +78,7 @@ async fn test_publish_with_empty_directory() {
     fs::create_dir_all(&obsidian_dir).unwrap();
 
     // A deployable artifact set must contain at least one article.
-    let result = publish(&obsidian_dir, &output_dir).await;
+    let result = publish(&site_config(), &obsidian_dir, &output_dir).await;
     assert!(result.is_err());
 }
 
@@ -98,7 +113,7 @@ async fn test_publish_with_sample_file() {
     write_about_page(&obsidian_dir);
     write_tech_category_landing(&obsidian_dir);
 
-    let result = publish(&obsidian_dir, &output_dir).await;
+    let result = publish(&site_config(), &obsidian_dir, &output_dir).await;
     assert!(result.is_ok());
 
     let site_root = output_dir.join("site");
@@ -182,14 +197,173 @@ async fn test_publish_resolves_links_to_all_content_kinds() {
     .unwrap();
     write_about_page(&obsidian_dir);
 
-    publish(&obsidian_dir, &output_dir).await.unwrap();
+    publish(&site_config(), &obsidian_dir, &output_dir)
+        .await
+        .unwrap();
 
     let html_files = collect_html_files(&output_dir.join("site/articles"));
     let html = fs::read_to_string(&html_files[0]).unwrap();
     assert!(html.contains(r#"href="/about">About</a>"#));
     assert!(html.contains(r#"href="/">Home</a>"#));
     assert!(html.contains(r#"href="/tech">Tech</a>"#));
-    assert!(html.contains(r#"src="/about" alt="About image""#));
+    assert!(html.contains(r#"<div class="transclusion">"#));
+    assert!(html.contains("This page is required for deployment."));
+    assert!(html.contains(r#"<a class="transclusion-source" href="/about">About</a>"#));
+    assert!(!html.contains(r#"src="/about""#));
+}
+
+#[tokio::test]
+async fn test_publish_applies_obsidian_inline_syntax() {
+    let temp_dir = TempDir::new().unwrap();
+    let obsidian_dir = temp_dir.path().join("obsidian");
+    let output_dir = temp_dir.path().join("dist");
+
+    write_tech_category_landing(&obsidian_dir);
+    write_about_page(&obsidian_dir);
+    fs::write(
+        obsidian_dir.join("tech/inline.md"),
+        indoc! {r#"
+            ---
+            title: "Inline"
+            tags: ["Rust", "Ｃ＃"]
+            created: "2025-01-01T00:00:00+09:00"
+            updated: "2025-01-01T00:00:00+09:00"
+            is_completed: true
+            category: "tech"
+            ---
+
+            A ==highlight== about #wasm and #rust.
+
+            %%
+            private draft
+            %%
+        "#},
+    )
+    .unwrap();
+
+    publish(&site_config(), &obsidian_dir, &output_dir)
+        .await
+        .unwrap();
+
+    let html_files = collect_html_files(&output_dir.join("site/articles"));
+    let html = fs::read_to_string(&html_files[0]).unwrap();
+    assert!(html.contains("<mark>highlight</mark>"));
+    assert!(html.contains(r#"<span class="tag">#wasm</span>"#));
+    assert!(!html.contains("private draft"));
+
+    let article_index = fs::read_to_string(output_dir.join("site/articles/index.json")).unwrap();
+    let article_index: serde_json::Value = serde_json::from_str(&article_index).unwrap();
+    assert_eq!(
+        article_index["articles"][0]["tags"],
+        serde_json::json!(["rust", "c#", "wasm"])
+    );
+    for tag_file in ["wasm.json", "c%23.json"] {
+        let tag_document = fs::read_to_string(output_dir.join("site/tags").join(tag_file)).unwrap();
+        let tag_document: serde_json::Value = serde_json::from_str(&tag_document).unwrap();
+        assert_eq!(tag_document["articles"][0]["title"], "Inline");
+    }
+}
+
+#[tokio::test]
+async fn test_publish_measures_reading_stats_and_excerpt_without_code_or_math() {
+    let temp_dir = TempDir::new().unwrap();
+    let obsidian_dir = temp_dir.path().join("obsidian");
+    let output_dir = temp_dir.path().join("dist");
+
+    write_tech_category_landing(&obsidian_dir);
+    write_about_page(&obsidian_dir);
+    fs::write(
+        obsidian_dir.join("tech/reading.md"),
+        indoc! {r#"
+            ---
+            title: "Reading"
+            created: "2025-01-01T00:00:00+09:00"
+            updated: "2025-01-01T00:00:00+09:00"
+            is_completed: true
+            category: "tech"
+            ---
+
+            ## 概要
+
+            日本語の本文です。
+
+            ```rust
+            fn main() {}
+            ```
+
+            $$x^2 + y^2$$
+        "#},
+    )
+    .unwrap();
+
+    publish(&site_config(), &obsidian_dir, &output_dir)
+        .await
+        .unwrap();
+
+    let article_index = fs::read_to_string(output_dir.join("site/articles/index.json")).unwrap();
+    let article_index: serde_json::Value = serde_json::from_str(&article_index).unwrap();
+    assert_eq!(article_index["articles"][0]["char_count"], 11);
+    assert_eq!(article_index["articles"][0]["reading_minutes"], 1);
+    assert_eq!(
+        article_index["articles"][0]["excerpt"],
+        "日本語の本文です。"
+    );
+}
+
+#[tokio::test]
+async fn test_publish_copies_referenced_attachments_as_hashed_assets() {
+    let temp_dir = TempDir::new().unwrap();
+    let obsidian_dir = temp_dir.path().join("obsidian");
+    let output_dir = temp_dir.path().join("dist");
+
+    fs::create_dir_all(obsidian_dir.join("tech")).unwrap();
+    fs::create_dir_all(obsidian_dir.join("attachments")).unwrap();
+    fs::write(obsidian_dir.join("attachments/diagram.png"), "png bytes").unwrap();
+    fs::write(obsidian_dir.join("attachments/paper.pdf"), "pdf bytes").unwrap();
+    fs::write(obsidian_dir.join("attachments/unused.png"), "unused bytes").unwrap();
+    fs::write(
+        obsidian_dir.join("tech/figures.md"),
+        indoc! {r#"
+            ---
+            title: "Figures"
+            created: "2025-01-01T00:00:00+09:00"
+            updated: "2025-01-01T00:00:00+09:00"
+            is_completed: true
+            category: "tech"
+            ---
+
+            ![[diagram.png]]
+            ![[paper.pdf|Paper]]
+        "#},
+    )
+    .unwrap();
+    write_about_page(&obsidian_dir);
+    write_tech_category_landing(&obsidian_dir);
+
+    publish(&site_config(), &obsidian_dir, &output_dir)
+        .await
+        .unwrap();
+
+    let mut assets: Vec<_> = fs::read_dir(output_dir.join("site/assets"))
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+        .collect();
+    assets.sort_unstable_by_key(|name| name.rsplit('.').next().map(str::to_string));
+    assert_eq!(assets.len(), 2, "only referenced attachments are published");
+    assert!(assets[0].ends_with(".pdf"));
+    assert!(assets[1].ends_with(".png"));
+
+    let html_files = collect_html_files(&output_dir.join("site/articles"));
+    let html = fs::read_to_string(&html_files[0]).unwrap();
+    assert!(html.contains(&format!(
+        r#"<img src="/media/{}" alt="diagram.png" />"#,
+        assets[1]
+    )));
+    assert!(html.contains(&format!(r#"<a href="/media/{}">Paper</a>"#, assets[0])));
+    assert_eq!(
+        fs::read_to_string(output_dir.join("site/assets").join(&assets[1])).unwrap(),
+        "png bytes"
+    );
 }
 
 #[tokio::test]
@@ -224,7 +398,7 @@ async fn test_publish_skips_incomplete_file() {
     write_about_page(&obsidian_dir);
     write_tech_category_landing(&obsidian_dir);
 
-    let result = publish(&obsidian_dir, &output_dir).await;
+    let result = publish(&site_config(), &obsidian_dir, &output_dir).await;
     assert!(result.is_ok());
 
     let article_index =
@@ -233,6 +407,198 @@ async fn test_publish_skips_incomplete_file() {
     assert!(!article_index.contains("Incomplete Article"));
 }
 
+#[rstest]
+#[case::held_back(false, "2025-05-31T23:59:59Z", false)]
+#[case::included(true, "2025-05-31T23:59:59Z", true)]
+#[case::released(false, "2025-06-01T00:00:00Z", true)]
+#[tokio::test]
+async fn test_publish_holds_back_scheduled_articles(
+    #[case] include_scheduled_articles: bool,
+    #[case] now: &str,
+    #[case] published: bool,
+) {
+    let temp_dir = TempDir::new().unwrap();
+    let obsidian_dir = temp_dir.path().join("obsidian");
+    let output_dir = temp_dir.path().join("dist");
+
+    write_required_article(&obsidian_dir);
+    write_about_page(&obsidian_dir);
+    write_tech_category_landing(&obsidian_dir);
+    fs::write(
+        obsidian_dir.join("tech/scheduled.md"),
+        indoc! {r#"
+            ---
+            title: "Scheduled Article"
+            created: "2025-01-01T00:00:00+09:00"
+            updated: "2025-01-01T00:00:00+09:00"
+            publish_at: "2025-06-01T09:00:00+09:00"
+            is_completed: true
+            category: "tech"
+            ---
+
+            Not yet.
+        "#},
+    )
+    .unwrap();
+
+    let mut config = site_config();
+    config.include_scheduled_articles = include_scheduled_articles;
+    let now = DateTime::parse_from_rfc3339(now).unwrap().to_utc();
+    let options = PublishOptions::new(config, &obsidian_dir, &output_dir)
+        .offline()
+        .with_clock(move || now);
+    options.publish().await.unwrap();
+
+    let article_index = fs::read_to_string(output_dir.join("site/articles/index.json")).unwrap();
+    assert_eq!(
+        article_index.contains(r#""publish_at": "2025-06-01T09:00:00+09:00""#),
+        published
+    );
+    assert_eq!(article_index.contains("Scheduled Article"), published);
+    assert!(options.check().await.unwrap().is_empty());
+}
+
+#[rstest]
+#[case::ignore(BrokenLinkPolicy::Ignore)]
+#[case::warn(BrokenLinkPolicy::Warn)]
+#[case::error(BrokenLinkPolicy::Error)]
+#[tokio::test]
+async fn test_publish_applies_the_broken_link_policy(#[case] policy: BrokenLinkPolicy) {
+    let temp_dir = TempDir::new().unwrap();
+    let obsidian_dir = temp_dir.path().join("obsidian");
+    let output_dir = temp_dir.path().join("dist");
+
+    write_tech_category_landing(&obsidian_dir);
+    write_about_page(&obsidian_dir);
+    fs::write(
+        obsidian_dir.join("tech/broken.md"),
+        indoc! {r#"
+            ---
+            title: "Broken Links"
+            created: "2025-01-01T00:00:00+09:00"
+            updated: "2025-01-01T00:00:00+09:00"
+            is_completed: true
+            category: "tech"
+            ---
+
+            [[Missing Note]], [[about#No Such Heading]] and ![[missing.png]].
+        "#},
+    )
+    .unwrap();
+
+    let mut config = site_config();
+    config.broken_links = policy;
+    let result = publish(&config, &obsidian_dir, &output_dir).await;
+
+    if policy == BrokenLinkPolicy::Error {
+        assert!(matches!(
+            result,
+            Err(PublishError::BrokenLinks { count: 3 })
+        ));
+        assert_eq!(result.unwrap_err().kind(), ErrorKind::Content);
+        assert!(!output_dir.exists());
+    } else {
+        result.unwrap();
+        assert!(output_dir.join("site/articles/index.json").exists());
+    }
+
+    let diagnostics = check(&config, &obsidian_dir).await.unwrap();
+    let expected_count = if policy == BrokenLinkPolicy::Ignore {
+        0
+    } else {
+        3
+    };
+    assert_eq!(diagnostics.len(), expected_count);
+    assert_eq!(
+        diagnostics.iter().any(|diagnostic| diagnostic.is_error()),
+        policy == BrokenLinkPolicy::Error
+    );
+}
+
+#[tokio::test]
+async fn test_publish_records_related_articles_and_backlinks() {
+    let temp_dir = TempDir::new().unwrap();
+    let obsidian_dir = temp_dir.path().join("obsidian");
+    let output_dir = temp_dir.path().join("dist");
+
+    write_required_article(&obsidian_dir);
+    write_about_page(&obsidian_dir);
+    write_tech_category_landing(&obsidian_dir);
+    for (file_name, front_matter, body) in [
+        (
+            "linking.md",
+            "slug: linking",
+            "Builds on [[required-article]].",
+        ),
+        ("tagged-a.md", "slug: tagged-a\ntags: [wasm]", "First."),
+        ("tagged-b.md", "slug: tagged-b\ntags: [WASM]", "Second."),
+    ] {
+        fs::write(
+            obsidian_dir.join("tech").join(file_name),
+            format!(
+                "---\ntitle: \"{file_name}\"\n{front_matter}\ncreated: \"2025-01-01T00:00:00+09:00\"\nupdated: \"2025-01-01T00:00:00+09:00\"\nis_completed: true\ncategory: \"tech\"\n---\n\n{body}\n"
+            ),
+        )
+        .unwrap();
+    }
+
+    publish(&site_config(), &obsidian_dir, &output_dir)
+        .await
+        .unwrap();
+
+    let article_index: domain::ArticleIndexDocument = serde_json::from_str(
+        &fs::read_to_string(output_dir.join("site/articles/index.json")).unwrap(),
+    )
+    .unwrap();
+    let related = |slug: &str| -> Vec<String> {
+        let article = article_index
+            .articles
+            .iter()
+            .find(|article| article.slug == slug)
+            .unwrap();
+        article
+            .related
+            .iter()
+            .map(|related| related.slug.clone())
+            .collect()
+    };
+    assert_eq!(related("tagged-a"), ["tagged-b"]);
+    assert_eq!(related("tagged-b"), ["tagged-a"]);
+    let required_slug = article_index
+        .articles
+        .iter()
+        .find(|article| article.title == "Required Article")
+        .unwrap()
+        .slug
+        .clone();
+    assert_eq!(related("linking"), [required_slug.as_str()]);
+    assert_eq!(related(&required_slug), ["linking"]);
+    let required = article_index
+        .articles
+        .iter()
+        .find(|article| article.slug == required_slug)
+        .unwrap();
+    let backlinks: Vec<_> = required
+        .backlinks
+        .iter()
+        .map(|backlink| backlink.slug.as_str())
+        .collect();
+    assert_eq!(backlinks, ["linking"]);
+
+    let link_graph: domain::LinkGraphDocument =
+        serde_json::from_str(&fs::read_to_string(output_dir.join("site/graph.json")).unwrap())
+            .unwrap();
+    let edges: Vec<_> = link_graph
+        .edges
+        .iter()
+        .map(|edge| (edge.source.as_str(), edge.target.as_str()))
+        .collect();
+    assert_eq!(
+        edges,
+        [("/tech/linking", format!("/tech/{required_slug}").as_str())]
+    );
+}
+
 #[tokio::test]
 async fn test_publish_with_static_page_file() {
     let temp_dir = TempDir::new().unwrap();
@@ -262,7 +628,7 @@ async fn test_publish_with_static_page_file() {
     write_required_article(&obsidian_dir);
     write_tech_category_landing(&obsidian_dir);
 
-    let result = publish(&obsidian_dir, &output_dir).await;
+    let result = publish(&site_config(), &obsidian_dir, &output_dir).await;
     assert!(result.is_ok());
 
     let page_document =
@@ -302,7 +668,7 @@ async fn test_publish_with_home_fragment_file() {
     write_about_page(&obsidian_dir);
     write_tech_category_landing(&obsidian_dir);
 
-    let result = publish(&obsidian_dir, &output_dir).await;
+    let result = publish(&site_config(), &obsidian_dir, &output_dir).await;
     assert!(result.is_ok());
 
     let page_document = fs::read_to_string(output_dir.join("site").join("home.json")).unwrap();
@@ -338,7 +704,7 @@ async fn test_publish_rejects_duplicate_home_files() {
     write_required_article(&obsidian_dir);
     write_about_page(&obsidian_dir);
 
-    let result = publish(&obsidian_dir, &output_dir).await;
+    let result = publish(&site_config(), &obsidian_dir, &output_dir).await;
 
     assert!(matches!(
         result,
@@ -389,7 +755,7 @@ async fn test_publish_with_category_landing_file() {
     fs::write(obsidian_dir.join("tech/index.md"), category_content).unwrap();
     write_about_page(&obsidian_dir);
 
-    let result = publish(&obsidian_dir, &output_dir).await;
+    let result = publish(&site_config(), &obsidian_dir, &output_dir).await;
     assert!(result.is_ok());
 
     let category_document =
@@ -410,13 +776,44 @@ async fn test_publish_rejects_missing_category_landing_before_writing() {
     write_required_article(&obsidian_dir);
     write_about_page(&obsidian_dir);
 
-    let result = publish(&obsidian_dir, &output_dir).await;
+    let result = publish(&site_config(), &obsidian_dir, &output_dir).await;
 
     assert!(matches!(
         result,
-        Err(PublishError::MissingCategoryLanding {
-            category: domain::Category::Tech
-        })
+        Err(PublishError::MissingCategoryLanding { category }) if category.as_str() == "tech"
+    ));
+    assert!(!output_dir.exists());
+}
+
+#[tokio::test]
+async fn test_publish_rejects_recursive_note_embeds_before_writing() {
+    let temp_dir = TempDir::new().unwrap();
+    let obsidian_dir = temp_dir.path().join("obsidian");
+    let output_dir = temp_dir.path().join("dist");
+
+    write_tech_category_landing(&obsidian_dir);
+    write_about_page(&obsidian_dir);
+    fs::write(
+        obsidian_dir.join("tech/loop.md"),
+        indoc! {r#"
+            ---
+            title: "Loop"
+            created: "2025-01-01T00:00:00+09:00"
+            updated: "2025-01-01T00:00:00+09:00"
+            is_completed: true
+            category: "tech"
+            ---
+
+            ![[loop]]
+        "#},
+    )
+    .unwrap();
+
+    let result = publish(&site_config(), &obsidian_dir, &output_dir).await;
+
+    assert!(matches!(
+        result,
+        Err(PublishError::EmbedCycle { cycle }) if cycle == "tech/loop -> tech/loop"
     ));
     assert!(!output_dir.exists());
 }
@@ -448,23 +845,262 @@ is_completed: true
     );
     fs::write(obsidian_dir.join("tech/index.md"), category_content).unwrap();
 
-    let result = publish(&obsidian_dir, &output_dir).await;
+    let result = publish(&site_config(), &obsidian_dir, &output_dir).await;
 
     assert!(result.is_err(), "publish should reject incomplete landing");
 }
 
+#[tokio::test]
+async fn test_check_reports_positioned_diagnostics_without_writing() {
+    let temp_dir = TempDir::new().unwrap();
+    let obsidian_dir = temp_dir.path().join("obsidian");
+
+    write_tech_category_landing(&obsidian_dir);
+    write_about_page(&obsidian_dir);
+    fs::write(
+        obsidian_dir.join("tech/linking.md"),
+        indoc! {r#"
+            ---
+            title: "Linking"
+            created: "2025-01-01T00:00:00+09:00"
+            updated: "2025-01-01T00:00:00+09:00"
+            is_completed: true
+            category: "tech"
+            ---
+
+            See [[about]] and [[Missing Note]] <kbd onclick="x()">K</kbd>.
+            %% [[Private Draft]] <script>x()</script> %%
+        "#},
+    )
+    .unwrap();
+    fs::write(
+        obsidian_dir.join("tech/series.md"),
+        indoc! {r#"
+            ---
+            title: "Series"
+            created: "2025-01-01T00:00:00+09:00"
+            updated: "2025-01-01T00:00:00+09:00"
+            is_completed: true
+            category: "tech"
+            series_order: 2
+            ---
+
+            Body.
+        "#},
+    )
+    .unwrap();
+
+    let diagnostics = check(&site_config(), &obsidian_dir).await.unwrap();
+
+    let reported: Vec<_> = diagnostics
+        .iter()
+        .map(|diagnostic| {
+            let file = Path::new(diagnostic.file.as_deref().unwrap());
+            (
+                file.strip_prefix(&obsidian_dir).unwrap().to_path_buf(),
+                diagnostic.line,
+                diagnostic.column,
+                diagnostic.severity,
+                diagnostic.code,
+            )
+        })
+        .collect();
+    assert_eq!(
+        reported,
+        [
+            (
+                Path::new("tech/linking.md").to_path_buf(),
+                Some(9),
+                Some(19),
+                Severity::Warning,
+                DiagnosticCode::UnresolvedLink,
+            ),
+            (
+                Path::new("tech/linking.md").to_path_buf(),
+                Some(9),
+                Some(36),
+                Severity::Warning,
+                DiagnosticCode::RejectedRawHtml,
+            ),
+            (
+                Path::new("tech/series.md").to_path_buf(),
+                Some(7),
+                Some(1),
+                Severity::Error,
+                DiagnosticCode::InvalidFrontmatter,
+            ),
+        ]
+    );
+    assert_eq!(
+        fs::read_dir(temp_dir.path()).unwrap().count(),
+        1,
+        "check must not write artifacts"
+    );
+}
+
+#[tokio::test]
+async fn test_check_positions_route_conflicts_in_every_note_involved() {
+    let temp_dir = TempDir::new().unwrap();
+    let obsidian_dir = temp_dir.path().join("obsidian");
+
+    write_tech_category_landing(&obsidian_dir);
+    write_about_page(&obsidian_dir);
+    for (file_name, title) in [("first.md", "First"), ("second.md", "Second")] {
+        fs::write(
+            obsidian_dir.join("tech").join(file_name),
+            formatdoc! {r#"
+                ---
+                title: "{title}"
+                created: "2025-01-01T00:00:00+09:00"
+                updated: "2025-01-01T00:00:00+09:00"
+                is_completed: true
+                category: "tech"
+                slug: "shared-slug"
+                ---
+
+                Body.
+            "#},
+        )
+        .unwrap();
+    }
+
+    let diagnostics = check(&site_config(), &obsidian_dir).await.unwrap();
+
+    let reported: Vec<_> = diagnostics
+        .iter()
+        .map(|diagnostic| {
+            let file = Path::new(diagnostic.file.as_deref().unwrap());
+            (
+                file.strip_prefix(&obsidian_dir).unwrap().to_path_buf(),
+                diagnostic.line,
+                diagnostic.code,
+            )
+        })
+        .collect();
+    assert_eq!(
+        reported,
+        [
+            (
+                Path::new("tech/first.md").to_path_buf(),
+                Some(7),
+                DiagnosticCode::DuplicateRoute,
+            ),
+            (
+                Path::new("tech/second.md").to_path_buf(),
+                Some(7),
+                DiagnosticCode::DuplicateRoute,
+            ),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_strict_frontmatter_reports_findings_and_fails_publish() {
+    let temp_dir = TempDir::new().unwrap();
+    let obsidian_dir = temp_dir.path().join("obsidian");
+    let output_dir = temp_dir.path().join("dist");
+
+    write_tech_category_landing(&obsidian_dir);
+    write_about_page(&obsidian_dir);
+    fs::write(
+        obsidian_dir.join("tech/sloppy.md"),
+        indoc! {r#"
+            ---
+            title: "Sloppy"
+            sumary: "Misspelled summary"
+            tags: [Rust, rust]
+            created: "2025-01-02T00:00:00+09:00"
+            updated: "2025-01-01T00:00:00Z"
+            is_completed: true
+            category: "tech"
+            ---
+
+            Body.
+        "#},
+    )
+    .unwrap();
+
+    publish(&site_config(), &obsidian_dir, &output_dir)
+        .await
+        .unwrap();
+    fs::remove_dir_all(&output_dir).unwrap();
+
+    let mut config = site_config();
+    config.strict_frontmatter = true;
+    let diagnostics = check(&config, &obsidian_dir).await.unwrap();
+
+    let sloppy = obsidian_dir.join("tech/sloppy.md");
+    let reported: Vec<_> = diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.file.as_deref() == sloppy.to_str())
+        .map(|diagnostic| (diagnostic.line, diagnostic.code))
+        .collect();
+    assert_eq!(
+        reported,
+        [
+            (Some(3), DiagnosticCode::UnknownField),
+            (Some(4), DiagnosticCode::InvalidTags),
+            (Some(6), DiagnosticCode::TimestampOrder),
+            (Some(6), DiagnosticCode::InconsistentOffset),
+        ]
+    );
+    assert!(diagnostics.iter().all(|diagnostic| diagnostic.is_error()));
+
+    let result = publish(&config, &obsidian_dir, &output_dir).await;
+    assert!(matches!(result, Err(PublishError::ContentErrors { .. })));
+    assert!(!output_dir.exists());
+}
+
+#[tokio::test]
+async fn test_check_reports_rendering_errors_in_the_failing_note() {
+    let temp_dir = TempDir::new().unwrap();
+    let obsidian_dir = temp_dir.path().join("obsidian");
+
+    write_required_article(&obsidian_dir);
+    write_about_page(&obsidian_dir);
+    fs::write(
+        obsidian_dir.join("tech/index.md"),
+        indoc! {r#"
+            ---
+            title: "   "
+            kind: category
+            category: tech
+            created: "2025-01-01T00:00:00+09:00"
+            updated: "2025-01-01T00:00:00+09:00"
+            is_completed: true
+            ---
+
+            Category description.
+        "#},
+    )
+    .unwrap();
+
+    let diagnostics = check(&site_config(), &obsidian_dir).await.unwrap();
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].code, DiagnosticCode::InvalidContent);
+    assert!(diagnostics[0].is_error());
+    assert!(
+        diagnostics[0]
+            .file
+            .as_deref()
+            .is_some_and(|file| file.ends_with("index.md"))
+    );
+}
+
 #[tokio::test]
 async fn test_publish_rejects_non_existent_obsidian_directory() {
     let temp_dir = TempDir::new().unwrap();
     let non_existent_dir = temp_dir.path().join("non_existent");
     let output_dir = temp_dir.path().join("dist");
 
-    let result = publish(&non_existent_dir, &output_dir).await;
+    let result = publish(&site_config(), &non_existent_dir, &output_dir).await;
 
     assert!(matches!(
         result,
         Err(PublishError::InvalidSourceDirectory(_))
     ));
+    assert_eq!(result.unwrap_err().kind(), ErrorKind::Io);
     assert!(!output_dir.exists());
 }
 
@@ -501,9 +1137,14 @@ async fn test_publish_with_bookmark_enricher() {
     write_about_page(&obsidian_dir);
     write_tech_category_landing(&obsidian_dir);
 
-    let result =
-        publish_with_bookmark_enricher(&obsidian_dir, &output_dir, offline_bookmark_enricher())
-            .await;
+    let result = publish_with_bookmark_enricher(
+        &site_config(),
+        &obsidian_dir,
+        &output_dir,
+        "offline",
+        offline_bookmark_enricher(),
+    )
+    .await;
     assert!(result.is_ok());
 
     let articles_dir = output_dir.join("site").join("articles");
@@ -530,3 +1171,161 @@ async fn test_publish_with_bookmark_enricher() {
         "bookmark should contain domain info; got: {html_content}"
     );
 }
+
+#[tokio::test]
+async fn test_publish_options_offline_keeps_simple_bookmarks() {
+    let temp_dir = TempDir::new().unwrap();
+    let obsidian_dir = temp_dir.path().join("obsidian");
+    let output_dir = temp_dir.path().join("dist");
+
+    write_required_article(&obsidian_dir);
+    write_about_page(&obsidian_dir);
+    write_tech_category_landing(&obsidian_dir);
+    fs::write(
+        obsidian_dir.join("tech/bookmark.md"),
+        indoc! {r#"
+            ---
+            title: "Bookmark Article"
+            created: "2025-01-01T00:00:00+09:00"
+            updated: "2025-01-01T00:00:00+09:00"
+            is_completed: true
+            category: "tech"
+            ---
+
+            <div class="bookmark">
+              <a href="https://example.com">Offline Bookmark</a>
+            </div>
+        "#},
+    )
+    .unwrap();
+
+    PublishOptions::new(site_config(), &obsidian_dir, &output_dir)
+        .with_concurrency(NonZeroUsize::MIN)
+        .offline()
+        .publish()
+        .await
+        .unwrap();
+
+    let html_files = collect_html_files(&output_dir.join("site/articles"));
+    assert_eq!(html_files.len(), 2);
+    let bookmark_html = html_files
+        .iter()
+        .map(|path| fs::read_to_string(path).unwrap())
+        .find(|html| html.contains("Offline Bookmark"))
+        .unwrap();
+    assert!(bookmark_html.contains(r#"<div class="bookmark">"#));
+    assert!(!bookmark_html.contains("bookmark-link"));
+}
+
+fn read_tree(root: &Path) -> BTreeMap<PathBuf, Vec<u8>> {
+    let mut files = BTreeMap::new();
+    for entry in fs::read_dir(root).unwrap().filter_map(Result::ok) {
+        let path = entry.path();
+        if path.is_dir() {
+            files.extend(
+                read_tree(&path)
+                    .into_iter()
+                    .map(|(child, bytes)| (Path::new(&entry.file_name()).join(child), bytes)),
+            );
+        } else {
+            files.insert(PathBuf::from(entry.file_name()), fs::read(&path).unwrap());
+        }
+    }
+    files
+}
+
+#[tokio::test]
+async fn test_publish_reuses_cached_bodies_with_identical_artifacts() {
+    let temp_dir = TempDir::new().unwrap();
+    let obsidian_dir = temp_dir.path().join("obsidian");
+    let cache_dir = temp_dir.path().join("cache");
+
+    write_required_article(&obsidian_dir);
+    write_about_page(&obsidian_dir);
+    write_tech_category_landing(&obsidian_dir);
+    let write_quoted = |body: &str| {
+        fs::write(
+            obsidian_dir.join("tech/quoted.md"),
+            format!(
+                indoc! {r#"
+                    ---
+                    title: "Quoted"
+                    created: "2025-01-01T00:00:00+09:00"
+                    updated: "2025-01-01T00:00:00+09:00"
+                    is_completed: true
+                    category: "tech"
+                    ---
+
+                    {}
+                "#},
+                body
+            ),
+        )
+        .unwrap();
+    };
+    write_quoted("Original quote.");
+    fs::write(
+        obsidian_dir.join("tech/quoting.md"),
+        indoc! {r#"
+            ---
+            title: "Quoting"
+            created: "2025-01-01T00:00:00+09:00"
+            updated: "2025-01-01T00:00:00+09:00"
+            is_completed: true
+            category: "tech"
+            ---
+
+            ![[quoted]]
+        "#},
+    )
+    .unwrap();
+
+    let enrich_count = Arc::new(AtomicUsize::new(0));
+    let publish_with = |enricher_name: &'static str, output_dir: PathBuf| {
+        let enrich_count = Arc::clone(&enrich_count);
+        let enrich: BookmarkEnricher = Arc::new(move |html: String| {
+            enrich_count.fetch_add(1, Ordering::SeqCst);
+            Box::pin(async move { html })
+        });
+        let options = PublishOptions::new(site_config(), &obsidian_dir, &output_dir)
+            .with_bookmark_enricher(enricher_name, enrich)
+            .with_cache_dir(&cache_dir);
+        async move {
+            options.publish().await.unwrap();
+            read_tree(&output_dir)
+        }
+    };
+
+    let publish_to = |output_dir| publish_with("counting", output_dir);
+    let cold = publish_to(temp_dir.path().join("cold")).await;
+    // Three articles, the about page and the category landing.
+    assert_eq!(enrich_count.swap(0, Ordering::SeqCst), 5);
+
+    let warm = publish_to(temp_dir.path().join("warm")).await;
+    assert_eq!(enrich_count.swap(0, Ordering::SeqCst), 0);
+    assert_eq!(
+        cold.keys().collect::<Vec<_>>(),
+        warm.keys().collect::<Vec<_>>()
+    );
+    assert!(
+        cold == warm,
+        "cached bodies should produce identical artifacts"
+    );
+
+    // Editing an embedded note renders it and the note embedding it again.
+    write_quoted("Edited quote.");
+    let edited = publish_to(temp_dir.path().join("edited")).await;
+    assert_eq!(enrich_count.swap(0, Ordering::SeqCst), 2);
+    assert_ne!(cold, edited);
+
+    // Bodies enriched by another enricher are never reused.
+    publish_with("other", temp_dir.path().join("other")).await;
+    assert_eq!(enrich_count.swap(0, Ordering::SeqCst), 5);
+
+    // Removed notes take their cached bodies with them.
+    assert_eq!(fs::read_dir(&cache_dir).unwrap().count(), 5);
+    fs::remove_file(obsidian_dir.join("tech/quoting.md")).unwrap();
+    publish_with("other", temp_dir.path().join("removed")).await;
+    assert_eq!(enrich_count.load(Ordering::SeqCst), 0);
+    assert_eq!(fs::read_dir(&cache_dir).unwrap().count(), 4);
+}
@@ -1,10 +1,15 @@
 #![allow(dead_code, reason = "shared by integration test crates")]
 
+use publish::SiteConfig;
 use std::{
     fs,
     path::{Path, PathBuf},
 };
 
+pub(crate) fn site_config() -> SiteConfig {
+    SiteConfig::load(Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/site.yaml"))).unwrap()
+}
+
 pub(crate) fn collect_html_files(root: &Path) -> Vec<PathBuf> {
     let mut html_files = Vec::new();
 
@@ -1,8 +1,9 @@
 use crate::{ArtifactReader, ArtifactSnapshot, DynArtifactReader, DynArtifactSnapshot, Result};
 use async_trait::async_trait;
 use domain::{
-    ArticleIndexDocument, Category, CategoryArtifactDocument, HomeFragmentArtifactDocument,
-    PageArtifactDocument, PageKey, SiteMetadataDocument, Slug,
+    ArticleIndexDocument, ArticleOutlineDocument, AssetName, Category, CategoryArtifactDocument,
+    HomeFragmentArtifactDocument, PageArtifactDocument, PageKey, RedirectIndexDocument,
+    SeriesArtifactDocument, SeriesKey, SiteMetadataDocument, Slug, Tag, TagArtifactDocument,
 };
 use std::{
     collections::HashMap,
@@ -111,8 +112,12 @@ struct CachingArtifactSnapshot {
     article_index: OnceCell<ArticleIndexDocument>,
     site_metadata: OnceCell<SiteMetadataDocument>,
     home_fragment: OnceCell<HomeFragmentArtifactDocument>,
+    redirects: OnceCell<RedirectIndexDocument>,
     category_documents: KeyedCache<CategoryArtifactDocument>,
+    series_documents: KeyedCache<SeriesArtifactDocument>,
+    tag_documents: KeyedCache<TagArtifactDocument>,
     article_html: KeyedCache<String>,
+    article_outlines: KeyedCache<ArticleOutlineDocument>,
     page_documents: KeyedCache<PageArtifactDocument>,
 }
 
@@ -123,8 +128,12 @@ impl CachingArtifactSnapshot {
             article_index: OnceCell::new(),
             site_metadata: OnceCell::new(),
             home_fragment: OnceCell::new(),
+            redirects: OnceCell::new(),
             category_documents: KeyedCache::new(),
+            series_documents: KeyedCache::new(),
+            tag_documents: KeyedCache::new(),
             article_html: KeyedCache::new(),
+            article_outlines: KeyedCache::new(),
             page_documents: KeyedCache::new(),
         }
     }
@@ -158,6 +167,22 @@ impl ArtifactSnapshot for CachingArtifactSnapshot {
             .await
     }
 
+    async fn read_series_document(&self, series: &SeriesKey) -> Result<SeriesArtifactDocument> {
+        self.series_documents
+            .get_or_try_init(series.as_str().to_string(), || {
+                self.inner.read_series_document(series)
+            })
+            .await
+    }
+
+    async fn read_tag_document(&self, tag: &Tag) -> Result<TagArtifactDocument> {
+        self.tag_documents
+            .get_or_try_init(tag.as_str().to_string(), || {
+                self.inner.read_tag_document(tag)
+            })
+            .await
+    }
+
     async fn read_site_metadata(&self) -> Result<SiteMetadataDocument> {
         self.site_metadata
             .get_or_try_init(|| self.inner.read_site_metadata())
@@ -173,6 +198,18 @@ impl ArtifactSnapshot for CachingArtifactSnapshot {
             .await
     }
 
+    async fn read_article_outline(
+        &self,
+        category: &Category,
+        slug: &Slug,
+    ) -> Result<ArticleOutlineDocument> {
+        self.article_outlines
+            .get_or_try_init(format!("{}/{}", category.as_str(), slug.as_str()), || {
+                self.inner.read_article_outline(category, slug)
+            })
+            .await
+    }
+
     async fn read_home_fragment(&self) -> Result<HomeFragmentArtifactDocument> {
         self.home_fragment
             .get_or_try_init(|| self.inner.read_home_fragment())
@@ -187,6 +224,18 @@ impl ArtifactSnapshot for CachingArtifactSnapshot {
             })
             .await
     }
+
+    async fn read_redirects(&self) -> Result<RedirectIndexDocument> {
+        self.redirects
+            .get_or_try_init(|| self.inner.read_redirects())
+            .await
+            .cloned()
+    }
+
+    // Assets can be large and are served with immutable HTTP caching, so they bypass memory.
+    async fn read_asset(&self, asset: &AssetName) -> Result<Vec<u8>> {
+        self.inner.read_asset(asset).await
+    }
 }
 
 struct KeyedCache<T> {
@@ -322,7 +371,7 @@ mod tests {
         ) -> Result<CategoryArtifactDocument> {
             Ok(CategoryArtifactDocument {
                 category: category.as_str().to_string(),
-                title: category.display_name().to_string(),
+                title: category.as_str().to_string(),
                 description: None,
                 html: category.as_str().to_string(),
                 updated_at: String::new(),
@@ -330,6 +379,20 @@ mod tests {
             })
         }
 
+        async fn read_series_document(&self, series: &SeriesKey) -> Result<SeriesArtifactDocument> {
+            Ok(SeriesArtifactDocument {
+                series: series.as_str().to_string(),
+                articles: vec![],
+            })
+        }
+
+        async fn read_tag_document(&self, tag: &Tag) -> Result<TagArtifactDocument> {
+            Ok(TagArtifactDocument {
+                tag: tag.as_str().to_string(),
+                articles: vec![],
+            })
+        }
+
         async fn read_site_metadata(&self) -> Result<SiteMetadataDocument> {
             Ok(SiteMetadataDocument {
                 total_articles: 0,
@@ -341,6 +404,14 @@ mod tests {
             Ok(format!("{}/{}", category.as_str(), slug.as_str()))
         }
 
+        async fn read_article_outline(
+            &self,
+            _category: &Category,
+            _slug: &Slug,
+        ) -> Result<ArticleOutlineDocument> {
+            Ok(ArticleOutlineDocument::default())
+        }
+
         async fn read_home_fragment(&self) -> Result<HomeFragmentArtifactDocument> {
             Ok(HomeFragmentArtifactDocument {
                 title: "Home".to_string(),
@@ -359,6 +430,14 @@ mod tests {
                 updated_at: String::new(),
             })
         }
+
+        async fn read_redirects(&self) -> Result<RedirectIndexDocument> {
+            Ok(RedirectIndexDocument::default())
+        }
+
+        async fn read_asset(&self, asset: &AssetName) -> Result<Vec<u8>> {
+            Ok(asset.as_str().as_bytes().to_vec())
+        }
     }
 
     fn counting_reader(
@@ -1,15 +1,19 @@
 mod cache;
 mod error;
+mod schedule;
 
 pub use cache::CachingArtifactReader;
 pub use error::{InfraError, Result};
+pub use schedule::ScheduledArtifactReader;
 
 use async_trait::async_trait;
 use aws_config::BehaviorVersion;
 use aws_sdk_s3::Client;
 use domain::{
-    ArticleIndexDocument, ArtifactReleasePointerDocument, Category, CategoryArtifactDocument,
-    HomeFragmentArtifactDocument, PageArtifactDocument, PageKey, SiteMetadataDocument, Slug,
+    ArticleIndexDocument, ArticleOutlineDocument, ArtifactReleasePointerDocument, AssetName,
+    Category, CategoryArtifactDocument, HomeFragmentArtifactDocument, PageArtifactDocument,
+    PageKey, RedirectIndexDocument, SeriesArtifactDocument, SeriesKey, SiteMetadataDocument, Slug,
+    Tag, TagArtifactDocument,
 };
 use std::{
     env,
@@ -25,6 +29,7 @@ const ARTIFACT_BUCKET_ENV: &str = "OKAWAK_BLOG_ARTIFACT_BUCKET";
 const ARTIFACT_PREFIX_ENV: &str = "OKAWAK_BLOG_ARTIFACT_PREFIX";
 const ARTIFACT_CACHE_TTL_SECONDS_ENV: &str = "OKAWAK_BLOG_ARTIFACT_CACHE_TTL_SECONDS";
 const DEFAULT_ARTIFACT_CACHE_TTL_SECONDS: u64 = 5;
+const HIDE_SCHEDULED_ARTICLES_ENV: &str = "OKAWAK_BLOG_HIDE_SCHEDULED_ARTICLES";
 
 pub type DynArtifactReader = Arc<dyn ArtifactReader>;
 pub type DynArtifactSnapshot = Arc<dyn ArtifactSnapshot>;
@@ -47,10 +52,19 @@ pub trait ArtifactSnapshot: Send + Sync {
     async fn read_article_index(&self) -> Result<ArticleIndexDocument>;
     async fn read_category_document(&self, category: &Category)
     -> Result<CategoryArtifactDocument>;
+    async fn read_series_document(&self, series: &SeriesKey) -> Result<SeriesArtifactDocument>;
+    async fn read_tag_document(&self, tag: &Tag) -> Result<TagArtifactDocument>;
     async fn read_site_metadata(&self) -> Result<SiteMetadataDocument>;
     async fn read_article_html(&self, category: &Category, slug: &Slug) -> Result<String>;
+    async fn read_article_outline(
+        &self,
+        category: &Category,
+        slug: &Slug,
+    ) -> Result<ArticleOutlineDocument>;
     async fn read_home_fragment(&self) -> Result<HomeFragmentArtifactDocument>;
     async fn read_page_document(&self, page: &PageKey) -> Result<PageArtifactDocument>;
+    async fn read_redirects(&self) -> Result<RedirectIndexDocument>;
+    async fn read_asset(&self, asset: &AssetName) -> Result<Vec<u8>>;
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -103,6 +117,16 @@ impl ArtifactSnapshot for LocalArtifactReader {
             .await
     }
 
+    async fn read_series_document(&self, series: &SeriesKey) -> Result<SeriesArtifactDocument> {
+        self.read_json(&format!("series/{}.json", series.as_str()))
+            .await
+    }
+
+    async fn read_tag_document(&self, tag: &Tag) -> Result<TagArtifactDocument> {
+        self.read_json(&format!("tags/{}.json", tag.path_segment()))
+            .await
+    }
+
     async fn read_site_metadata(&self) -> Result<SiteMetadataDocument> {
         self.read_json("metadata/site.json").await
     }
@@ -116,6 +140,19 @@ impl ArtifactSnapshot for LocalArtifactReader {
         .await?)
     }
 
+    async fn read_article_outline(
+        &self,
+        category: &Category,
+        slug: &Slug,
+    ) -> Result<ArticleOutlineDocument> {
+        self.read_json(&format!(
+            "articles/{}/{}.outline.json",
+            category.as_str(),
+            slug.as_str()
+        ))
+        .await
+    }
+
     async fn read_home_fragment(&self) -> Result<HomeFragmentArtifactDocument> {
         self.read_json("home.json").await
     }
@@ -124,6 +161,14 @@ impl ArtifactSnapshot for LocalArtifactReader {
         self.read_json(&format!("pages/{}.json", page.as_str()))
             .await
     }
+
+    async fn read_redirects(&self) -> Result<RedirectIndexDocument> {
+        self.read_json("redirects.json").await
+    }
+
+    async fn read_asset(&self, asset: &AssetName) -> Result<Vec<u8>> {
+        Ok(tokio::fs::read(self.artifact_path(&format!("assets/{}", asset.as_str()))).await?)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -227,7 +272,7 @@ impl S3ArtifactSnapshot {
         }
     }
 
-    async fn read_text(&self, relative: &str) -> Result<String> {
+    async fn read_bytes(&self, relative: &str) -> Result<Vec<u8>> {
         let key = self.location.key_for(relative);
         let response = self
             .client
@@ -242,7 +287,11 @@ impl S3ArtifactSnapshot {
                 InfraError::s3_read(self.location.bucket(), key.clone(), source)
             })?;
 
-        Ok(String::from_utf8(bytes.into_bytes().to_vec())?)
+        Ok(bytes.into_bytes().to_vec())
+    }
+
+    async fn read_text(&self, relative: &str) -> Result<String> {
+        Ok(String::from_utf8(self.read_bytes(relative).await?)?)
     }
 
     async fn read_json<T>(&self, relative: &str) -> Result<T>
@@ -305,6 +354,16 @@ impl ArtifactSnapshot for S3ArtifactSnapshot {
             .await
     }
 
+    async fn read_series_document(&self, series: &SeriesKey) -> Result<SeriesArtifactDocument> {
+        self.read_json(&format!("series/{}.json", series.as_str()))
+            .await
+    }
+
+    async fn read_tag_document(&self, tag: &Tag) -> Result<TagArtifactDocument> {
+        self.read_json(&format!("tags/{}.json", tag.path_segment()))
+            .await
+    }
+
     async fn read_site_metadata(&self) -> Result<SiteMetadataDocument> {
         self.read_json("metadata/site.json").await
     }
@@ -318,6 +377,19 @@ impl ArtifactSnapshot for S3ArtifactSnapshot {
         .await
     }
 
+    async fn read_article_outline(
+        &self,
+        category: &Category,
+        slug: &Slug,
+    ) -> Result<ArticleOutlineDocument> {
+        self.read_json(&format!(
+            "articles/{}/{}.outline.json",
+            category.as_str(),
+            slug.as_str()
+        ))
+        .await
+    }
+
     async fn read_home_fragment(&self) -> Result<HomeFragmentArtifactDocument> {
         self.read_json("home.json").await
     }
@@ -328,16 +400,28 @@ impl ArtifactSnapshot for S3ArtifactSnapshot {
             .await?;
         Ok(serde_json::from_str(&text)?)
     }
+
+    async fn read_redirects(&self) -> Result<RedirectIndexDocument> {
+        self.read_json("redirects.json").await
+    }
+
+    async fn read_asset(&self, asset: &AssetName) -> Result<Vec<u8>> {
+        self.read_bytes(&format!("assets/{}", asset.as_str())).await
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ArtifactSourceConfig {
     Local {
         site_root: PathBuf,
+        /// Wraps the reader in a [`ScheduledArtifactReader`].
+        hide_scheduled_articles: bool,
     },
     S3 {
         location: S3ArtifactLocation,
         cache_ttl: Duration,
+        /// Wraps the reader in a [`ScheduledArtifactReader`].
+        hide_scheduled_articles: bool,
     },
 }
 
@@ -348,12 +432,24 @@ impl ArtifactSourceConfig {
 
     fn from_env_with(mut read_var: impl FnMut(&str) -> Option<String>) -> Result<Self> {
         let source = read_var(ARTIFACT_SOURCE_ENV).unwrap_or_else(|| "local".to_string());
+        let hide_scheduled_articles = read_var(HIDE_SCHEDULED_ARTICLES_ENV)
+            .map(|value| {
+                value
+                    .parse::<bool>()
+                    .map_err(|_| InfraError::InvalidConfig {
+                        key: HIDE_SCHEDULED_ARTICLES_ENV,
+                        value,
+                    })
+            })
+            .transpose()?
+            .unwrap_or(false);
         match source.as_str() {
             "local" => Ok(Self::Local {
                 site_root: PathBuf::from(
                     read_var(ARTIFACT_LOCAL_ROOT_ENV)
                         .unwrap_or_else(|| DEFAULT_LOCAL_SITE_ROOT.to_string()),
                 ),
+                hide_scheduled_articles,
             }),
             "s3" => {
                 let bucket = read_var(ARTIFACT_BUCKET_ENV)
@@ -373,6 +469,7 @@ impl ArtifactSourceConfig {
                 Ok(Self::S3 {
                     location: S3ArtifactLocation::new(bucket, prefix)?,
                     cache_ttl,
+                    hide_scheduled_articles,
                 })
             }
             unsupported => Err(InfraError::UnsupportedSource(unsupported.to_string())),
@@ -388,19 +485,32 @@ impl ArtifactSourceConfig {
 }
 
 pub async fn build_artifact_reader(config: ArtifactSourceConfig) -> Result<DynArtifactReader> {
-    match config {
-        ArtifactSourceConfig::Local { site_root } => {
-            Ok(Arc::new(LocalArtifactReader::new(site_root)))
-        }
+    let (reader, hide_scheduled_articles): (DynArtifactReader, _) = match config {
+        ArtifactSourceConfig::Local {
+            site_root,
+            hide_scheduled_articles,
+        } => (
+            Arc::new(LocalArtifactReader::new(site_root)),
+            hide_scheduled_articles,
+        ),
         ArtifactSourceConfig::S3 {
             location,
             cache_ttl,
+            hide_scheduled_articles,
         } => {
             let shared_config = aws_config::defaults(BehaviorVersion::latest()).load().await;
             let client = Client::new(&shared_config);
             let reader: DynArtifactReader = Arc::new(S3ArtifactReader::new(client, location));
-            Ok(Arc::new(CachingArtifactReader::new(reader, cache_ttl)))
+            (
+                Arc::new(CachingArtifactReader::new(reader, cache_ttl)),
+                hide_scheduled_articles,
+            )
         }
+    };
+    if hide_scheduled_articles {
+        Ok(Arc::new(ScheduledArtifactReader::new(reader)))
+    } else {
+        Ok(reader)
     }
 }
 
@@ -409,7 +519,7 @@ mod tests {
     use super::*;
     use domain::{
         ARTIFACT_RELEASE_SCHEMA_VERSION, ArticleSummaryDocument, CategoryMetadataDocument,
-        SectionPath,
+        RedirectDocument, SectionPath, TocEntry,
     };
     use std::fs;
     use tempfile::TempDir;
@@ -433,6 +543,13 @@ mod tests {
                     priority: Some(1),
                     created_at: "2025-01-01T00:00:00+09:00".to_string(),
                     updated_at: "2025-01-01T00:00:00+09:00".to_string(),
+                    series: None,
+                    publish_at: None,
+                    related: vec![],
+                    backlinks: vec![],
+                    char_count: 0,
+                    reading_minutes: 0,
+                    excerpt: None,
                 }],
             })
             .unwrap(),
@@ -456,6 +573,13 @@ mod tests {
                     priority: Some(1),
                     created_at: "2025-01-01T00:00:00+09:00".to_string(),
                     updated_at: "2025-01-01T00:00:00+09:00".to_string(),
+                    series: None,
+                    publish_at: None,
+                    related: vec![],
+                    backlinks: vec![],
+                    char_count: 0,
+                    reading_minutes: 0,
+                    excerpt: None,
                 }],
             })
             .unwrap(),
@@ -467,6 +591,10 @@ mod tests {
                 total_articles: 1,
                 categories: vec![CategoryMetadataDocument {
                     category: "tech".to_string(),
+                    display_name: "技術".to_string(),
+                    description: None,
+                    sort_order: 10,
+                    icon: None,
                     article_count: 1,
                 }],
             })
@@ -479,6 +607,18 @@ mod tests {
             "<h1>Intro</h1>",
         )
         .unwrap();
+        fs::write(
+            root.join("articles/tech/intro00000001.outline.json"),
+            serde_json::to_string_pretty(&ArticleOutlineDocument {
+                toc: vec![TocEntry {
+                    level: 2,
+                    text: "Setup".to_string(),
+                    id: "setup".to_string(),
+                }],
+            })
+            .unwrap(),
+        )
+        .unwrap();
         fs::write(
             root.join("pages/about.json"),
             serde_json::to_string_pretty(&PageArtifactDocument {
@@ -491,6 +631,8 @@ mod tests {
             .unwrap(),
         )
         .unwrap();
+        fs::create_dir_all(root.join("assets")).unwrap();
+        fs::write(root.join("assets/00112233aabbccdd.png"), b"png bytes").unwrap();
         fs::write(
             root.join("home.json"),
             serde_json::to_string_pretty(&HomeFragmentArtifactDocument {
@@ -502,6 +644,17 @@ mod tests {
             .unwrap(),
         )
         .unwrap();
+        fs::write(
+            root.join("redirects.json"),
+            serde_json::to_string_pretty(&RedirectIndexDocument {
+                redirects: vec![RedirectDocument {
+                    from: "/tech/old-intro".to_string(),
+                    to: "/tech/intro00000001".to_string(),
+                }],
+            })
+            .unwrap(),
+        )
+        .unwrap();
     }
 
     #[tokio::test]
@@ -513,13 +666,20 @@ mod tests {
 
         let document = snapshot.read_article_index().await.unwrap();
         let category = snapshot
-            .read_category_document(&Category::Tech)
+            .read_category_document(&Category::new("tech".to_string()).unwrap())
             .await
             .unwrap();
         let metadata = snapshot.read_site_metadata().await.unwrap();
         let html = snapshot
             .read_article_html(
-                &Category::Tech,
+                &Category::new("tech".to_string()).unwrap(),
+                &Slug::new("intro00000001".to_string()).unwrap(),
+            )
+            .await
+            .unwrap();
+        let outline = snapshot
+            .read_article_outline(
+                &Category::new("tech".to_string()).unwrap(),
                 &Slug::new("intro00000001".to_string()).unwrap(),
             )
             .await
@@ -529,6 +689,11 @@ mod tests {
             .await
             .unwrap();
         let home_fragment = snapshot.read_home_fragment().await.unwrap();
+        let redirects = snapshot.read_redirects().await.unwrap();
+        let asset = snapshot
+            .read_asset(&AssetName::new("00112233aabbccdd.png".to_string()).unwrap())
+            .await
+            .unwrap();
 
         assert_eq!(document.articles.len(), 1);
         assert_eq!(document.articles[0].slug, "intro00000001");
@@ -537,10 +702,16 @@ mod tests {
         assert_eq!(category.html, "<article><h1>Tech</h1></article>");
         assert_eq!(metadata.total_articles, 1);
         assert_eq!(html, "<h1>Intro</h1>");
+        assert_eq!(outline.toc[0].id, "setup");
         assert_eq!(page.page.as_str(), "about");
         assert_eq!(page.title, "About");
         assert_eq!(home_fragment.title, "Home");
         assert_eq!(home_fragment.html, "<p>Welcome</p>");
+        assert_eq!(
+            redirects.target_for("/tech/old-intro"),
+            Some("/tech/intro00000001")
+        );
+        assert_eq!(asset, b"png bytes");
     }
 
     #[test]
@@ -596,6 +767,7 @@ mod tests {
     fn test_artifact_source_config_defaults_to_local_reader() {
         let source = ArtifactSourceConfig::Local {
             site_root: PathBuf::from(DEFAULT_LOCAL_SITE_ROOT),
+            hide_scheduled_articles: false,
         };
 
         assert_eq!(source.kind(), "local");
@@ -609,6 +781,7 @@ mod tests {
             source,
             ArtifactSourceConfig::Local {
                 site_root: PathBuf::from(DEFAULT_LOCAL_SITE_ROOT),
+                hide_scheduled_articles: false,
             }
         );
     }
@@ -618,6 +791,7 @@ mod tests {
         let source = ArtifactSourceConfig::from_env_with(|key| match key {
             ARTIFACT_SOURCE_ENV => Some("local".to_string()),
             ARTIFACT_LOCAL_ROOT_ENV => Some("/tmp/site".to_string()),
+            HIDE_SCHEDULED_ARTICLES_ENV => Some("true".to_string()),
             _ => None,
         })
         .unwrap();
@@ -626,6 +800,7 @@ mod tests {
             source,
             ArtifactSourceConfig::Local {
                 site_root: PathBuf::from("/tmp/site"),
+                hide_scheduled_articles: true,
             }
         );
     }
@@ -645,6 +820,7 @@ mod tests {
             ArtifactSourceConfig::S3 {
                 location: S3ArtifactLocation::new("blog-bucket", Some("/public/site/")).unwrap(),
                 cache_ttl: Duration::from_secs(DEFAULT_ARTIFACT_CACHE_TTL_SECONDS),
+                hide_scheduled_articles: false,
             }
         );
     }
@@ -664,6 +840,7 @@ mod tests {
             ArtifactSourceConfig::S3 {
                 location: S3ArtifactLocation::new("blog-bucket", None::<String>).unwrap(),
                 cache_ttl: Duration::ZERO,
+                hide_scheduled_articles: false,
             }
         );
     }
@@ -684,6 +861,20 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_artifact_source_config_from_env_rejects_invalid_scheduled_article_flag() {
+        let result = ArtifactSourceConfig::from_env_with(|key| match key {
+            HIDE_SCHEDULED_ARTICLES_ENV => Some("yes".to_string()),
+            _ => None,
+        });
+
+        assert!(matches!(
+            result,
+            Err(InfraError::InvalidConfig { key, value })
+                if key == HIDE_SCHEDULED_ARTICLES_ENV && value == "yes"
+        ));
+    }
+
     #[test]
     fn test_artifact_source_config_from_env_requires_s3_bucket() {
         let result = ArtifactSourceConfig::from_env_with(|key| match key {
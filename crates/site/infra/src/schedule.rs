@@ -0,0 +1,510 @@
+use crate::{ArtifactReader, ArtifactSnapshot, DynArtifactReader, DynArtifactSnapshot, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain::{
+    ArticleIndexDocument, ArticleOutlineDocument, ArticleSummaryDocument, AssetName, Category,
+    CategoryArtifactDocument, HomeFragmentArtifactDocument, PageArtifactDocument, PageKey,
+    RedirectIndexDocument, SeriesArtifactDocument, SeriesKey, SiteMetadataDocument, Slug, Tag,
+    TagArtifactDocument,
+};
+use std::{collections::HashSet, io, sync::Arc, time::SystemTime};
+use tokio::sync::Mutex;
+
+/// Hides articles whose `publish_at` is still ahead at request time.
+///
+/// Releases built with scheduled articles go live without another publish run; until then
+/// every document reads as if the article had not been published.
+pub struct ScheduledArtifactReader {
+    inner: DynArtifactReader,
+    /// Release times of the last snapshot with a cache identity, so an unchanged release is
+    /// not read and parsed again on every request.
+    cached: Mutex<Option<CachedSchedule>>,
+}
+
+struct CachedSchedule {
+    identity: String,
+    schedule: Arc<[ScheduledArticle]>,
+}
+
+/// `/{category}/{slug}` path of an article and the moment it goes public.
+struct ScheduledArticle {
+    path: String,
+    publish_at: DateTime<Utc>,
+}
+
+impl ScheduledArtifactReader {
+    pub fn new(inner: DynArtifactReader) -> Self {
+        Self {
+            inner,
+            cached: Mutex::new(None),
+        }
+    }
+
+    async fn snapshot_at(&self, now: DateTime<Utc>) -> Result<DynArtifactSnapshot> {
+        let snapshot = self.inner.snapshot().await?;
+        let Some(schedule) = self.schedule(&snapshot).await? else {
+            return Ok(snapshot);
+        };
+        let hidden: HashSet<_> = schedule
+            .iter()
+            .filter(|article| article.publish_at > now)
+            .map(|article| article.path.clone())
+            .collect();
+        if hidden.is_empty() {
+            return Ok(snapshot);
+        }
+
+        // Each reveal changes the rendered pages, so it must also change the HTTP validators.
+        let cache_identity = snapshot
+            .cache_identity()
+            .map(|identity| format!("{identity}+{}-scheduled", hidden.len()));
+        let last_revealed = schedule
+            .iter()
+            .map(|article| article.publish_at)
+            .filter(|publish_at| *publish_at <= now)
+            .max()
+            .map(SystemTime::from);
+        let last_modified = snapshot.last_modified().max(last_revealed);
+
+        Ok(Arc::new(ScheduledArtifactSnapshot {
+            inner: snapshot,
+            hidden,
+            cache_identity,
+            last_modified,
+        }))
+    }
+
+    /// Release times of the articles in `snapshot`, or `None` when it has no article index.
+    async fn schedule(
+        &self,
+        snapshot: &DynArtifactSnapshot,
+    ) -> Result<Option<Arc<[ScheduledArticle]>>> {
+        let identity = snapshot.cache_identity();
+        if let Some(identity) = identity
+            && let Some(cached) = self.cached.lock().await.as_ref()
+            && cached.identity == identity
+        {
+            return Ok(Some(Arc::clone(&cached.schedule)));
+        }
+
+        let article_index = match snapshot.read_article_index().await {
+            Ok(article_index) => article_index,
+            Err(error) if error.is_not_found() => return Ok(None),
+            Err(error) => return Err(error),
+        };
+        let schedule: Arc<[ScheduledArticle]> = article_index
+            .articles
+            .iter()
+            .filter_map(|article| {
+                Some(ScheduledArticle {
+                    path: article_path(article),
+                    publish_at: article.release_time()?,
+                })
+            })
+            .collect();
+        if let Some(identity) = identity {
+            *self.cached.lock().await = Some(CachedSchedule {
+                identity: identity.to_string(),
+                schedule: Arc::clone(&schedule),
+            });
+        }
+        Ok(Some(schedule))
+    }
+}
+
+#[async_trait]
+impl ArtifactReader for ScheduledArtifactReader {
+    async fn snapshot(&self) -> Result<DynArtifactSnapshot> {
+        self.snapshot_at(Utc::now()).await
+    }
+}
+
+struct ScheduledArtifactSnapshot {
+    inner: DynArtifactSnapshot,
+    /// `/{category}/{slug}` paths of articles that are not yet public.
+    hidden: HashSet<String>,
+    cache_identity: Option<String>,
+    last_modified: Option<SystemTime>,
+}
+
+impl ScheduledArtifactSnapshot {
+    fn is_visible(&self, article: &ArticleSummaryDocument) -> bool {
+        !self.hidden.contains(&article_path(article))
+    }
+
+    fn ensure_visible(&self, category: &Category, slug: &Slug) -> Result<()> {
+        if self
+            .hidden
+            .contains(&format!("/{}/{}", category.as_str(), slug.as_str()))
+        {
+            return Err(io::Error::new(io::ErrorKind::NotFound, "article is scheduled").into());
+        }
+        Ok(())
+    }
+
+    fn visible(&self, mut articles: Vec<ArticleSummaryDocument>) -> Vec<ArticleSummaryDocument> {
+        articles.retain(|article| self.is_visible(article));
+        articles
+    }
+}
+
+fn article_path(article: &ArticleSummaryDocument) -> String {
+    format!("/{}/{}", article.category, article.slug)
+}
+
+#[async_trait]
+impl ArtifactSnapshot for ScheduledArtifactSnapshot {
+    fn cache_identity(&self) -> Option<&str> {
+        self.cache_identity.as_deref()
+    }
+
+    fn last_modified(&self) -> Option<SystemTime> {
+        self.last_modified
+    }
+
+    async fn read_article_index(&self) -> Result<ArticleIndexDocument> {
+        let article_index = self.inner.read_article_index().await?;
+        Ok(ArticleIndexDocument {
+            articles: self.visible(article_index.articles),
+        })
+    }
+
+    async fn read_category_document(
+        &self,
+        category: &Category,
+    ) -> Result<CategoryArtifactDocument> {
+        let mut document = self.inner.read_category_document(category).await?;
+        document.articles = self.visible(document.articles);
+        Ok(document)
+    }
+
+    async fn read_series_document(&self, series: &SeriesKey) -> Result<SeriesArtifactDocument> {
+        let mut document = self.inner.read_series_document(series).await?;
+        document.articles = self.visible(document.articles);
+        Ok(document)
+    }
+
+    async fn read_tag_document(&self, tag: &Tag) -> Result<TagArtifactDocument> {
+        let mut document = self.inner.read_tag_document(tag).await?;
+        document.articles = self.visible(document.articles);
+        Ok(document)
+    }
+
+    async fn read_site_metadata(&self) -> Result<SiteMetadataDocument> {
+        let mut site_metadata = self.inner.read_site_metadata().await?;
+        site_metadata.total_articles = site_metadata
+            .total_articles
+            .saturating_sub(self.hidden.len());
+        for category in &mut site_metadata.categories {
+            let prefix = format!("/{}/", category.category);
+            let hidden_count = self
+                .hidden
+                .iter()
+                .filter(|path| path.starts_with(&prefix))
+                .count();
+            category.article_count = category.article_count.saturating_sub(hidden_count);
+        }
+        Ok(site_metadata)
+    }
+
+    async fn read_article_html(&self, category: &Category, slug: &Slug) -> Result<String> {
+        self.ensure_visible(category, slug)?;
+        self.inner.read_article_html(category, slug).await
+    }
+
+    async fn read_article_outline(
+        &self,
+        category: &Category,
+        slug: &Slug,
+    ) -> Result<ArticleOutlineDocument> {
+        self.ensure_visible(category, slug)?;
+        self.inner.read_article_outline(category, slug).await
+    }
+
+    async fn read_home_fragment(&self) -> Result<HomeFragmentArtifactDocument> {
+        self.inner.read_home_fragment().await
+    }
+
+    async fn read_page_document(&self, page: &PageKey) -> Result<PageArtifactDocument> {
+        self.inner.read_page_document(page).await
+    }
+
+    async fn read_redirects(&self) -> Result<RedirectIndexDocument> {
+        let mut redirects = self.inner.read_redirects().await?;
+        redirects
+            .redirects
+            .retain(|redirect| !self.hidden.contains(&redirect.to));
+        Ok(redirects)
+    }
+
+    async fn read_asset(&self, asset: &AssetName) -> Result<Vec<u8>> {
+        self.inner.read_asset(asset).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LocalArtifactReader;
+    use domain::{CategoryMetadataDocument, RedirectDocument};
+    use std::fs;
+    use std::sync::Mutex as StdMutex;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use tempfile::TempDir;
+
+    /// Serves a fixed article index under whatever release identity is current.
+    struct ReleaseReader {
+        identity: StdMutex<&'static str>,
+        index_reads: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl ArtifactReader for ReleaseReader {
+        async fn snapshot(&self) -> Result<DynArtifactSnapshot> {
+            Ok(Arc::new(ReleaseSnapshot {
+                identity: *self.identity.lock().unwrap(),
+                index_reads: Arc::clone(&self.index_reads),
+            }))
+        }
+    }
+
+    struct ReleaseSnapshot {
+        identity: &'static str,
+        index_reads: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl ArtifactSnapshot for ReleaseSnapshot {
+        fn cache_identity(&self) -> Option<&str> {
+            Some(self.identity)
+        }
+
+        async fn read_article_index(&self) -> Result<ArticleIndexDocument> {
+            self.index_reads.fetch_add(1, Ordering::SeqCst);
+            Ok(ArticleIndexDocument {
+                articles: vec![
+                    summary("released", Some("2025-01-02T00:00:00Z")),
+                    summary("scheduled", Some("2025-01-03T00:00:00Z")),
+                ],
+            })
+        }
+
+        async fn read_category_document(
+            &self,
+            _category: &Category,
+        ) -> Result<CategoryArtifactDocument> {
+            unreachable!()
+        }
+
+        async fn read_series_document(
+            &self,
+            _series: &SeriesKey,
+        ) -> Result<SeriesArtifactDocument> {
+            unreachable!()
+        }
+
+        async fn read_tag_document(&self, _tag: &Tag) -> Result<TagArtifactDocument> {
+            unreachable!()
+        }
+
+        async fn read_site_metadata(&self) -> Result<SiteMetadataDocument> {
+            unreachable!()
+        }
+
+        async fn read_article_html(&self, _category: &Category, _slug: &Slug) -> Result<String> {
+            unreachable!()
+        }
+
+        async fn read_article_outline(
+            &self,
+            _category: &Category,
+            _slug: &Slug,
+        ) -> Result<ArticleOutlineDocument> {
+            unreachable!()
+        }
+
+        async fn read_home_fragment(&self) -> Result<HomeFragmentArtifactDocument> {
+            unreachable!()
+        }
+
+        async fn read_page_document(&self, _page: &PageKey) -> Result<PageArtifactDocument> {
+            unreachable!()
+        }
+
+        async fn read_redirects(&self) -> Result<RedirectIndexDocument> {
+            unreachable!()
+        }
+
+        async fn read_asset(&self, _asset: &AssetName) -> Result<Vec<u8>> {
+            unreachable!()
+        }
+    }
+
+    fn summary(slug: &str, publish_at: Option<&str>) -> ArticleSummaryDocument {
+        ArticleSummaryDocument {
+            slug: slug.to_string(),
+            title: slug.to_string(),
+            category: "tech".to_string(),
+            section_path: Default::default(),
+            description: None,
+            tags: vec![],
+            priority: None,
+            created_at: "2025-01-01T00:00:00+09:00".to_string(),
+            updated_at: "2025-01-01T00:00:00+09:00".to_string(),
+            series: None,
+            publish_at: publish_at.map(str::to_string),
+            related: vec![],
+            backlinks: vec![],
+            char_count: 0,
+            reading_minutes: 0,
+            excerpt: None,
+        }
+    }
+
+    fn write_site(root: &std::path::Path) {
+        let articles = vec![
+            summary("released", Some("2025-01-02T00:00:00Z")),
+            summary("scheduled", Some("2025-01-03T00:00:00Z")),
+        ];
+        fs::create_dir_all(root.join("articles/tech")).unwrap();
+        fs::create_dir_all(root.join("metadata")).unwrap();
+        fs::write(
+            root.join("articles/index.json"),
+            serde_json::to_string(&ArticleIndexDocument { articles }).unwrap(),
+        )
+        .unwrap();
+        fs::write(root.join("articles/tech/scheduled.html"), "<p>soon</p>").unwrap();
+        fs::write(
+            root.join("articles/tech/scheduled.outline.json"),
+            serde_json::to_string(&ArticleOutlineDocument::default()).unwrap(),
+        )
+        .unwrap();
+        fs::write(
+            root.join("metadata/site.json"),
+            serde_json::to_string(&SiteMetadataDocument {
+                total_articles: 2,
+                categories: vec![CategoryMetadataDocument {
+                    category: "tech".to_string(),
+                    display_name: "Tech".to_string(),
+                    description: None,
+                    sort_order: 10,
+                    icon: None,
+                    article_count: 2,
+                }],
+            })
+            .unwrap(),
+        )
+        .unwrap();
+        fs::write(
+            root.join("redirects.json"),
+            serde_json::to_string(&RedirectIndexDocument {
+                redirects: vec![RedirectDocument {
+                    from: "/tech/old-scheduled".to_string(),
+                    to: "/tech/scheduled".to_string(),
+                }],
+            })
+            .unwrap(),
+        )
+        .unwrap();
+    }
+
+    fn at(timestamp: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(timestamp).unwrap().to_utc()
+    }
+
+    #[tokio::test]
+    async fn test_scheduled_snapshot_hides_articles_until_publish_at() {
+        let temp_dir = TempDir::new().unwrap();
+        write_site(temp_dir.path());
+        let reader =
+            ScheduledArtifactReader::new(Arc::new(LocalArtifactReader::new(temp_dir.path())));
+        let category = Category::new("tech".to_string()).unwrap();
+        let slug = Slug::new("scheduled".to_string()).unwrap();
+
+        let snapshot = reader
+            .snapshot_at(at("2025-01-02T12:00:00Z"))
+            .await
+            .unwrap();
+        let slugs: Vec<_> = snapshot
+            .read_article_index()
+            .await
+            .unwrap()
+            .articles
+            .into_iter()
+            .map(|article| article.slug)
+            .collect();
+        assert_eq!(slugs, ["released"]);
+        assert!(
+            snapshot
+                .read_article_html(&category, &slug)
+                .await
+                .unwrap_err()
+                .is_not_found()
+        );
+        assert!(
+            snapshot
+                .read_article_outline(&category, &slug)
+                .await
+                .unwrap_err()
+                .is_not_found()
+        );
+        let site_metadata = snapshot.read_site_metadata().await.unwrap();
+        assert_eq!(site_metadata.total_articles, 1);
+        assert_eq!(site_metadata.categories[0].article_count, 1);
+        assert!(
+            snapshot
+                .read_redirects()
+                .await
+                .unwrap()
+                .redirects
+                .is_empty()
+        );
+
+        let snapshot = reader
+            .snapshot_at(at("2025-01-03T00:00:00Z"))
+            .await
+            .unwrap();
+        assert_eq!(
+            snapshot.read_article_index().await.unwrap().articles.len(),
+            2
+        );
+        assert_eq!(
+            snapshot.read_article_html(&category, &slug).await.unwrap(),
+            "<p>soon</p>"
+        );
+        assert!(
+            snapshot
+                .read_article_outline(&category, &slug)
+                .await
+                .is_ok()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_scheduled_reader_reads_each_release_schedule_once() {
+        let index_reads = Arc::new(AtomicUsize::new(0));
+        let release = Arc::new(ReleaseReader {
+            identity: StdMutex::new("release-1"),
+            index_reads: Arc::clone(&index_reads),
+        });
+        let reader = ScheduledArtifactReader::new(Arc::clone(&release) as DynArtifactReader);
+
+        for now in ["2025-01-02T12:00:00Z", "2025-01-03T12:00:00Z"] {
+            reader.snapshot_at(at(now)).await.unwrap();
+        }
+        assert_eq!(index_reads.load(Ordering::SeqCst), 1);
+
+        let hidden = reader
+            .snapshot_at(at("2025-01-02T12:00:00Z"))
+            .await
+            .unwrap();
+        assert_eq!(hidden.cache_identity(), Some("release-1+1-scheduled"));
+
+        *release.identity.lock().unwrap() = "release-2";
+        reader
+            .snapshot_at(at("2025-01-02T12:00:00Z"))
+            .await
+            .unwrap();
+        assert_eq!(index_reads.load(Ordering::SeqCst), 2);
+    }
+}
@@ -1,5 +1,7 @@
 //! HTTP handlers and Leptos integration points.
 
 pub mod api;
+pub mod media;
 
 pub use api::create_api_router;
+pub use media::create_media_router;
@@ -43,6 +43,13 @@ mod tests {
                     priority: Some(1),
                     created_at: "2025-01-01T00:00:00+09:00".to_string(),
                     updated_at: "2025-01-01T00:00:00+09:00".to_string(),
+                    series: None,
+                    publish_at: None,
+                    related: vec![],
+                    backlinks: vec![],
+                    char_count: 0,
+                    reading_minutes: 0,
+                    excerpt: None,
                 }],
             })
             .unwrap(),
@@ -0,0 +1,109 @@
+//! Attachment handlers backed by generated site artifacts.
+
+use axum::{
+    Extension, Router,
+    extract::Path,
+    http::{HeaderValue, StatusCode, header},
+    response::{IntoResponse, Response},
+    routing::get,
+};
+use domain::AssetName;
+use infra::DynArtifactReader;
+use leptos::prelude::LeptosOptions;
+
+// Asset names are content hashes, so a given URL never changes its representation.
+const IMMUTABLE_CACHE_CONTROL_VALUE: &str = "public, max-age=31536000, immutable";
+
+/// Builds the router that serves published vault attachments.
+pub fn create_media_router(artifact_reader: DynArtifactReader) -> Router<LeptosOptions> {
+    Router::new()
+        .route("/{asset}", get(serve_asset))
+        .layer(Extension(artifact_reader))
+}
+
+pub async fn serve_asset(
+    Extension(artifact_reader): Extension<DynArtifactReader>,
+    Path(asset): Path<String>,
+) -> Result<Response, StatusCode> {
+    let asset = AssetName::new(asset).map_err(|_| StatusCode::NOT_FOUND)?;
+    let snapshot = artifact_reader
+        .snapshot()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let bytes = match snapshot.read_asset(&asset).await {
+        Ok(bytes) => bytes,
+        Err(error) if error.is_not_found() => return Err(StatusCode::NOT_FOUND),
+        Err(error) => {
+            eprintln!("Artifact asset read failed for {asset}: {error}");
+            return Err(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    Ok((
+        [
+            (
+                header::CONTENT_TYPE,
+                HeaderValue::from_static(asset.media_type()),
+            ),
+            (
+                header::CACHE_CONTROL,
+                HeaderValue::from_static(IMMUTABLE_CACHE_CONTROL_VALUE),
+            ),
+        ],
+        bytes,
+    )
+        .into_response())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, http::Request};
+    use infra::LocalArtifactReader;
+    use std::{fs, sync::Arc};
+    use tempfile::TempDir;
+    use tower::util::ServiceExt;
+
+    fn create_test_router(site_root: &std::path::Path) -> Router {
+        create_media_router(Arc::new(LocalArtifactReader::new(site_root)))
+            .with_state(LeptosOptions::builder().output_name("web").build())
+    }
+
+    async fn request(site_root: &std::path::Path, uri: &str) -> Response {
+        create_test_router(site_root)
+            .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_serve_asset_returns_artifact_bytes_with_media_type() {
+        let temp_dir = TempDir::new().unwrap();
+        fs::create_dir_all(temp_dir.path().join("assets")).unwrap();
+        fs::write(
+            temp_dir.path().join("assets/00112233aabbccdd.png"),
+            b"png bytes",
+        )
+        .unwrap();
+
+        let response = request(temp_dir.path(), "/00112233aabbccdd.png").await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "image/png");
+        assert_eq!(
+            response.headers()[header::CACHE_CONTROL],
+            IMMUTABLE_CACHE_CONTROL_VALUE
+        );
+    }
+
+    #[tokio::test]
+    async fn test_serve_asset_returns_not_found_for_missing_or_invalid_names() {
+        let temp_dir = TempDir::new().unwrap();
+
+        for uri in ["/00112233aabbccdd.png", "/secret.txt", "/..%2Fhome.json"] {
+            let response = request(temp_dir.path(), uri).await;
+
+            assert_eq!(response.status(), StatusCode::NOT_FOUND, "{uri}");
+        }
+    }
+}
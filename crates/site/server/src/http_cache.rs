@@ -211,6 +211,7 @@ fn is_static_path(path: &str) -> bool {
         || path.starts_with("/pkg/")
         || path == "/assets"
         || path.starts_with("/assets/")
+        || path.starts_with("/media/")
         || path == "/favicon.ico"
 }
 
@@ -220,8 +221,10 @@ mod tests {
     use async_trait::async_trait;
     use axum::{Router, body::Body, routing::get};
     use domain::{
-        ArticleIndexDocument, Category, CategoryArtifactDocument, HomeFragmentArtifactDocument,
-        PageArtifactDocument, PageKey, SiteMetadataDocument, Slug,
+        ArticleIndexDocument, ArticleOutlineDocument, AssetName, Category,
+        CategoryArtifactDocument, HomeFragmentArtifactDocument, PageArtifactDocument, PageKey,
+        RedirectIndexDocument, SeriesArtifactDocument, SeriesKey, SiteMetadataDocument, Slug, Tag,
+        TagArtifactDocument,
     };
     use infra::{ArtifactReader, ArtifactSnapshot, DynArtifactSnapshot, Result};
     use std::sync::{
@@ -275,6 +278,17 @@ mod tests {
             unreachable!()
         }
 
+        async fn read_series_document(
+            &self,
+            _series: &SeriesKey,
+        ) -> Result<SeriesArtifactDocument> {
+            unreachable!()
+        }
+
+        async fn read_tag_document(&self, _tag: &Tag) -> Result<TagArtifactDocument> {
+            unreachable!()
+        }
+
         async fn read_site_metadata(&self) -> Result<SiteMetadataDocument> {
             unreachable!()
         }
@@ -283,6 +297,14 @@ mod tests {
             unreachable!()
         }
 
+        async fn read_article_outline(
+            &self,
+            _category: &Category,
+            _slug: &Slug,
+        ) -> Result<ArticleOutlineDocument> {
+            unreachable!()
+        }
+
         async fn read_home_fragment(&self) -> Result<HomeFragmentArtifactDocument> {
             unreachable!()
         }
@@ -290,6 +312,14 @@ mod tests {
         async fn read_page_document(&self, _page: &PageKey) -> Result<PageArtifactDocument> {
             unreachable!()
         }
+
+        async fn read_redirects(&self) -> Result<RedirectIndexDocument> {
+            unreachable!()
+        }
+
+        async fn read_asset(&self, _asset: &AssetName) -> Result<Vec<u8>> {
+            unreachable!()
+        }
     }
 
     fn cache_state(identity: Option<&str>, enabled: bool) -> ArtifactHttpCacheState {
@@ -380,6 +410,10 @@ mod tests {
         assert!(!is_artifact_request(&Method::GET, "/api/server-fn"));
         assert!(!is_artifact_request(&Method::GET, "/pkg/web.js"));
         assert!(!is_artifact_request(&Method::GET, "/assets/logo.png"));
+        assert!(!is_artifact_request(
+            &Method::GET,
+            "/media/00112233aabbccdd.png"
+        ));
         assert!(!is_artifact_request(&Method::GET, "/favicon.ico"));
     }
 
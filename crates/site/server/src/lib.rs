@@ -11,3 +11,6 @@ pub mod handlers;
 
 #[cfg(not(target_arch = "wasm32"))]
 pub mod http_cache;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub mod redirect;
@@ -4,8 +4,9 @@ use axum::{Router, middleware, routing::get};
 use infra::{ArtifactSourceConfig, build_artifact_reader};
 use leptos::prelude::*;
 use leptos_axum::{LeptosRoutes, file_and_error_handler, generate_route_list};
-use server::handlers::create_api_router;
+use server::handlers::{create_api_router, create_media_router};
 use server::http_cache::{ArtifactHttpCacheState, artifact_conditional_get};
+use server::redirect::article_redirect;
 use tower_http::services::{ServeDir, ServeFile};
 use web::app::{App, shell};
 
@@ -38,6 +39,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         // API routes
         .nest("/api", create_api_router(artifact_reader.clone()))
         .route("/api/health", get(health))
+        // Published vault attachments.
+        .nest("/media", create_media_router(artifact_reader.clone()))
         // Static file serving.
         .nest_service(
             "/pkg",
@@ -64,9 +67,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         // Fallback handler.
         .fallback(file_and_error_handler(shell))
         .layer(middleware::from_fn_with_state(
-            ArtifactHttpCacheState::new(artifact_reader, validators_enabled),
+            ArtifactHttpCacheState::new(artifact_reader.clone(), validators_enabled),
             artifact_conditional_get,
         ))
+        // Retired article URLs redirect before any page is rendered.
+        .layer(middleware::from_fn_with_state(
+            artifact_reader,
+            article_redirect,
+        ))
         .with_state(leptos_options);
 
     println!("Server listening on http://{}", addr);
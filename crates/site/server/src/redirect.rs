@@ -0,0 +1,153 @@
+//! Permanent redirects from retired article URLs to their current location.
+
+use axum::{
+    extract::{Request, State},
+    http::{HeaderValue, Method, StatusCode, Uri, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use domain::Category;
+use infra::DynArtifactReader;
+
+pub async fn article_redirect(
+    State(artifact_reader): State<DynArtifactReader>,
+    request: Request,
+    next: Next,
+) -> Response {
+    match redirect_location(&artifact_reader, request.method(), request.uri()).await {
+        Some(location) => (
+            StatusCode::MOVED_PERMANENTLY,
+            [(header::LOCATION, location)],
+        )
+            .into_response(),
+        None => next.run(request).await,
+    }
+}
+
+async fn redirect_location(
+    artifact_reader: &DynArtifactReader,
+    method: &Method,
+    uri: &Uri,
+) -> Option<HeaderValue> {
+    if method != Method::GET && method != Method::HEAD {
+        return None;
+    }
+    let path = uri.path();
+    let path = path.strip_suffix(".html").unwrap_or(path);
+    if !is_article_path(path) {
+        return None;
+    }
+
+    let snapshot = artifact_reader.snapshot().await.ok()?;
+    let redirects = match snapshot.read_redirects().await {
+        Ok(redirects) => redirects,
+        // Releases published before redirects existed have no redirect artifact.
+        Err(error) if error.is_not_found() => return None,
+        Err(error) => {
+            eprintln!("Artifact redirect read failed: {error}");
+            return None;
+        }
+    };
+    let target = redirects.target_for(path)?;
+    let location = match uri.query() {
+        Some(query) => format!("{target}?{query}"),
+        None => target.to_string(),
+    };
+    HeaderValue::try_from(location).ok()
+}
+
+fn is_article_path(path: &str) -> bool {
+    path.strip_prefix('/')
+        .and_then(|path| path.split_once('/'))
+        .is_some_and(|(category, slug)| {
+            category.parse::<Category>().is_ok() && !slug.is_empty() && !slug.contains('/')
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{Router, body::Body, routing::get};
+    use domain::{RedirectDocument, RedirectIndexDocument};
+    use infra::LocalArtifactReader;
+    use std::{fs, sync::Arc};
+    use tempfile::TempDir;
+    use tower::util::ServiceExt;
+
+    fn create_test_router(site_root: &std::path::Path) -> Router {
+        let artifact_reader: DynArtifactReader = Arc::new(LocalArtifactReader::new(site_root));
+        Router::new()
+            .route("/{category}/{slug}", get(|| async { "article page" }))
+            .layer(axum::middleware::from_fn_with_state(
+                artifact_reader,
+                article_redirect,
+            ))
+    }
+
+    fn write_redirects(site_root: &std::path::Path) {
+        fs::write(
+            site_root.join("redirects.json"),
+            serde_json::to_string(&RedirectIndexDocument {
+                redirects: vec![RedirectDocument {
+                    from: "/tech/old-slug".to_string(),
+                    to: "/tech/new-slug".to_string(),
+                }],
+            })
+            .unwrap(),
+        )
+        .unwrap();
+    }
+
+    async fn request(site_root: &std::path::Path, method: Method, uri: &str) -> Response {
+        create_test_router(site_root)
+            .oneshot(
+                Request::builder()
+                    .method(method)
+                    .uri(uri)
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap()
+    }
+
+    #[test]
+    fn test_is_article_path_requires_category_and_slug() {
+        assert!(is_article_path("/tech/old-slug"));
+        assert!(!is_article_path("/tech"));
+        assert!(!is_article_path("/tech/"));
+        assert!(!is_article_path("/api/articles"));
+        assert!(!is_article_path("/tech/old/slug"));
+    }
+
+    #[tokio::test]
+    async fn test_article_redirect_answers_old_urls_with_moved_permanently() {
+        let temp_dir = TempDir::new().unwrap();
+        write_redirects(temp_dir.path());
+
+        for (uri, location) in [
+            ("/tech/old-slug", "/tech/new-slug"),
+            ("/tech/old-slug.html", "/tech/new-slug"),
+            ("/tech/old-slug?ref=feed", "/tech/new-slug?ref=feed"),
+        ] {
+            let response = request(temp_dir.path(), Method::GET, uri).await;
+
+            assert_eq!(response.status(), StatusCode::MOVED_PERMANENTLY, "{uri}");
+            assert_eq!(response.headers()[header::LOCATION], location, "{uri}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_article_redirect_passes_through_current_urls_and_missing_artifact() {
+        let temp_dir = TempDir::new().unwrap();
+
+        let response = request(temp_dir.path(), Method::GET, "/tech/old-slug").await;
+        assert_eq!(response.status(), StatusCode::OK);
+
+        write_redirects(temp_dir.path());
+        let response = request(temp_dir.path(), Method::GET, "/tech/new-slug").await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let response = request(temp_dir.path(), Method::POST, "/tech/old-slug").await;
+        assert_eq!(response.status(), StatusCode::METHOD_NOT_ALLOWED);
+    }
+}
@@ -7,6 +7,8 @@ pub mod article_card;
 pub mod footer;
 pub mod header;
 pub mod page_metadata;
+pub mod series_box;
+pub mod table_of_contents;
 pub mod ui;
 
 // Re-export frequently used components.
@@ -14,29 +16,54 @@ pub use article_card::ArticleCard;
 pub use footer::Footer;
 pub use header::Header;
 pub use page_metadata::PageMetadata;
+pub use series_box::SeriesBox;
+pub use table_of_contents::TableOfContents;
+
+use domain::{SiteCategorySummary, build_category_path};
 
 // Shared types and constants used across components.
 #[derive(Clone, Debug, PartialEq)]
 pub struct NavigationItem {
     pub title: String,
     pub href: String,
+    pub icon: Option<String>,
     pub is_active: bool,
 }
 
-/// Main navigation items.
-pub fn get_main_nav_items(current_path: &str) -> Vec<NavigationItem> {
-    vec![
-        NavigationItem {
-            title: "ホーム".into(),
-            href: "/".into(),
-            is_active: current_path == "/",
-        },
+/// Main navigation items, with published categories between Home and About.
+pub fn get_main_nav_items(
+    current_path: &str,
+    categories: &[SiteCategorySummary],
+) -> Vec<NavigationItem> {
+    let home = NavigationItem {
+        title: "ホーム".into(),
+        href: "/".into(),
+        icon: None,
+        is_active: current_path == "/",
+    };
+    let category_items = categories.iter().map(|category| {
+        let href = build_category_path(&category.category);
         NavigationItem {
-            title: "About".into(),
-            href: "/about".into(),
-            is_active: current_path == "/about",
-        },
-    ]
+            title: category.category_display_name.clone(),
+            is_active: current_path == href
+                || current_path
+                    .strip_prefix(href.as_str())
+                    .is_some_and(|rest| rest.starts_with('/')),
+            href,
+            icon: category.icon.clone(),
+        }
+    });
+    let about = NavigationItem {
+        title: "About".into(),
+        href: "/about".into(),
+        icon: None,
+        is_active: current_path == "/about",
+    };
+
+    std::iter::once(home)
+        .chain(category_items)
+        .chain(std::iter::once(about))
+        .collect()
 }
 
 /// Social links.
@@ -44,6 +71,7 @@ pub fn get_social_links() -> Vec<NavigationItem> {
     vec![NavigationItem {
         title: "GitHub".into(),
         href: "https://github.com/okawak".into(),
+        icon: None,
         is_active: false,
     }]
 }
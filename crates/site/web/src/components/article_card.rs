@@ -1,7 +1,7 @@
 use crate::components::ui::badge::{Badge, BadgeVariant};
 use crate::components::ui::card::{Card, CardSize};
-use crate::format::format_display_date;
-use domain::{SiteArticleCard, build_article_path};
+use crate::format::{format_display_date, format_reading_stats};
+use domain::{SiteArticleCard, build_article_path, build_tag_path};
 use leptos::prelude::*;
 use leptos_router::components::A;
 
@@ -11,28 +11,31 @@ pub fn ArticleCard(article: SiteArticleCard) -> impl IntoView {
     let article_href = build_article_path(&article.category, &article.slug);
     let title = article.title.as_str().to_string();
     let article_label = title.clone();
-    let category = article.category_display_name;
     let description = article
-        .description
-        .unwrap_or_else(|| "説明はまだありません。".to_string());
+        .summary_text()
+        .unwrap_or("説明はまだありません。")
+        .to_string();
+    let category = article.category_display_name;
     let tags = article.tags;
     let has_tags = !tags.is_empty();
     let created_at = article.created_at;
     let updated_at = article.updated_at;
     let created_at_label = format_display_date(&created_at);
     let updated_at_label = format_display_date(&updated_at);
+    let reading_label = format_reading_stats(&article.reading_stats);
 
     view! {
         <article class="min-w-0">
-            <A
-                href={article_href}
-                {..}
-                class="group block text-inherit no-underline focus-visible:rounded-xl focus-visible:outline-2 focus-visible:outline-offset-4 focus-visible:outline-ring"
-                attr:aria-label=article_label
+            // Tag badges link to their own pages, so only the summary sits inside the article link.
+            <Card
+                size=CardSize::Sm
+                class="gap-3 border-border/80 bg-card/90 p-5 shadow-[0_10px_30px_rgb(0_0_0/0.22)] transition-[transform,box-shadow,border-color] duration-300 hover:-translate-y-0.5 hover:border-primary hover:shadow-[0_16px_36px_rgb(0_0_0/0.32)] has-[a:focus-visible]:border-primary"
             >
-                <Card
-                    size=CardSize::Sm
-                    class="gap-3 border-border/80 bg-card/90 p-5 shadow-[0_10px_30px_rgb(0_0_0/0.22)] transition-[transform,box-shadow,border-color] duration-300 group-hover:-translate-y-0.5 group-hover:border-primary group-hover:shadow-[0_16px_36px_rgb(0_0_0/0.32)] group-focus-visible:border-primary"
+                <A
+                    href={article_href}
+                    {..}
+                    class="group grid gap-3 text-inherit no-underline focus-visible:rounded-md focus-visible:outline-2 focus-visible:outline-offset-4 focus-visible:outline-ring"
+                    attr:aria-label=article_label
                 >
                     <div class="flex flex-wrap items-center justify-between gap-2 text-xs text-muted-foreground sm:text-sm">
                         <Badge
@@ -49,6 +52,13 @@ pub fn ArticleCard(article: SiteArticleCard) -> impl IntoView {
                             <span>
                                 {"更新 "} <time datetime=updated_at>{updated_at_label}</time>
                             </span>
+                            {reading_label
+                                .map(|reading_label| {
+                                    view! {
+                                        <span aria-hidden="true">"/"</span>
+                                        <span>{reading_label}</span>
+                                    }
+                                })}
                         </span>
                     </div>
 
@@ -56,25 +66,34 @@ pub fn ArticleCard(article: SiteArticleCard) -> impl IntoView {
                         {title}
                     </h3>
                     <p class="m-0 leading-7 text-muted-foreground">{description}</p>
+                </A>
 
-                    <Show when=move || has_tags fallback=|| ()>
-                        <ul class="m-0 flex list-none flex-wrap gap-2 p-0" aria-label="タグ">
-                            {tags
-                                .iter()
-                                .map(|tag| {
-                                    view! {
-                                        <li>
-                                            <Badge variant=BadgeVariant::Muted>
+                <Show when=move || has_tags fallback=|| ()>
+                    <ul class="m-0 flex list-none flex-wrap gap-2 p-0" aria-label="タグ">
+                        {tags
+                            .iter()
+                            .map(|tag| {
+                                view! {
+                                    <li>
+                                        <A
+                                            href=build_tag_path(tag)
+                                            {..}
+                                            class="rounded-md no-underline focus-visible:outline-2 focus-visible:outline-offset-2 focus-visible:outline-ring"
+                                        >
+                                            <Badge
+                                                variant=BadgeVariant::Muted
+                                                class="hover:text-primary"
+                                            >
                                                 {format!("#{tag}")}
                                             </Badge>
-                                        </li>
-                                    }
-                                })
-                                .collect_view()}
-                        </ul>
-                    </Show>
-                </Card>
-            </A>
+                                        </A>
+                                    </li>
+                                }
+                            })
+                            .collect_view()}
+                    </ul>
+                </Show>
+            </Card>
         </article>
     }
 }
@@ -1,17 +1,50 @@
 use crate::SITE_NAME;
 use crate::components::ui::button::{Button, ButtonSize, ButtonVariant};
 use crate::components::{NavigationItem, get_main_nav_items};
+use domain::SiteCategorySummary;
+#[cfg(feature = "ssr")]
+use domain::build_site_category_summaries;
+#[cfg(feature = "ssr")]
+use infra::DynArtifactReader;
 use leptos::prelude::*;
 use leptos_router::components::A;
 use leptos_router::hooks::use_location;
 
+#[server]
+pub async fn get_site_category_summaries() -> Result<Vec<SiteCategorySummary>, ServerFnError> {
+    #[cfg(feature = "ssr")]
+    {
+        let artifact_reader = use_context::<DynArtifactReader>()
+            .ok_or_else(|| ServerFnError::new("artifact reader context is missing"))?;
+        let snapshot = artifact_reader.snapshot().await?;
+        let site_metadata = snapshot.read_site_metadata().await?;
+
+        Ok(build_site_category_summaries(&site_metadata)?)
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        Err(ServerFnError::new(
+            "get_site_category_summaries is only available during SSR",
+        ))
+    }
+}
+
 /// Site header component.
 #[component]
 pub fn Header() -> impl IntoView {
     const NAV_ID: &str = "site-header-nav";
 
     let location = use_location();
-    let nav_items = Memo::new(move |_| get_main_nav_items(&location.pathname.get()));
+    // Without published metadata the header still offers the fixed Home and About links.
+    let categories = Resource::new_blocking(
+        || (),
+        |_| async move { get_site_category_summaries().await.unwrap_or_default() },
+    );
+    let nav_items = Memo::new(move |_| {
+        let categories = categories.get().unwrap_or_default();
+        get_main_nav_items(&location.pathname.get(), &categories)
+    });
     let (menu_open, set_menu_open) = signal(false);
 
     view! {
@@ -62,33 +95,53 @@ pub fn Header() -> impl IntoView {
                     }
                 >
                     <ul class="m-0 flex list-none flex-col gap-1 p-0 md:flex-row md:items-center md:gap-2">
-                        <For
-                            each=move || nav_items.get()
-                            key=|item: &NavigationItem| item.href.clone()
-                            children=move |child| {
-                                let href = child.href.clone();
-                                let active_href = href.clone();
-                                let link_class = move || {
-                                    if location.pathname.get() == active_href {
-                                        "block rounded-md border-b-2 border-primary px-3 py-2 text-sm font-medium text-foreground no-underline"
-                                    } else {
-                                        "block rounded-md border-b-2 border-transparent px-3 py-2 text-sm font-medium text-muted-foreground no-underline transition-colors hover:border-primary hover:text-foreground focus-visible:outline-2 focus-visible:outline-offset-2 focus-visible:outline-ring"
+                        <Transition fallback=|| ()>
+                            <For
+                                each=move || nav_items.get()
+                                key=|item: &NavigationItem| item.href.clone()
+                                children=move |child| {
+                                    let href = child.href.clone();
+                                    let active_href = href.clone();
+                                    let is_active = move || {
+                                        nav_items
+                                            .with(|items| {
+                                                items
+                                                    .iter()
+                                                    .any(|item| item.href == active_href && item.is_active)
+                                            })
+                                    };
+                                    let link_class = move || {
+                                        if is_active() {
+                                            "block rounded-md border-b-2 border-primary px-3 py-2 text-sm font-medium text-foreground no-underline"
+                                        } else {
+                                            "block rounded-md border-b-2 border-transparent px-3 py-2 text-sm font-medium text-muted-foreground no-underline transition-colors hover:border-primary hover:text-foreground focus-visible:outline-2 focus-visible:outline-offset-2 focus-visible:outline-ring"
+                                        }
+                                    };
+                                    view! {
+                                        <li>
+                                            <A
+                                                href={href}
+                                                {..}
+                                                class=link_class
+                                                on:click=move |_| set_menu_open.set(false)
+                                            >
+                                                {child
+                                                    .icon
+                                                    .map(|icon| {
+                                                        view! {
+                                                            <i
+                                                                class=format!("{icon} mr-2 text-xs")
+                                                                aria-hidden="true"
+                                                            ></i>
+                                                        }
+                                                    })}
+                                                {child.title}
+                                            </A>
+                                        </li>
                                     }
-                                };
-                                view! {
-                                    <li>
-                                        <A
-                                            href={href}
-                                            {..}
-                                            class=link_class
-                                            on:click=move |_| set_menu_open.set(false)
-                                        >
-                                            {child.title}
-                                        </A>
-                                    </li>
                                 }
-                            }
-                        />
+                            />
+                        </Transition>
                     </ul>
 
                     <div class="border-t border-border pt-3 md:border-t-0 md:pt-0">
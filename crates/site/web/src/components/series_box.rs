@@ -0,0 +1,83 @@
+use domain::{SeriesNavigation, SeriesPartLink};
+use leptos::prelude::*;
+
+const PART_LINK_CLASS: &str = "block rounded-md px-3 py-1 leading-6 no-underline transition-colors focus-visible:outline-2 focus-visible:outline-offset-2 focus-visible:outline-ring";
+const NEIGHBOR_LINK_CLASS: &str = "grid min-w-0 gap-1 rounded-lg border border-border/80 bg-background/45 px-4 py-3 no-underline transition-colors hover:border-primary focus-visible:outline-2 focus-visible:outline-offset-2 focus-visible:outline-ring";
+
+/// Part list of a multi-part series with links to the neighboring parts.
+#[component]
+pub fn SeriesBox(navigation: SeriesNavigation) -> impl IntoView {
+    let previous = navigation.previous().cloned();
+    let next = navigation.next().cloned();
+    let SeriesNavigation {
+        series,
+        current_order,
+        parts,
+    } = navigation;
+    let total = parts.len();
+
+    view! {
+        <nav
+            class="grid gap-4 rounded-xl border border-border/80 bg-card p-6 shadow-[0_12px_32px_rgb(0_0_0/0.22)] sm:p-8"
+            aria-label="シリーズ"
+        >
+            <p class="m-0 text-sm font-bold tracking-[0.12em] text-primary uppercase">
+                {format!("シリーズ: {series} ({current_order}/{total})")}
+            </p>
+            <ol class="m-0 grid list-none gap-0.5 p-0">
+                {parts
+                    .into_iter()
+                    .map(|part| {
+                        let is_current = part.order == current_order;
+                        let state = if is_current {
+                            "bg-secondary/60 font-semibold text-primary"
+                        } else {
+                            "text-muted-foreground hover:text-foreground"
+                        };
+
+                        view! {
+                            <li>
+                                <a
+                                    href=part.path
+                                    class=format!("{PART_LINK_CLASS} {state}")
+                                    aria-current=is_current.then_some("page")
+                                >
+                                    {format!("{}. {}", part.order, part.title)}
+                                </a>
+                            </li>
+                        }
+                    })
+                    .collect_view()}
+            </ol>
+            <div class="grid gap-3 sm:grid-cols-2">
+                {previous.map(|part| view! { <NeighborLink part label="前の記事" rel="prev" /> })}
+                {next
+                    .map(|part| {
+                        view! {
+                            <NeighborLink
+                                part
+                                label="次の記事"
+                                rel="next"
+                                class="sm:col-start-2 sm:text-right"
+                            />
+                        }
+                    })}
+            </div>
+        </nav>
+    }
+}
+
+#[component]
+fn NeighborLink(
+    part: SeriesPartLink,
+    label: &'static str,
+    rel: &'static str,
+    #[prop(optional)] class: &'static str,
+) -> impl IntoView {
+    view! {
+        <a href=part.path class=format!("{NEIGHBOR_LINK_CLASS} {class}") rel=rel>
+            <span class="text-xs text-muted-foreground">{label}</span>
+            <span class="truncate text-foreground">{part.title}</span>
+        </a>
+    }
+}
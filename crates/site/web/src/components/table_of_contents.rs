@@ -0,0 +1,109 @@
+use domain::TocEntry;
+use leptos::prelude::*;
+
+const LINK_CLASS: &str = "block rounded-md border-l-2 px-3 py-1 leading-6 no-underline transition-colors focus-visible:outline-2 focus-visible:outline-offset-2 focus-visible:outline-ring";
+const INACTIVE_LINK_CLASS: &str = "border-transparent text-muted-foreground hover:text-foreground";
+const ACTIVE_LINK_CLASS: &str = "border-primary bg-secondary/60 font-semibold text-primary";
+
+/// Sticky article outline that highlights the section being read.
+#[component]
+pub fn TableOfContents(entries: Vec<TocEntry>) -> impl IntoView {
+    let active_id = RwSignal::new(None::<String>);
+    let top_level = entries.iter().map(|entry| entry.level).min().unwrap_or(1);
+
+    #[cfg(target_arch = "wasm32")]
+    track_active_heading(
+        entries.iter().map(|entry| entry.id.clone()).collect(),
+        active_id,
+    );
+
+    view! {
+        <nav
+            class="min-w-0 rounded-xl border border-border/80 bg-card/90 p-4 text-sm shadow-[0_12px_32px_rgb(0_0_0/0.22)] lg:sticky lg:top-[calc(var(--site-header-height)+1.5rem)] lg:max-h-[calc(100dvh-var(--site-header-height)-3rem)] lg:overflow-y-auto"
+            aria-label="目次"
+        >
+            <p class="m-0 mb-2 px-3 text-xs font-bold tracking-[0.12em] text-primary uppercase">
+                "目次"
+            </p>
+            <ol class="m-0 grid list-none gap-0.5 p-0">
+                {entries
+                    .into_iter()
+                    .map(|entry| {
+                        let depth = entry.level.saturating_sub(top_level);
+                        let href = format!("#{}", entry.id);
+                        let is_active = {
+                            let id = entry.id.clone();
+                            move || active_id.with(|active| active.as_deref() == Some(id.as_str()))
+                        };
+                        let link_class = {
+                            let is_active = is_active.clone();
+                            move || {
+                                let state = if is_active() {
+                                    ACTIVE_LINK_CLASS
+                                } else {
+                                    INACTIVE_LINK_CLASS
+                                };
+                                format!("{LINK_CLASS} {state}")
+                            }
+                        };
+                        let id = entry.id;
+
+                        view! {
+                            <li style:padding-inline-start=format!("{}rem", f32::from(depth) * 0.75)>
+                                <a
+                                    href=href
+                                    class=link_class
+                                    aria-current=move || is_active().then_some("location")
+                                    on:click=move |_| active_id.set(Some(id.clone()))
+                                >
+                                    {entry.text}
+                                </a>
+                            </li>
+                        }
+                    })
+                    .collect_view()}
+            </ol>
+        </nav>
+    }
+}
+
+// The current section is the last heading that has scrolled into the top third of the
+// viewport, or the last heading once the page cannot scroll any further.
+#[cfg(target_arch = "wasm32")]
+fn track_active_heading(ids: Vec<String>, active_id: RwSignal<Option<String>>) {
+    let update = move || {
+        let window = window();
+        let document = document();
+        let viewport_height = window
+            .inner_height()
+            .ok()
+            .and_then(|height| height.as_f64())
+            .unwrap_or_default();
+        let scroll_y = window.scroll_y().unwrap_or_default();
+        let scrolled_to_end = scroll_y > 0.0
+            && document.document_element().is_some_and(|root| {
+                scroll_y + viewport_height >= f64::from(root.scroll_height()) - 1.0
+            });
+        let current = if scrolled_to_end {
+            ids.last()
+        } else {
+            ids.iter()
+                .rev()
+                .find(|id| {
+                    document.get_element_by_id(id).is_some_and(|heading| {
+                        heading.get_bounding_client_rect().top() <= viewport_height / 3.0
+                    })
+                })
+                .or(ids.first())
+        };
+
+        if active_id.with_untracked(|active| active.as_ref() != current) {
+            active_id.set(current.cloned());
+        }
+    };
+
+    let initial_update = update.clone();
+    Effect::new(move |_| initial_update());
+    let handle = window_event_listener(leptos::ev::scroll, move |_| update());
+    on_cleanup(move || handle.remove());
+}
@@ -1,4 +1,5 @@
 use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime};
+use domain::ReadingStats;
 
 /// Formats an artifact date for human-readable presentation.
 pub fn format_display_date(value: &str) -> String {
@@ -19,9 +20,28 @@ pub fn format_display_date(value: &str) -> String {
     }
 }
 
+/// Formats article length, or nothing for artifacts published before it was measured.
+pub fn format_reading_stats(stats: &ReadingStats) -> Option<String> {
+    if stats.char_count == 0 {
+        return None;
+    }
+
+    let digits = stats.char_count.to_string();
+    let mut char_count = String::with_capacity(digits.len() + digits.len() / 3);
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index) % 3 == 0 {
+            char_count.push(',');
+        }
+        char_count.push(digit);
+    }
+
+    Some(format!("約{}分・{char_count}文字", stats.reading_minutes))
+}
+
 #[cfg(test)]
 mod tests {
-    use super::format_display_date;
+    use super::{format_display_date, format_reading_stats};
+    use domain::ReadingStats;
 
     #[test]
     fn formats_rfc3339_without_exposing_time_or_offset() {
@@ -47,4 +67,30 @@ mod tests {
     fn preserves_unknown_values() {
         assert_eq!(format_display_date("unknown"), "unknown");
     }
+
+    #[test]
+    fn formats_reading_stats_with_grouped_char_count() {
+        let stats = ReadingStats {
+            char_count: 12345,
+            reading_minutes: 25,
+        };
+        assert_eq!(
+            format_reading_stats(&stats).as_deref(),
+            Some("約25分・12,345文字")
+        );
+
+        let stats = ReadingStats {
+            char_count: 480,
+            reading_minutes: 1,
+        };
+        assert_eq!(
+            format_reading_stats(&stats).as_deref(),
+            Some("約1分・480文字")
+        );
+    }
+
+    #[test]
+    fn omits_unmeasured_reading_stats() {
+        assert_eq!(format_reading_stats(&ReadingStats::default()), None);
+    }
 }
@@ -1,13 +1,15 @@
 // Declare route submodules.
-pub mod about;
 pub mod article;
 pub mod category;
 pub mod home;
 pub mod not_found;
+pub mod page;
+pub mod tag;
 
 // Re-export route components for convenient access.
-pub use about::AboutPage;
 pub use article::ArticlePage;
 pub use category::CategoryPage;
 pub use home::HomePage;
 pub use not_found::NotFoundPage;
+pub use page::StaticPage;
+pub use tag::TagPage;
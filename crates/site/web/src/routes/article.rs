@@ -1,24 +1,26 @@
-use crate::components::PageMetadata;
 use crate::components::ui::badge::{Badge, BadgeVariant};
-use crate::format::format_display_date;
+use crate::components::{ArticleCard, PageMetadata, SeriesBox, TableOfContents};
+use crate::format::{format_display_date, format_reading_stats};
 use crate::routes::not_found::NotFoundPage;
 use crate::{SITE_NAME, build_site_url};
 #[cfg(feature = "ssr")]
 use axum::http::StatusCode;
 use domain::ArticlePageDocument;
 #[cfg(feature = "ssr")]
-use domain::{Category, Slug, build_article_page_document, find_article_summary};
+use domain::{
+    ArticleOutlineDocument, CategoryRegistry, SeriesKey, Slug, build_article_page_document,
+    find_article_summary,
+};
 use domain::{
     build_article_page_canonical_path, build_article_page_description, build_article_page_title,
+    build_tag_path,
 };
 #[cfg(feature = "ssr")]
 use infra::DynArtifactReader;
 use leptos::prelude::*;
 #[cfg(feature = "ssr")]
 use leptos_axum::ResponseOptions;
-use leptos_router::{hooks::use_params_map, params::ParamsMap};
-#[cfg(feature = "ssr")]
-use std::str::FromStr;
+use leptos_router::{components::A, hooks::use_params_map, params::ParamsMap};
 
 #[server]
 pub async fn get_article_page_document(
@@ -29,15 +31,17 @@ pub async fn get_article_page_document(
     {
         let artifact_reader = use_context::<DynArtifactReader>()
             .ok_or_else(|| ServerFnError::new("artifact reader context is missing"))?;
-        let category = match Category::from_str(&category) {
-            Ok(category) => category,
-            Err(_) => return Ok(None),
-        };
         let slug = match Slug::new(normalize_article_slug_param(&slug).to_string()) {
             Ok(slug) => slug,
             Err(_) => return Ok(None),
         };
         let snapshot = artifact_reader.snapshot().await?;
+        let site_metadata = snapshot.read_site_metadata().await?;
+        let registry = CategoryRegistry::try_from(&site_metadata)?;
+        let category = match registry.resolve(&category) {
+            Ok(category) => category,
+            Err(_) => return Ok(None),
+        };
         let article_index = snapshot.read_article_index().await?;
         let Some(summary) = find_article_summary(&article_index, &category, &slug) else {
             return Ok(None);
@@ -47,8 +51,29 @@ pub async fn get_article_page_document(
             Err(error) if error.is_not_found() => return Ok(None),
             Err(error) => return Err(error.into()),
         };
+        // Releases published before outlines existed render without a table of contents.
+        let outline = match snapshot.read_article_outline(&category, &slug).await {
+            Ok(outline) => outline,
+            Err(error) if error.is_not_found() => ArticleOutlineDocument::default(),
+            Err(error) => return Err(error.into()),
+        };
+        let series = match &summary.series {
+            Some(part) => Some(
+                snapshot
+                    .read_series_document(&SeriesKey::new(part.series.clone())?)
+                    .await?,
+            ),
+            None => None,
+        };
 
-        Ok(Some(build_article_page_document(summary, &html)?))
+        Ok(Some(build_article_page_document(
+            summary,
+            &html,
+            &outline,
+            series.as_ref(),
+            &article_index,
+            &registry,
+        )?))
     }
 
     #[cfg(not(feature = "ssr"))]
@@ -69,14 +94,24 @@ fn ArticlePageContent(document: ArticlePageDocument) -> impl IntoView {
     let updated_at = document.article.updated_at;
     let created_at_label = format_display_date(&created_at);
     let updated_at_label = format_display_date(&updated_at);
+    let reading_label = format_reading_stats(&document.article.reading_stats);
     let description = document.article.description;
     let tags = document.article.tags;
     let has_tags = !tags.is_empty();
     let html = document.html;
+    let toc = document.toc;
+    let series = document.series;
+    let related = document.related;
+    let backlinks = document.backlinks;
+    let article_class = if toc.is_empty() {
+        "mx-auto grid min-h-full w-full max-w-[var(--site-content-width)] gap-8 px-4 py-8 text-left sm:px-6 sm:py-12"
+    } else {
+        "mx-auto grid min-h-full w-full max-w-[var(--site-content-width)] gap-8 px-4 py-8 text-left sm:px-6 sm:py-12 lg:grid-cols-[minmax(0,1fr)_15rem]"
+    };
 
     view! {
-        <article class="mx-auto grid min-h-full w-full max-w-[var(--site-content-width)] gap-8 px-4 py-8 text-left sm:px-6 sm:py-12">
-            <header class="grid gap-3 rounded-2xl border border-border/80 bg-gradient-to-b from-card to-secondary/70 p-6 text-center shadow-[0_18px_42px_rgb(0_0_0/0.24)] sm:p-8">
+        <article class=article_class>
+            <header class="grid gap-3 lg:col-span-full rounded-2xl border border-border/80 bg-gradient-to-b from-card to-secondary/70 p-6 text-center shadow-[0_18px_42px_rgb(0_0_0/0.24)] sm:p-8">
                 <p class="m-0 text-sm font-bold tracking-[0.12em] text-primary uppercase">
                     {category}
                 </p>
@@ -87,6 +122,13 @@ fn ArticlePageContent(document: ArticlePageDocument) -> impl IntoView {
                     <span>{"公開 "}<time datetime=created_at>{created_at_label}</time></span>
                     <span aria-hidden="true">{"/"}</span>
                     <span>{"更新 "}<time datetime=updated_at>{updated_at_label}</time></span>
+                    {reading_label
+                        .map(|reading_label| {
+                            view! {
+                                <span aria-hidden="true">{"/"}</span>
+                                <span>{reading_label}</span>
+                            }
+                        })}
                 </p>
                 {description
                     .map(|description| {
@@ -106,12 +148,18 @@ fn ArticlePageContent(document: ArticlePageDocument) -> impl IntoView {
                             .map(|tag| {
                                 view! {
                                     <li>
-                                        <Badge
-                                            variant=BadgeVariant::Outline
-                                            class="rounded-full border-border bg-background/45 px-3 py-1 text-xs font-normal text-muted-foreground"
+                                        <A
+                                            href=build_tag_path(tag)
+                                            {..}
+                                            class="rounded-full no-underline focus-visible:outline-2 focus-visible:outline-offset-2 focus-visible:outline-ring"
                                         >
-                                            {format!("#{tag}")}
-                                        </Badge>
+                                            <Badge
+                                                variant=BadgeVariant::Outline
+                                                class="rounded-full border-border bg-background/45 px-3 py-1 text-xs font-normal text-muted-foreground hover:border-primary hover:text-primary"
+                                            >
+                                                {format!("#{tag}")}
+                                            </Badge>
+                                        </A>
                                     </li>
                                 }
                             })
@@ -120,12 +168,77 @@ fn ArticlePageContent(document: ArticlePageDocument) -> impl IntoView {
                 </Show>
             </header>
 
+            // The outline precedes the body on narrow screens and moves beside it on wide ones.
+            {(!toc.is_empty())
+                .then(|| {
+                    view! {
+                        <div class="min-w-0 lg:col-start-2 lg:row-start-2">
+                            <TableOfContents entries=toc />
+                        </div>
+                    }
+                })}
+
             // Artifact HTML is generated by the private publish pipeline, which escapes raw
             // HTML from markdown before writing the artifact.
             <div
-                class="content-prose w-full rounded-xl border border-border/80 bg-card p-6 shadow-[0_12px_32px_rgb(0_0_0/0.22)] sm:p-8"
+                class="content-prose w-full rounded-xl lg:col-start-1 lg:row-start-2 border border-border/80 bg-card p-6 shadow-[0_12px_32px_rgb(0_0_0/0.22)] sm:p-8"
                 inner_html=html
             ></div>
+
+            {series
+                .map(|navigation| {
+                    view! {
+                        <div class="min-w-0 lg:col-start-1">
+                            <SeriesBox navigation />
+                        </div>
+                    }
+                })}
+
+            {(!related.is_empty())
+                .then(|| {
+                    let related_items = related
+                        .into_iter()
+                        .map(|article| view! { <ArticleCard article /> })
+                        .collect_view();
+
+                    view! {
+                        <section
+                            class="grid min-w-0 gap-4 lg:col-start-1"
+                            aria-labelledby="related-articles-heading"
+                        >
+                            <h2
+                                id="related-articles-heading"
+                                class="m-0 text-xl font-semibold text-foreground"
+                            >
+                                {"関連記事"}
+                            </h2>
+                            <div class="grid gap-4">{related_items}</div>
+                        </section>
+                    }
+                })}
+
+            {(!backlinks.is_empty())
+                .then(|| {
+                    let backlink_items = backlinks
+                        .into_iter()
+                        .map(|article| view! { <ArticleCard article /> })
+                        .collect_view();
+
+                    view! {
+                        <section
+                            class="grid min-w-0 gap-4 lg:col-start-1"
+                            aria-labelledby="backlinks-heading"
+                        >
+                            <h2
+                                id="backlinks-heading"
+                                class="m-0 text-xl font-semibold text-foreground"
+                            >
+                                {"この記事を参照している記事"}
+                            </h2>
+                            <div class="grid gap-4">{backlink_items}</div>
+                        </section>
+                    }
+                })}
         </article>
     }
 }
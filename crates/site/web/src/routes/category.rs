@@ -1,12 +1,12 @@
 use crate::components::ui::card::Card;
 use crate::components::{ArticleCard, PageMetadata};
-use crate::routes::not_found::NotFoundPage;
+use crate::routes::page::StaticPage;
 use crate::{SITE_NAME, build_site_url};
 #[cfg(feature = "ssr")]
 use axum::http::StatusCode;
 use domain::CategoryPageDocument;
 #[cfg(feature = "ssr")]
-use domain::{Category, build_category_page_document};
+use domain::{CategoryRegistry, build_category_page_document};
 use domain::{
     build_category_page_canonical_path, build_category_page_description, build_category_page_title,
 };
@@ -16,8 +16,6 @@ use leptos::prelude::*;
 #[cfg(feature = "ssr")]
 use leptos_axum::ResponseOptions;
 use leptos_router::{hooks::use_params_map, params::ParamsMap};
-#[cfg(feature = "ssr")]
-use std::str::FromStr;
 use std::sync::Arc;
 
 #[server]
@@ -28,11 +26,13 @@ pub async fn get_category_page_document(
     {
         let artifact_reader = use_context::<DynArtifactReader>()
             .ok_or_else(|| ServerFnError::new("artifact reader context is missing"))?;
-        let category = match Category::from_str(&category) {
+        let snapshot = artifact_reader.snapshot().await?;
+        let site_metadata = snapshot.read_site_metadata().await?;
+        let registry = CategoryRegistry::try_from(&site_metadata)?;
+        let category = match registry.resolve(&category) {
             Ok(category) => category,
             Err(_) => return Ok(None),
         };
-        let snapshot = artifact_reader.snapshot().await?;
 
         let category_document = match snapshot.read_category_document(&category).await {
             Ok(document) => document,
@@ -40,7 +40,10 @@ pub async fn get_category_page_document(
             Err(error) => return Err(error.into()),
         };
 
-        Ok(Some(build_category_page_document(&category_document)?))
+        Ok(Some(build_category_page_document(
+            &category_document,
+            &registry,
+        )?))
     }
 
     #[cfg(not(feature = "ssr"))]
@@ -152,10 +155,8 @@ pub fn CategoryPage() -> impl IntoView {
                     }
                         .into_any()
                 }
-                Some(Ok(None)) => {
-                    mark_not_found_response();
-                    view! { <NotFoundPage /> }.into_any()
-                }
+                // Segments that are not published categories fall through to static pages.
+                Some(Ok(None)) => view! { <StaticPage page=category() /> }.into_any(),
                 Some(Err(error)) => {
                     mark_internal_server_error_response();
                     view! {
@@ -171,16 +172,6 @@ pub fn CategoryPage() -> impl IntoView {
     }
 }
 
-#[cfg(feature = "ssr")]
-fn mark_not_found_response() {
-    if let Some(response) = use_context::<ResponseOptions>() {
-        response.set_status(StatusCode::NOT_FOUND);
-    }
-}
-
-#[cfg(not(feature = "ssr"))]
-fn mark_not_found_response() {}
-
 #[cfg(feature = "ssr")]
 fn mark_internal_server_error_response() {
     if let Some(response) = use_context::<ResponseOptions>() {
@@ -72,6 +72,13 @@ fn HomePageContent(document: HomePageDocument) -> impl IntoView {
                             {..}
                             class="font-semibold text-foreground no-underline transition-colors hover:text-primary focus-visible:rounded-sm focus-visible:outline-2 focus-visible:outline-offset-2 focus-visible:outline-ring"
                         >
+                            {category
+                                .icon
+                                .map(|icon| {
+                                    view! {
+                                        <i class=format!("{icon} mr-1.5") aria-hidden="true"></i>
+                                    }
+                                })}
                             {category.category_display_name}
                         </A>
                         <span class="text-xs font-normal text-muted-foreground">
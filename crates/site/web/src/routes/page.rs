@@ -16,14 +16,18 @@ use leptos::prelude::*;
 use leptos_axum::ResponseOptions;
 
 #[server]
-pub async fn get_about_page_document() -> Result<Option<StaticPageDocument>, ServerFnError> {
+pub async fn get_static_page_document(
+    page: String,
+) -> Result<Option<StaticPageDocument>, ServerFnError> {
     #[cfg(feature = "ssr")]
     {
         let artifact_reader = use_context::<DynArtifactReader>()
             .ok_or_else(|| ServerFnError::new("artifact reader context is missing"))?;
+        let page = match PageKey::new(page) {
+            Ok(page) => page,
+            Err(_) => return Ok(None),
+        };
         let snapshot = artifact_reader.snapshot().await?;
-        let page = PageKey::new("about".to_string())
-            .map_err(|error| ServerFnError::new(error.to_string()))?;
         let artifact = match snapshot.read_page_document(&page).await {
             Ok(artifact) => artifact,
             Err(error) if error.is_not_found() => return Ok(None),
@@ -35,14 +39,15 @@ pub async fn get_about_page_document() -> Result<Option<StaticPageDocument>, Ser
 
     #[cfg(not(feature = "ssr"))]
     {
+        let _ = page;
         Err(ServerFnError::new(
-            "get_about_page_document is only available during SSR",
+            "get_static_page_document is only available during SSR",
         ))
     }
 }
 
 #[component]
-fn AboutPageContent(document: StaticPageDocument) -> impl IntoView {
+fn StaticPageContent(document: StaticPageDocument) -> impl IntoView {
     let title = document.title;
     let html = document.html;
 
@@ -70,31 +75,33 @@ fn AboutPageContent(document: StaticPageDocument) -> impl IntoView {
     }
 }
 
+/// Static page published as `pages/<page>.json`, such as `/about`.
 #[component]
-pub fn AboutPage() -> impl IntoView {
-    let about_page = Resource::<Result<Option<StaticPageDocument>, String>>::new_blocking(
-        || (),
-        move |_| async move {
-            get_about_page_document()
+pub fn StaticPage(#[prop(into)] page: String) -> impl IntoView {
+    let fallback_page = page.clone();
+    let static_page = Resource::<Result<Option<StaticPageDocument>, String>>::new_blocking(
+        move || page.clone(),
+        move |page| async move {
+            get_static_page_document(page)
                 .await
                 .map_err(|error| error.to_string())
         },
     );
 
     view! {
-        <Suspense fallback=|| {
+        <Suspense fallback=move || {
             view! {
                 <PageMetadata
-                    title=format!("About | {SITE_NAME}")
-                    description="About ページです。"
-                    canonical_url=build_site_url("/about")
+                    title=format!("{fallback_page} | {SITE_NAME}")
+                    description=format!("{fallback_page} ページです。")
+                    canonical_url=build_site_url(&format!("/{fallback_page}"))
                 />
                 <div class="mx-auto my-8 w-[calc(100%-2rem)] max-w-[var(--site-content-width)] rounded-xl bg-secondary p-8 text-center text-muted-foreground">
                     "ページを読み込み中..."
                 </div>
             }
         }>
-            {move || match about_page.get() {
+            {move || match static_page.get() {
                 Some(Ok(Some(document))) => {
                     let page_title = build_static_page_title(&document, SITE_NAME);
                     let page_description = build_static_page_description(&document);
@@ -104,7 +111,7 @@ pub fn AboutPage() -> impl IntoView {
 
                     view! {
                         <PageMetadata title=page_title description=page_description canonical_url />
-                        <AboutPageContent document />
+                        <StaticPageContent document />
                     }
                         .into_any()
                 }
@@ -0,0 +1,159 @@
+use crate::components::ui::card::Card;
+use crate::components::{ArticleCard, PageMetadata};
+use crate::routes::not_found::NotFoundPage;
+use crate::{SITE_NAME, build_site_url};
+#[cfg(feature = "ssr")]
+use axum::http::StatusCode;
+#[cfg(feature = "ssr")]
+use domain::{CategoryRegistry, build_tag_page_document};
+use domain::{
+    Tag, TagPageDocument, build_tag_page_canonical_path, build_tag_page_description,
+    build_tag_page_title, build_tag_path,
+};
+#[cfg(feature = "ssr")]
+use infra::DynArtifactReader;
+use leptos::prelude::*;
+#[cfg(feature = "ssr")]
+use leptos_axum::ResponseOptions;
+use leptos_router::{hooks::use_params_map, params::ParamsMap};
+use std::sync::Arc;
+
+#[server]
+pub async fn get_tag_page_document(tag: String) -> Result<Option<TagPageDocument>, ServerFnError> {
+    #[cfg(feature = "ssr")]
+    {
+        let artifact_reader = use_context::<DynArtifactReader>()
+            .ok_or_else(|| ServerFnError::new("artifact reader context is missing"))?;
+        let tag = match Tag::new(tag) {
+            Ok(tag) => tag,
+            Err(_) => return Ok(None),
+        };
+        let snapshot = artifact_reader.snapshot().await?;
+        let tag_document = match snapshot.read_tag_document(&tag).await {
+            Ok(document) => document,
+            Err(error) if error.is_not_found() => return Ok(None),
+            Err(error) => return Err(error.into()),
+        };
+        let site_metadata = snapshot.read_site_metadata().await?;
+        let registry = CategoryRegistry::try_from(&site_metadata)?;
+
+        Ok(Some(build_tag_page_document(&tag_document, &registry)?))
+    }
+
+    #[cfg(not(feature = "ssr"))]
+    {
+        let _ = tag;
+        Err(ServerFnError::new(
+            "get_tag_page_document is only available during SSR",
+        ))
+    }
+}
+
+#[component]
+fn TagPageContent(document: TagPageDocument) -> impl IntoView {
+    let page_description: Arc<str> = build_tag_page_description(&document).into();
+    let heading = format!("#{}", document.tag);
+    let article_items = document
+        .articles
+        .into_iter()
+        .map(|article| view! { <ArticleCard article /> })
+        .collect_view();
+
+    view! {
+        <div class="mx-auto grid min-h-full w-full max-w-[var(--site-content-width)] gap-6 px-4 py-8 text-left sm:px-6 sm:py-12">
+            <Card class="gap-3 border-border/80 bg-gradient-to-b from-card to-secondary/70 p-6 sm:p-8">
+                <p class="m-0 text-sm tracking-[0.16em] text-primary uppercase">{"Tag"}</p>
+                <h1 class="m-0 text-3xl leading-tight font-bold sm:text-4xl">{heading}</h1>
+                <p class="m-0 leading-7 text-muted-foreground">{page_description}</p>
+            </Card>
+
+            <div class="grid gap-4">{article_items}</div>
+        </div>
+    }
+}
+
+#[component]
+pub fn TagPage() -> impl IntoView {
+    let params = use_params_map();
+    let tag = move || params.with(|params: &ParamsMap| params.get("tag").unwrap_or_default());
+    let tag_page = Resource::<Result<Option<TagPageDocument>, String>>::new_blocking(
+        tag,
+        move |tag| async move {
+            if tag.is_empty() {
+                return Ok(None);
+            }
+
+            get_tag_page_document(tag)
+                .await
+                .map_err(|error| error.to_string())
+        },
+    );
+
+    view! {
+        <Suspense fallback=move || {
+            let tag_param = params
+                .with_untracked(|params: &ParamsMap| params.get("tag").unwrap_or_default());
+            let canonical_path = Tag::new(tag_param.clone())
+                .map_or_else(|_| format!("/tags/{tag_param}"), |tag| build_tag_path(&tag));
+
+            view! {
+                <PageMetadata
+                    title=format!("#{tag_param} | {SITE_NAME}")
+                    description=format!("#{tag_param} のタグが付いた記事の一覧です。")
+                    canonical_url=build_site_url(&canonical_path)
+                />
+                <div class="mx-auto my-8 w-[calc(100%-2rem)] max-w-[var(--site-content-width)] rounded-xl bg-secondary p-8 text-center text-muted-foreground">
+                    "タグを読み込み中..."
+                </div>
+            }
+        }>
+            {move || match tag_page.get() {
+                Some(Ok(Some(document))) => {
+                    let page_title = build_tag_page_title(&document, SITE_NAME);
+                    let page_description = build_tag_page_description(&document);
+                    let canonical_url = build_site_url(&build_tag_page_canonical_path(&document));
+
+                    view! {
+                        <PageMetadata title=page_title description=page_description canonical_url />
+                        <TagPageContent document />
+                    }
+                        .into_any()
+                }
+                Some(Ok(None)) => {
+                    mark_not_found_response();
+                    view! { <NotFoundPage /> }.into_any()
+                }
+                Some(Err(error)) => {
+                    mark_internal_server_error_response();
+                    view! {
+                        <div class="mx-auto my-8 w-[calc(100%-2rem)] max-w-[var(--site-content-width)] rounded-xl bg-secondary p-8 text-center text-muted-foreground">
+                            {format!("タグの読み込みに失敗しました: {error}")}
+                        </div>
+                    }
+                        .into_any()
+                }
+                None => view! { <div></div> }.into_any(),
+            }}
+        </Suspense>
+    }
+}
+
+#[cfg(feature = "ssr")]
+fn mark_not_found_response() {
+    if let Some(response) = use_context::<ResponseOptions>() {
+        response.set_status(StatusCode::NOT_FOUND);
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+fn mark_not_found_response() {}
+
+#[cfg(feature = "ssr")]
+fn mark_internal_server_error_response() {
+    if let Some(response) = use_context::<ResponseOptions>() {
+        response.set_status(StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+fn mark_internal_server_error_response() {}
@@ -132,12 +132,18 @@ test("home renders artifacts and hydrates article navigation", async ({ page })
   const articleWidths = await page.locator("main article").evaluate((article) => {
     const header = article.querySelector(":scope > header");
     const prose = article.querySelector(":scope > .content-prose");
+    const toc = article.querySelector(':scope nav[aria-label="目次"]');
     return {
       header: header?.getBoundingClientRect().width ?? 0,
       prose: prose?.getBoundingClientRect().width ?? 0,
+      toc: toc?.getBoundingClientRect().width ?? 0,
+      gap: Number.parseFloat(getComputedStyle(article).columnGap),
     };
   });
-  expect(articleWidths.prose).toBeCloseTo(articleWidths.header, 0);
+  expect(articleWidths.prose + articleWidths.gap + articleWidths.toc).toBeCloseTo(
+    articleWidths.header,
+    0,
+  );
   expect(documentRequests).toBe(0);
   await expectMetadata(
     page,
@@ -203,6 +209,16 @@ test("mobile navigation stays in the viewport and exposes its state", async ({ p
   await expect(menuButton).toHaveAttribute("aria-expanded", "false");
 });
 
+test("header navigation lists categories from site metadata", async ({ page }) => {
+  await page.goto("/tech/e2e-article");
+
+  const navigation = page.locator("#site-header-nav");
+  const links = navigation.locator("ul").getByRole("link");
+  await expect(links).toHaveText(["ホーム", "技術", "物理学", "About"]);
+  await expect(navigation.getByRole("link", { name: "技術" })).toHaveAttribute("href", "/tech");
+  await expect(navigation.getByRole("link", { name: "技術" })).toHaveClass(/border-primary/);
+});
+
 test("home article cards stay within the mobile viewport", async ({ page }) => {
   await page.setViewportSize({ width: 390, height: 844 });
   await page.goto("/");
@@ -226,6 +242,15 @@ test("about renders its page artifact", async ({ page }) => {
   await expectMetadata(page, `Fixture About | ${SITE_NAME}`, "/about");
 });
 
+test("any published page key is routed at the top level", async ({ page }) => {
+  const response = await page.goto("/colophon");
+
+  expect(response?.status()).toBe(200);
+  await expect(page.getByRole("heading", { name: "Fixture Colophon" })).toBeVisible();
+  await expect(page.locator("main .content-prose")).toContainText("Colophon fixture body");
+  await expectMetadata(page, `Fixture Colophon | ${SITE_NAME}`, "/colophon");
+});
+
 test("category renders landing content and grouped articles", async ({ page }) => {
   const reactiveWarnings = captureReactiveWarnings(page);
   const response = await page.goto("/tech");
@@ -275,9 +300,9 @@ test("generated article content stays readable on mobile", async ({ page }) => {
 
   await expect(prose).toBeVisible();
   await expect(page.getByTestId("article-bookmark")).toBeVisible();
-  await expect(page.getByTestId("article-katex")).toBeVisible();
-  await expect(wideCode.locator("code")).toHaveClass(/hljs/);
-  await expect(wideCode.locator(".hljs-keyword").first()).toBeVisible();
+  await expect(page.getByTestId("article-math").locator("math")).toBeVisible();
+  await expect(wideCode.locator("code")).toHaveClass("language-rust");
+  await expect(wideCode.locator(".hl-keyword").first()).toHaveCSS("color", "rgb(224, 168, 111)");
 
   const contentStyles = await prose.evaluate((element) => ({
     textAlign: getComputedStyle(element).textAlign,
@@ -300,6 +325,128 @@ test("generated article content stays readable on mobile", async ({ page }) => {
   expect(pageHasNoHorizontalOverflow).toBe(true);
 });
 
+test("article table of contents links to headings and tracks the current section", async ({
+  page,
+}) => {
+  const browserErrors = captureBrowserErrors(page);
+  await page.goto("/tech/e2e-article");
+
+  const toc = page.getByRole("navigation", { name: "目次" });
+  const firstLink = toc.getByRole("link", { name: "Article artifact" });
+  const link = toc.getByRole("link", { name: "Generated content" });
+
+  await expect(toc.getByRole("link")).toHaveText(["Article artifact", "Generated content"]);
+  await expect(link).toHaveAttribute("href", "#generated-content");
+  expect(await toc.evaluate((element) => getComputedStyle(element).position)).toBe("sticky");
+  await expect(firstLink).toHaveAttribute("aria-current", "location");
+
+  await link.click();
+
+  await expect(page).toHaveURL(/#generated-content$/);
+  await expect(link).toHaveAttribute("aria-current", "location");
+  await expect(firstLink).not.toHaveAttribute("aria-current");
+  expect(browserErrors).toEqual([]);
+});
+
+test("article series box lists every part and links its neighbors", async ({ page }) => {
+  await page.goto("/tech/e2e-article");
+
+  const series = page.getByRole("navigation", { name: "シリーズ" });
+  await expect(series).toContainText("e2e-series (1/2)");
+  await expect(series.getByRole("listitem")).toHaveText(["1. E2E Article", "2. Series Finale"]);
+  await expect(series.getByRole("link", { name: "1. E2E Article" })).toHaveAttribute(
+    "aria-current",
+    "page",
+  );
+  await expect(series.locator('a[rel="prev"]')).toHaveCount(0);
+
+  await series.locator('a[rel="next"]').click();
+
+  await expect(page).toHaveURL(/\/tech\/e2e-series-finale$/);
+  await expect(page.getByRole("heading", { name: "Series Finale", level: 1 })).toBeVisible();
+  const finaleSeries = page.getByRole("navigation", { name: "シリーズ" });
+  await expect(finaleSeries.locator('a[rel="prev"]')).toHaveAttribute(
+    "href",
+    "/tech/e2e-article",
+  );
+  await expect(finaleSeries.locator('a[rel="next"]')).toHaveCount(0);
+});
+
+test("article lists related articles as cards", async ({ page }) => {
+  await page.goto("/tech/e2e-article");
+
+  const related = page.getByRole("region", { name: "関連記事" });
+  await expect(related.getByRole("heading", { level: 3 })).toHaveText(["Series Finale"]);
+  await related.getByRole("link", { name: /Series Finale/ }).click();
+
+  await expect(page).toHaveURL(/\/tech\/e2e-series-finale$/);
+  await expect(page.getByRole("region", { name: "関連記事" })).toHaveCount(0);
+});
+
+test("article lists the articles linking to it", async ({ page }) => {
+  await page.goto("/tech/e2e-series-finale");
+
+  const backlinks = page.getByRole("region", { name: "この記事を参照している記事" });
+  await expect(backlinks.getByRole("heading", { level: 3 })).toHaveText(["E2E Article"]);
+
+  await page.goto("/tech/e2e-article");
+  await expect(page.getByRole("region", { name: "この記事を参照している記事" })).toHaveCount(0);
+});
+
+test("article header and cards show reading time and character count", async ({ page }) => {
+  await page.goto("/tech/e2e-article");
+  await expect(page.locator("article > header")).toContainText("約3分・1,234文字");
+
+  await page.goto("/tech");
+  const card = page.getByRole("link", { name: "E2E Article" });
+  await expect(card).toContainText("約3分・1,234文字");
+  await expect(page.getByRole("link", { name: "Series Finale" })).not.toContainText("文字");
+});
+
+test("articles without a summary fall back to their body excerpt", async ({ page }) => {
+  await page.goto("/tech");
+  await expect(page.getByRole("link", { name: "Series Finale" })).toContainText(
+    "Series finale opening sentence.",
+  );
+
+  await page.goto("/tech/e2e-series-finale");
+  await expect(page.locator('meta[name="description"]')).toHaveAttribute(
+    "content",
+    "Series finale opening sentence.",
+  );
+});
+
+test("retired article URLs redirect permanently to the current article", async ({
+  request,
+}) => {
+  for (const path of ["/tech/e2e-article-old", "/tech/e2e-article-old.html"]) {
+    const response = await request.get(path, { maxRedirects: 0 });
+
+    expect(response.status()).toBe(301);
+    expect(response.headers().location).toBe("/tech/e2e-article");
+  }
+});
+
+test("tag badges link to tag pages listing every tagged article", async ({ page }) => {
+  await page.goto("/tech/e2e-article");
+
+  const tags = page.getByRole("list", { name: "タグ" });
+  await expect(tags.getByRole("link")).toHaveText(["#rust", "#e2e"]);
+  await tags.getByRole("link", { name: "#rust" }).click();
+
+  await expect(page).toHaveURL(/\/tags\/rust$/);
+  await expect(page.getByRole("heading", { name: "#rust", level: 1 })).toBeVisible();
+  await expectMetadata(page, `#rust | ${SITE_NAME}`, "/tags/rust");
+  await expect(page.getByRole("heading", { level: 3 })).toHaveText([
+    "E2E Article",
+    "Series Finale",
+  ]);
+
+  const missingResponse = await page.goto("/tags/missing-tag");
+  expect(missingResponse?.status()).toBe(404);
+  await expectNotFoundMetadata(page, "/tags/missing-tag");
+});
+
 test("missing article and category return 404 pages", async ({ page }) => {
   const articleResponse = await page.goto("/tech/missing-article");
 